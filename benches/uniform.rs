@@ -9,7 +9,7 @@
 //! Implement benchmarks for uniform distributions over integer types
 
 use core::time::Duration;
-use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
 use rand::distributions::uniform::{SampleRange, Uniform};
 use rand::prelude::*;
 use rand_chacha::ChaCha8Rng;
@@ -46,6 +46,20 @@ macro_rules! sample {
 
             b.iter(|| dist.sample(&mut rng));
         });
+
+        $g.bench_function(BenchmarkId::new(stringify!($R), "distr_once"), |b| {
+            let mut rng = <$R>::from_entropy();
+            let x = rng.gen::<$U>();
+            let bits = (<$T>::BITS / 2);
+            let mask = (1 as $U).wrapping_neg() >> bits;
+            let range = (x >> bits) * (x & mask);
+            let low = <$T>::MIN;
+            let high = low.wrapping_add(range as $T);
+
+            // Constructing a `Uniform` for a single sample, for comparison
+            // with "single"
+            b.iter(|| Uniform::<$T>::new_inclusive(black_box(low), black_box(high)).unwrap().sample(&mut rng));
+        });
     };
 
     ($c:expr, $T:ty, $U:ty) => {{
@@ -13,7 +13,7 @@
 
 use self::core::fmt;
 use crate::guts::ChaCha;
-use rand_core::block::{BlockRng, BlockRngCore, CryptoBlockRng};
+use rand_core::block::{BlockRng, BlockRngCore, CryptoBlockRng, SeekableRng};
 use rand_core::{CryptoRng, Error, RngCore, SeedableRng};
 
 #[cfg(feature = "serde1")] use serde::{Serialize, Deserialize, Serializer, Deserializer};
@@ -114,6 +114,25 @@ macro_rules! chacha_impl {
 
         impl CryptoBlockRng for $ChaChaXCore {}
 
+        impl SeekableRng for $ChaChaXCore {
+            #[inline]
+            fn set_stream(&mut self, stream: u64) {
+                self.state.set_nonce(stream);
+            }
+
+            #[inline]
+            fn word_pos(&self) -> u128 {
+                u128::from(self.state.get_block_pos()) * u128::from(BLOCK_WORDS)
+            }
+
+            #[inline]
+            fn set_word_pos(&mut self, pos: u128) -> usize {
+                let block = (pos / u128::from(BLOCK_WORDS)) as u64;
+                self.state.set_block_pos(block);
+                (pos % u128::from(BLOCK_WORDS)) as usize
+            }
+        }
+
         /// A cryptographically secure random number generator that uses the ChaCha algorithm.
         ///
         /// ChaCha is a stream cipher designed by Daniel J. Bernstein[^1], that we use as an RNG. It is
@@ -83,6 +83,27 @@ pub trait BlockRngCore {
 /// See [`CryptoRng`][crate::CryptoRng] docs for more information.
 pub trait CryptoBlockRng: BlockRngCore { }
 
+/// A [`BlockRngCore`] with multiple output streams, which supports seeking
+/// within a stream, e.g. a counter-based generator like ChaCha.
+///
+/// Positions are counted in `Item`s ("words") from the start of the stream.
+/// A generator may only be able to generate blocks starting at certain
+/// positions, such as multiples of its internal block size.
+pub trait SeekableRng: BlockRngCore {
+    /// Select the stream number, keeping the position.
+    fn set_stream(&mut self, stream: u64);
+
+    /// The position of the first word which the next call to `generate`
+    /// produces.
+    fn word_pos(&self) -> u128;
+
+    /// Seek to the word at `pos`.
+    ///
+    /// The next call to `generate` produces a block containing this word;
+    /// the return value is its index in the block.
+    fn set_word_pos(&mut self, pos: u128) -> usize;
+}
+
 /// A wrapper type implementing [`RngCore`] for some type implementing
 /// [`BlockRngCore`] with `u32` array buffer; i.e. this can be used to implement
 /// a full RNG from just a `generate` function.
@@ -182,6 +203,30 @@ impl<R: BlockRngCore> BlockRng<R> {
         self.core.generate(&mut self.results);
         self.index = index;
     }
+
+    /// Get the result buffer.
+    ///
+    /// Only results from [`index()`] onwards are yet to be used.
+    ///
+    /// [`index()`]: BlockRng::index
+    #[inline]
+    pub fn results(&self) -> &R::Results {
+        &self.results
+    }
+
+    /// Replace the result buffer and set the index, e.g. to restore a state
+    /// captured with [`results()`] and [`index()`].
+    ///
+    /// An `index` equal to or larger than the size of the result buffer
+    /// marks the buffer as "empty".
+    ///
+    /// [`results()`]: BlockRng::results
+    /// [`index()`]: BlockRng::index
+    #[inline]
+    pub fn set_results(&mut self, results: R::Results, index: usize) {
+        self.results = results;
+        self.index = index;
+    }
 }
 
 impl<R: BlockRngCore<Item = u32>> RngCore for BlockRng<R> {
@@ -487,6 +532,21 @@ mod test {
         assert_eq!(a, c);
     }
 
+    #[test]
+    fn blockrng_set_results() {
+        let mut rng1 = BlockRng::<DummyRng>::from_seed([1, 2, 3, 4]);
+        for _ in 0..5 {
+            rng1.next_u32();
+        }
+        assert_eq!(rng1.index(), 5);
+
+        let mut rng2 = BlockRng::new(rng1.core.clone());
+        rng2.set_results(*rng1.results(), rng1.index());
+        for _ in 0..40 {
+            assert_eq!(rng1.next_u32(), rng2.next_u32());
+        }
+    }
+
     #[derive(Debug, Clone)]
     struct DummyRng64 {
         counter: u64,
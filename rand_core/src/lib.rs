@@ -53,6 +53,7 @@ pub mod block;
 mod error;
 pub mod impls;
 pub mod le;
+mod sha256;
 #[cfg(feature = "getrandom")] mod os;
 
 
@@ -341,6 +342,42 @@ pub trait SeedableRng: Sized {
         Self::from_seed(seed)
     }
 
+    /// Create a new PRNG from a string, e.g. a seed passed on the command
+    /// line.
+    ///
+    /// The seed is derived from the UTF-8 bytes of `s` with SHA-256: for
+    /// `i = 0, 1, ...`, bytes `32 * i .. 32 * (i + 1)` of the seed are taken
+    /// from the digest of `i` (as a little-endian `u32`) followed by `s`.
+    /// This mapping is fixed: the same string yields the same seed on all
+    /// platforms and in all versions. *Changing* it would be a value-breaking
+    /// change.
+    ///
+    /// This is only as unpredictable as the string itself; a short string
+    /// is easily guessed, thus this **is not suitable for cryptography**
+    /// unless the string is a secret with sufficient entropy.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rand_core::SeedableRng;
+    /// # struct Lcg([u8; 8]);
+    /// # impl SeedableRng for Lcg {
+    /// #     type Seed = [u8; 8];
+    /// #     fn from_seed(seed: [u8; 8]) -> Self { Lcg(seed) }
+    /// # }
+    /// let a = Lcg::seed_from_str("my-experiment-42");
+    /// let b = Lcg::seed_from_str("my-experiment-42");
+    /// assert_eq!(a.0, b.0);
+    /// ```
+    fn seed_from_str(s: &str) -> Self {
+        let mut seed = Self::Seed::default();
+        for (i, chunk) in seed.as_mut().chunks_mut(32).enumerate() {
+            let digest = sha256::sha256(&[&(i as u32).to_le_bytes(), s.as_bytes()]);
+            chunk.copy_from_slice(&digest[..chunk.len()]);
+        }
+        Self::from_seed(seed)
+    }
+
     /// Create a new PRNG seeded from another `Rng`.
     ///
     /// This may be useful when needing to rapidly seed many PRNGs from a master
@@ -529,4 +566,46 @@ mod test {
         // value-breakage test:
         assert_eq!(results[0], 5029875928683246316);
     }
+
+    #[test]
+    fn test_seed_from_str() {
+        struct Seed40([u8; 40]);
+        impl Default for Seed40 {
+            fn default() -> Self {
+                Seed40([0; 40])
+            }
+        }
+        impl AsMut<[u8]> for Seed40 {
+            fn as_mut(&mut self) -> &mut [u8] {
+                &mut self.0
+            }
+        }
+
+        struct SeedBytes<S>(S);
+        impl<S: Default + AsMut<[u8]>> SeedableRng for SeedBytes<S> {
+            type Seed = S;
+
+            fn from_seed(seed: Self::Seed) -> Self {
+                SeedBytes(seed)
+            }
+        }
+
+        let a = SeedBytes::<[u8; 8]>::seed_from_str("my-experiment-42").0;
+        let b = SeedBytes::<[u8; 8]>::seed_from_str("my-experiment-42").0;
+        assert_eq!(a, b);
+        // value-breakage test: SHA-256 of [0, 0, 0, 0] followed by the string
+        assert_eq!(a, [43, 71, 88, 203, 104, 153, 136, 57]);
+
+        let c = SeedBytes::<[u8; 8]>::seed_from_str("my-experiment-43").0;
+        assert!((u64::from_le_bytes(a) ^ u64::from_le_bytes(c)).count_ones() >= 16);
+        let empty = SeedBytes::<[u8; 8]>::seed_from_str("").0;
+        assert_ne!(empty, [0; 8]);
+
+        // Seeds longer than one digest
+        let long = SeedBytes::<Seed40>::seed_from_str("my-experiment-42").0 .0;
+        assert_eq!(long[..8], a);
+        let full = SeedBytes::<[u8; 32]>::seed_from_str("my-experiment-42").0;
+        assert_eq!(long[..32], full);
+        assert_ne!(long[32..], a);
+    }
 }
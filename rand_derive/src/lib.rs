@@ -0,0 +1,110 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Derive macros for `rand`.
+//!
+//! These macros are re-exported by `rand` with its `derive` feature; the
+//! generated code refers to items of the `rand` crate, which must thus be a
+//! dependency of the crate using them.
+
+#![doc(
+    html_logo_url = "https://www.rust-lang.org/logos/rust-logo-128x128-blk.png",
+    html_favicon_url = "https://www.rust-lang.org/favicon.ico",
+    html_root_url = "https://rust-random.github.io/rand/"
+)]
+#![deny(missing_docs)]
+#![deny(missing_debug_implementations)]
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Error, Fields};
+
+/// Derive `Distribution<T> for Standard` for a fieldless enum `T`, sampling
+/// each variant with equal probability.
+///
+/// # Example
+///
+/// ```
+/// use rand::distributions::RandUniform;
+/// use rand::Rng;
+///
+/// #[derive(Debug, RandUniform)]
+/// enum Suit {
+///     Clubs,
+///     Diamonds,
+///     Hearts,
+///     Spades,
+/// }
+///
+/// let suit: Suit = rand::thread_rng().gen();
+/// println!("{:?}", suit);
+/// ```
+///
+/// Variants carrying data are rejected, as are enums without variants:
+///
+/// ```compile_fail
+/// use rand::distributions::RandUniform;
+///
+/// #[derive(RandUniform)]
+/// enum Shape {
+///     Circle(f64),
+///     Point,
+/// }
+/// ```
+#[proc_macro_derive(RandUniform)]
+pub fn derive_rand_uniform(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    rand_uniform(input).unwrap_or_else(Error::into_compile_error).into()
+}
+
+fn rand_uniform(input: DeriveInput) -> Result<proc_macro2::TokenStream, Error> {
+    let name = &input.ident;
+    let data = match input.data {
+        Data::Enum(ref data) => data,
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "RandUniform can only be derived for enums",
+            ))
+        }
+    };
+    if data.variants.is_empty() {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "RandUniform cannot be derived for an enum without variants",
+        ));
+    }
+
+    let mut arms = Vec::with_capacity(data.variants.len());
+    for (i, variant) in data.variants.iter().enumerate() {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(Error::new_spanned(
+                variant,
+                "RandUniform can only be derived for enums whose variants have no fields",
+            ));
+        }
+        let i = i as u32;
+        let ident = &variant.ident;
+        arms.push(quote! { #i => #name::#ident, });
+    }
+    let n = arms.len() as u32;
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::rand::distributions::Distribution<#name #ty_generics>
+            for ::rand::distributions::Standard #where_clause
+        {
+            fn sample<R: ::rand::Rng + ?Sized>(&self, rng: &mut R) -> #name #ty_generics {
+                match ::rand::Rng::gen_range(rng, 0..#n) {
+                    #(#arms)*
+                    _ => ::core::unreachable!(),
+                }
+            }
+        }
+    })
+}
@@ -0,0 +1,63 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use rand::distributions::{Distribution, RandUniform, Standard};
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, RandUniform)]
+enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+#[derive(Debug, PartialEq, Eq, RandUniform)]
+#[allow(dead_code)]
+enum Discriminants {
+    A = 10,
+    B = 20,
+    C = 1,
+}
+
+#[derive(Debug, PartialEq, Eq, RandUniform)]
+enum Single {
+    Only,
+}
+
+#[test]
+fn all_variants_equally_likely() {
+    let mut rng = SmallRng::seed_from_u64(319);
+    let n = 40_000;
+    let mut counts = [0usize; 4];
+    for _ in 0..n {
+        let d: Direction = rng.gen();
+        counts[d as usize] += 1;
+    }
+    for &count in counts.iter() {
+        // Expected 10000, standard deviation ~87
+        assert!((9_500..10_500).contains(&count), "{:?}", counts);
+    }
+}
+
+#[test]
+fn explicit_discriminants() {
+    let mut rng = SmallRng::seed_from_u64(320);
+    let mut seen = [false; 3];
+    for x in Standard.sample_iter(&mut rng).take(100) {
+        match x {
+            Discriminants::A => seen[0] = true,
+            Discriminants::B => seen[1] = true,
+            Discriminants::C => seen[2] = true,
+        }
+    }
+    assert_eq!(seen, [true; 3]);
+
+    assert_eq!(rng.gen::<Single>(), Single::Only);
+}
@@ -0,0 +1,148 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The bivariate Gaussian copula.
+
+use crate::utils::normal_cdf;
+use crate::{Distribution, StandardNormal};
+use core::fmt;
+use num_traits::Float;
+use rand::Rng;
+
+/// The bivariate Gaussian copula: pairs of correlated uniform variates.
+///
+/// Each sample `(u, v)` has `u` and `v` uniformly distributed on `[0, 1]`,
+/// with the dependence structure of a bivariate normal distribution. The
+/// correlation of the underlying normal distribution is chosen such that the
+/// rank (Spearman) correlation of `u` and `v` is `rho`; this is
+/// `2 sin(π rho / 6)`, which is close to `rho`.
+///
+/// The normal CDF is evaluated by an approximation accurate to about `1e-7`.
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::{Distribution, GaussianCopula2D};
+///
+/// let copula = GaussianCopula2D::new(0.8).unwrap();
+/// let (u, v) = copula.sample(&mut rand::thread_rng());
+/// println!("correlated uniforms: {} {}", u, v);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde1", derive(serde::Serialize, serde::Deserialize))]
+pub struct GaussianCopula2D {
+    /// Correlation of the underlying normal distribution
+    r: f64,
+    /// `sqrt(1 - r^2)`
+    s: f64,
+}
+
+/// Error type returned from `GaussianCopula2D::new`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// `rho <= -1` or `rho >= 1` or `nan`.
+    InvalidCorrelation,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Error::InvalidCorrelation => "rho is not in (-1, 1) in Gaussian copula",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
+impl std::error::Error for Error {}
+
+impl GaussianCopula2D {
+    /// Construct a new `GaussianCopula2D` with rank correlation `rho`.
+    ///
+    /// Requires `-1 < rho < 1`.
+    pub fn new(rho: f64) -> Result<GaussianCopula2D, Error> {
+        if !(rho > -1.0 && rho < 1.0) {
+            return Err(Error::InvalidCorrelation);
+        }
+        let r = 2.0 * Float::sin(core::f64::consts::PI * rho / 6.0);
+        Ok(GaussianCopula2D {
+            r,
+            s: Float::sqrt(1.0 - r * r),
+        })
+    }
+
+    /// Returns the correlation of the underlying bivariate normal distribution.
+    pub fn normal_correlation(&self) -> f64 {
+        self.r
+    }
+}
+
+impl Distribution<(f64, f64)> for GaussianCopula2D {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> (f64, f64) {
+        let z1: f64 = rng.sample(StandardNormal);
+        let z2: f64 = rng.sample(StandardNormal);
+        let z2 = self.r * z1 + self.s * z2;
+        (normal_cdf(z1), normal_cdf(z2))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[cfg(feature = "alloc")]
+    use alloc::{vec, vec::Vec};
+
+    #[test]
+    fn test_normal_cdf() {
+        assert!((normal_cdf(0.0) - 0.5).abs() < 1e-7);
+        assert!((normal_cdf(1.959963984540054) - 0.975).abs() < 1e-7);
+        assert!((normal_cdf(-1.0) - 0.15865525393145707).abs() < 1e-7);
+        assert!(normal_cdf(-40.0) >= 0.0 && normal_cdf(40.0) <= 1.0);
+    }
+
+    #[test]
+    fn test_copula_invalid() {
+        for &rho in &[-1.0, 1.0, 1.5, f64::NAN] {
+            assert_eq!(GaussianCopula2D::new(rho), Err(Error::InvalidCorrelation));
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    fn ranks(values: &[f64]) -> Vec<f64> {
+        let mut order: Vec<usize> = (0..values.len()).collect();
+        order.sort_by(|&i, &j| values[i].partial_cmp(&values[j]).unwrap());
+        let mut ranks = vec![0.0; values.len()];
+        for (rank, &i) in order.iter().enumerate() {
+            ranks[i] = rank as f64;
+        }
+        ranks
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_copula_spearman() {
+        let mut rng = crate::test::rng(294);
+        let n = 5000;
+        for &rho in &[-0.7, 0.0, 0.5, 0.95] {
+            let copula = GaussianCopula2D::new(rho).unwrap();
+            let (us, vs): (Vec<f64>, Vec<f64>) = (0..n).map(|_| copula.sample(&mut rng)).unzip();
+            assert!(us.iter().chain(vs.iter()).all(|&x| (0.0..=1.0).contains(&x)));
+            let mean = us.iter().sum::<f64>() / n as f64;
+            assert!((mean - 0.5).abs() < 0.02);
+
+            // Spearman correlation: the Pearson correlation of the ranks,
+            // which have mean `(n - 1) / 2`
+            let (ru, rv) = (ranks(&us), ranks(&vs));
+            let m = (n - 1) as f64 / 2.0;
+            let cov: f64 = ru.iter().zip(rv.iter()).map(|(a, b)| (a - m) * (b - m)).sum();
+            let var: f64 = ru.iter().map(|a| (a - m) * (a - m)).sum();
+            let spearman = cov / var;
+            assert!((spearman - rho).abs() < 0.03, "rho {}: {}", rho, spearman);
+        }
+    }
+}
@@ -0,0 +1,28 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Probability densities.
+
+use crate::Distribution;
+
+/// Distributions of continuous values with a known probability density.
+///
+/// This is used where the density of samples must be known, e.g. by
+/// [`importance_sample`](crate::importance_sample).
+pub trait Density<T>: Distribution<T> {
+    /// The probability density function evaluated at `x`
+    ///
+    /// Returns `0` for values outside the support of the distribution.
+    fn pdf(&self, x: T) -> f64;
+}
+
+impl<T, D: Density<T> + ?Sized> Density<T> for &D {
+    fn pdf(&self, x: T) -> f64 {
+        (*self).pdf(x)
+    }
+}
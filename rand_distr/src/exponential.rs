@@ -11,7 +11,7 @@
 
 use crate::utils::ziggurat;
 use num_traits::Float;
-use crate::{ziggurat_tables, Distribution};
+use crate::{ziggurat_tables, Density, Distribution};
 use rand::Rng;
 use core::fmt;
 
@@ -150,6 +150,16 @@ where F: Float, Exp1: Distribution<F>
     }
 }
 
+impl Density<f64> for Exp<f64> {
+    fn pdf(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            0.0
+        } else {
+            Float::exp(-x / self.lambda_inverse) / self.lambda_inverse
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -163,6 +173,13 @@ mod test {
         }
     }
     #[test]
+    fn test_exp_pdf() {
+        let exp = Exp::new(2.0).unwrap();
+        assert_eq!(exp.pdf(-1.0), 0.0);
+        assert_eq!(exp.pdf(0.0), 2.0);
+        assert!((exp.pdf(1.5) - 2.0 * (-3.0f64).exp()).abs() < 1e-15);
+    }
+    #[test]
     fn test_zero() {
         let d = Exp::new(0.0).unwrap();
         assert_eq!(d.sample(&mut crate::test::rng(21)), f64::infinity());
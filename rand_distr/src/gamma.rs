@@ -550,6 +550,17 @@ struct BC<N> {
 
 /// The Beta distribution with shape parameters `alpha` and `beta`.
 ///
+/// # Algorithm
+///
+/// Samples are not computed as the ratio `X / (X + Y)` of two Gamma
+/// variates; instead, one of Cheng's rejection algorithms is selected on
+/// construction: BB if `min(alpha, beta) > 1`, and BC otherwise. Both need
+/// two uniform variates per iteration, and the expected number of
+/// iterations is bounded for all parameters, including small ones (where
+/// the Gamma ratio is slow and loses precision). See
+/// R. C. H. Cheng (1978), *Generating beta variates with nonintegral shape
+/// parameters*, Communications of the ACM 21, 317–322.
+///
 /// # Example
 ///
 /// ```
@@ -811,6 +822,30 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_beta_moments() {
+        let mut rng = crate::test::rng(309);
+        let n = 20_000;
+        for &(a, b) in &[(0.2, 3.0), (0.5, 0.5), (1.0, 1.0), (0.9, 40.0), (2.0, 5.0), (30.0, 10.0)] {
+            let beta = Beta::<f64>::new(a, b).unwrap();
+            let (mut sum, mut sum_sq) = (0.0, 0.0);
+            for _ in 0..n {
+                let x = beta.sample(&mut rng);
+                assert!(x > 0.0 && x < 1.0, "Beta({}, {}) sampled {}", a, b, x);
+                sum += x;
+                sum_sq += x * x;
+            }
+            let mean = sum / n as f64;
+            let var = sum_sq / n as f64 - mean * mean;
+            let expected_mean = a / (a + b);
+            let expected_var = a * b / ((a + b) * (a + b) * (a + b + 1.0));
+            // Within 5 standard errors of the mean
+            let tol = 5.0 * (expected_var / n as f64).sqrt();
+            assert!((mean - expected_mean).abs() < tol, "Beta({}, {}): mean {}", a, b, mean);
+            assert!((var / expected_var - 1.0).abs() < 0.1, "Beta({}, {}): variance {}", a, b, var);
+        }
+    }
+
     #[test]
     fn gamma_distributions_can_be_compared() {
         assert_eq!(Gamma::new(1.0, 2.0), Gamma::new(1.0, 2.0));
@@ -61,7 +61,9 @@ impl Geometric {
     pub fn new(p: f64) -> Result<Self, Error> {
         if !p.is_finite() || p < 0.0 || p > 1.0 {
             Err(Error::InvalidProbability)
-        } else if p == 0.0 || p >= 2.0 / 3.0 {
+        } else if p == 0.0 || p >= 2.0 / 3.0 || 1.0 - p == 1.0 {
+            // For tiny `p`, `1 - p` rounds to `1`; such `p` use the inverse
+            // CDF (the `k == 0` case in `sample`).
             Ok(Geometric { p, pi: p, k: 0 })
         } else {
             let (pi, k) = {
@@ -98,6 +100,13 @@ impl Distribution<u64> for Geometric
 
         let Geometric { p, pi, k } = *self;
 
+        if k == 0 {
+            // Inverse CDF: `floor(ln(U) / ln(1 - p))` where `ln(1 - p) = -p`
+            // to within rounding. The cast saturates at `u64::MAX`.
+            let u = 1.0 - rng.gen::<f64>();
+            return (-u.ln() / p).floor() as u64;
+        }
+
         // Based on the algorithm presented in section 3 of
         // Karl Bringmann and Tobias Friedrich (July 2013) - Exact and Efficient
         // Generation of Geometric Random Variates and Random Graphs, published
@@ -137,6 +146,58 @@ impl Distribution<u64> for Geometric
     }
 }
 
+/// The geometric distribution capped at a maximum value.
+///
+/// This is the number of failures before the first success in a series of
+/// Bernoulli trials with success probability `p`, but at most `max`. For
+/// example, this models the number of retries of an operation with a retry
+/// limit. Samples are `min(k, max)` for `k` sampled from
+/// [`Geometric`](crate::Geometric), thus `max` is returned with probability
+/// `(1 - p)^max`.
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::{CappedGeometric, Distribution};
+///
+/// let retries = CappedGeometric::new(0.3, 5).unwrap();
+/// let v = retries.sample(&mut rand::thread_rng());
+/// assert!(v <= 5);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde1", derive(serde::Serialize, serde::Deserialize))]
+pub struct CappedGeometric {
+    geometric: Geometric,
+    max: u64,
+}
+
+impl CappedGeometric {
+    /// Construct a new `CappedGeometric` with success probability `p` and
+    /// maximum `max`.
+    ///
+    /// Requires `0 < p <= 1`.
+    pub fn new(p: f64, max: u64) -> Result<Self, Error> {
+        if !(p > 0.0) {
+            return Err(Error::InvalidProbability);
+        }
+        Ok(CappedGeometric {
+            geometric: Geometric::new(p)?,
+            max,
+        })
+    }
+
+    /// The maximum value
+    pub fn max(&self) -> u64 {
+        self.max
+    }
+}
+
+impl Distribution<u64> for CappedGeometric {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> u64 {
+        self.geometric.sample(rng).min(self.max)
+    }
+}
+
 /// Samples integers according to the geometric distribution with success
 /// probability `p = 0.5`. This is equivalent to `Geometeric::new(0.5)`,
 /// but faster.
@@ -215,6 +276,57 @@ mod test {
         test_geo_mean_and_variance(0.90, &mut rng);
     }
 
+    #[test]
+    fn test_geometric_extreme_p() {
+        let mut rng = crate::test::rng(293);
+
+        // With `p == 1` the first trial succeeds: zero failures
+        let distr = Geometric::new(1.0).unwrap();
+        for _ in 0..100 {
+            assert_eq!(distr.sample(&mut rng), 0);
+        }
+
+        // `1 - p` rounds to `1` for these
+        for &p in &[1e-17, 5e-17] {
+            let distr = Geometric::new(p).unwrap();
+            let n = 10000;
+            let mean = (0..n).map(|_| distr.sample(&mut rng) as f64).sum::<f64>() / n as f64;
+            let expected_mean = (1.0 - p) / p;
+            assert!((mean - expected_mean).abs() < expected_mean / 20.0);
+        }
+
+        // Results saturate rather than overflow
+        let distr = Geometric::new(1e-300).unwrap();
+        for _ in 0..100 {
+            assert!(distr.sample(&mut rng) > 1 << 60);
+        }
+    }
+
+    #[test]
+    fn test_capped_geometric() {
+        assert!(CappedGeometric::new(0.0, 5).is_err());
+        assert!(CappedGeometric::new(-0.1, 5).is_err());
+        assert!(CappedGeometric::new(1.5, 5).is_err());
+
+        let mut rng = crate::test::rng(295);
+        for &(p, max) in &[(0.2, 5u64), (0.5, 3), (0.05, 10), (1.0, 4), (0.3, 0)] {
+            let distr = CappedGeometric::new(p, max).unwrap();
+            let n = 20000;
+            let mut at_max = 0;
+            for _ in 0..n {
+                let v = distr.sample(&mut rng);
+                assert!(v <= max);
+                if v == max {
+                    at_max += 1;
+                }
+            }
+            // The mass at `max` is the tail of the geometric distribution
+            let expected = (1.0 - p).powi(max as i32);
+            let freq = at_max as f64 / n as f64;
+            assert!((freq - expected).abs() < 0.015, "p {} max {}: {}", p, max, freq);
+        }
+    }
+
     #[test]
     fn test_standard_geometric() {
         let mut rng = crate::test::rng(654321);
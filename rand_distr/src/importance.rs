@@ -0,0 +1,103 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Self-normalized importance sampling.
+
+#![cfg(feature = "alloc")]
+use crate::Density;
+use alloc::vec::Vec;
+use rand::Rng;
+
+/// Draw `n` samples from `proposal` with normalized importance weights for
+/// the density `target_pdf`.
+///
+/// Returns `(sample, weight)` pairs, where the weight of each sample `x` is
+/// `target_pdf(x) / proposal.pdf(x)`, normalized such that the weights sum to
+/// `1`. The target density need not be normalized. Expectations under the
+/// target distribution may then be estimated as weighted sums over the
+/// samples.
+///
+/// Samples at which the proposal has zero density are given zero weight.
+///
+/// # Panics
+///
+/// Panics if `n > 0` and the weights do not have a positive, finite sum
+/// (e.g. if `target_pdf` is zero at all samples).
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::{importance_sample, Normal};
+///
+/// // Estimate the mean of the density 2x on [0, 1)
+/// let proposal = Normal::new(0.5, 0.5).unwrap();
+/// let target = |x: f64| if (0.0..1.0).contains(&x) { 2.0 * x } else { 0.0 };
+/// let samples = importance_sample(&mut rand::thread_rng(), &proposal, target, 10000);
+/// let mean: f64 = samples.iter().map(|&(x, w)| x * w).sum();
+/// assert!((mean - 2.0 / 3.0).abs() < 0.05);
+/// ```
+pub fn importance_sample<R, P, F>(rng: &mut R, proposal: &P, target_pdf: F, n: usize) -> Vec<(f64, f64)>
+where
+    R: Rng + ?Sized,
+    P: Density<f64> + ?Sized,
+    F: Fn(f64) -> f64,
+{
+    let mut samples: Vec<(f64, f64)> = Vec::with_capacity(n);
+    let mut total = 0.0;
+    for _ in 0..n {
+        let x = proposal.sample(rng);
+        let q = proposal.pdf(x);
+        let w = if q > 0.0 { target_pdf(x) / q } else { 0.0 };
+        total += w;
+        samples.push((x, w));
+    }
+
+    if n > 0 {
+        assert!(
+            total > 0.0 && total.is_finite(),
+            "importance_sample: weights must have a positive, finite sum"
+        );
+    }
+    for sample in &mut samples {
+        sample.1 /= total;
+    }
+    samples
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Normal;
+
+    #[test]
+    fn test_importance_sample() {
+        let mut rng = crate::test::rng(287);
+        let proposal = Normal::new(1.0, 1.0).unwrap();
+
+        // Target: triangle on [0, 2] with peak at 1 (unnormalized)
+        let target = |x: f64| if (0.0..2.0).contains(&x) { 1.0 - (x - 1.0).abs() } else { 0.0 };
+        let samples = importance_sample(&mut rng, &proposal, target, 20000);
+        assert_eq!(samples.len(), 20000);
+
+        let sum: f64 = samples.iter().map(|&(_, w)| w).sum();
+        assert!((sum - 1.0).abs() < 1e-9);
+        assert!(samples.iter().all(|&(x, w)| w >= 0.0 && (w > 0.0) == (x > 0.0 && x < 2.0)));
+
+        let mean: f64 = samples.iter().map(|&(x, w)| x * w).sum();
+        assert!((mean - 1.0).abs() < 0.02);
+
+        assert!(importance_sample(&mut rng, &proposal, target, 0).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_importance_sample_zero_target() {
+        let proposal = Normal::new(0.0, 1.0).unwrap();
+        importance_sample(&mut crate::test::rng(288), &proposal, |_| 0.0, 10);
+    }
+}
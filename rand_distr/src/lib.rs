@@ -48,7 +48,7 @@
 //!   - [`Cauchy`] distribution
 //! - Related to Bernoulli trials (yes/no events, with a given probability):
 //!   - [`Binomial`] distribution
-//!   - [`Geometric`] distribution
+//!   - [`Geometric`] distribution, and [`CappedGeometric`] with a maximum
 //!   - [`Hypergeometric`] distribution
 //! - Related to positive real-valued quantities that grow exponentially
 //!   (e.g. prices, incomes, populations):
@@ -76,11 +76,23 @@
 //!   - [`UnitBall`] distribution
 //!   - [`UnitCircle`] distribution
 //!   - [`UnitDisc`] distribution
+//!   - [`GaussianCopula2D`] distribution of correlated uniform pairs
+//!   - [`MultivariateNormal`] distribution
 //! - Alternative implementation for weighted index sampling
 //!   - [`WeightedAliasIndex`] distribution
 //! - Misc. distributions
 //!   - [`InverseGaussian`] distribution
 //!   - [`NormalInverseGaussian`] distribution
+//! - Distributions described by data
+//!   - [`PiecewiseLinear`] density interpolated between points
+//!   - [`MarkovChain`] walks on a table of transition weights
+//! - Point sets
+//!   - [`PoissonDisk`] points with a minimum distance between them
+//! - Probability densities
+//!   - The [`Density`] trait, and [`importance_sample`] to estimate
+//!     expectations under a density
+//! - Synthetic data
+//!   - [`TimeSeries`] with trend, seasonality and noise
 
 #[cfg(feature = "alloc")]
 extern crate alloc;
@@ -93,12 +105,14 @@ extern crate std;
 use rand::Rng;
 
 pub use rand::distributions::{
-    uniform, Alphanumeric, Bernoulli, BernoulliError, DistIter, Distribution, Open01, OpenClosed01,
-    Standard, Uniform,
+    uniform, Alphanumeric, Bernoulli, BernoulliError, DistIter, Distribution, Open01,
+    OpenClosed01, Standard, Uniform,
 };
 
 pub use self::binomial::{Binomial, Error as BinomialError};
 pub use self::cauchy::{Cauchy, Error as CauchyError};
+pub use self::copula::{Error as GaussianCopula2DError, GaussianCopula2D};
+pub use self::density::Density;
 #[cfg(feature = "alloc")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
 pub use self::dirichlet::{Dirichlet, Error as DirichletError};
@@ -108,19 +122,37 @@ pub use self::gamma::{
     Beta, BetaError, ChiSquared, ChiSquaredError, Error as GammaError, FisherF, FisherFError,
     Gamma, StudentT,
 };
-pub use self::geometric::{Error as GeoError, Geometric, StandardGeometric};
+pub use self::geometric::{CappedGeometric, Error as GeoError, Geometric, StandardGeometric};
 pub use self::gumbel::{Error as GumbelError, Gumbel};
 pub use self::hypergeometric::{Error as HyperGeoError, Hypergeometric};
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+pub use self::importance::importance_sample;
 pub use self::inverse_gaussian::{Error as InverseGaussianError, InverseGaussian};
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+pub use self::markov::{Error as MarkovChainError, MarkovChain};
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+pub use self::multivariate_normal::{Error as MultivariateNormalError, MultivariateNormal};
 pub use self::normal::{Error as NormalError, LogNormal, Normal, StandardNormal};
 pub use self::normal_inverse_gaussian::{
     Error as NormalInverseGaussianError, NormalInverseGaussian,
 };
 pub use self::pareto::{Error as ParetoError, Pareto};
 pub use self::pert::{Pert, PertError};
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+pub use self::piecewise_linear::{Error as PiecewiseLinearError, PiecewiseLinear};
 pub use self::poisson::{Error as PoissonError, Poisson};
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+pub use self::poisson_disk::PoissonDisk;
 pub use self::skew_normal::{Error as SkewNormalError, SkewNormal};
 pub use self::triangular::{Triangular, TriangularError};
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+pub use self::time_series::TimeSeries;
 pub use self::unit_ball::UnitBall;
 pub use self::unit_circle::UnitCircle;
 pub use self::unit_disc::UnitDisc;
@@ -189,6 +221,8 @@ pub mod weighted_alias;
 
 mod binomial;
 mod cauchy;
+mod copula;
+mod density;
 mod dirichlet;
 mod exponential;
 mod frechet;
@@ -196,13 +230,19 @@ mod gamma;
 mod geometric;
 mod gumbel;
 mod hypergeometric;
+mod importance;
 mod inverse_gaussian;
+mod markov;
+mod multivariate_normal;
 mod normal;
 mod normal_inverse_gaussian;
 mod pareto;
 mod pert;
+mod piecewise_linear;
 mod poisson;
+mod poisson_disk;
 mod skew_normal;
+mod time_series;
 mod triangular;
 mod unit_ball;
 mod unit_circle;
@@ -0,0 +1,196 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Finite Markov chains.
+
+#![cfg(feature = "alloc")]
+use crate::{Distribution, WeightedIndex};
+use alloc::vec::Vec;
+use core::fmt;
+use rand::Rng;
+
+/// A walk on a finite Markov chain with states `0..n`.
+///
+/// The chain is given by a square transition matrix, where
+/// `transitions[i][j]` is the relative likelihood of moving from state `i` to
+/// state `j`. Rows need not be normalized. The walk starts in state `0`; use
+/// [`MarkovChain::set_state`] to start elsewhere.
+///
+/// Each step samples the current row with a [`WeightedIndex`] (built once per
+/// row), taking `O(log n)` time.
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::MarkovChain;
+///
+/// // Weather: 0 = sunny, 1 = rainy
+/// let mut chain = MarkovChain::new(vec![vec![0.9, 0.1], vec![0.5, 0.5]]).unwrap();
+/// let mut rng = rand::thread_rng();
+/// let week: Vec<usize> = (0..7).map(|_| chain.next(&mut rng)).collect();
+/// println!("{:?}", week);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde1", derive(serde::Serialize, serde::Deserialize))]
+pub struct MarkovChain {
+    rows: Vec<WeightedIndex<f64>>,
+    state: usize,
+}
+
+/// Error type returned from `MarkovChain::new`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The matrix is empty or not square.
+    NotSquare,
+    /// A row has a negative or non-finite entry, or sums to zero.
+    InvalidRow,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Error::NotSquare => "transition matrix empty or not square in Markov chain",
+            Error::InvalidRow => {
+                "transition row negative, not finite or summing to zero in Markov chain"
+            }
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
+impl std::error::Error for Error {}
+
+impl MarkovChain {
+    /// Construct from a transition matrix, starting in state `0`.
+    ///
+    /// Requires the matrix to be non-empty and square, and each row to have
+    /// finite, non-negative entries with a positive sum.
+    pub fn new(transitions: Vec<Vec<f64>>) -> Result<MarkovChain, Error> {
+        let n = transitions.len();
+        if n == 0 || transitions.iter().any(|row| row.len() != n) {
+            return Err(Error::NotSquare);
+        }
+        let rows = transitions
+            .iter()
+            .map(|row| {
+                let mut sum = 0.0;
+                for &p in row {
+                    if !(p >= 0.0 && p.is_finite()) {
+                        return Err(Error::InvalidRow);
+                    }
+                    sum += p;
+                }
+                if !(sum > 0.0 && sum.is_finite()) {
+                    return Err(Error::InvalidRow);
+                }
+                let normalized = row.iter().map(|p| p / sum);
+                WeightedIndex::new(normalized).map_err(|_| Error::InvalidRow)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(MarkovChain { rows, state: 0 })
+    }
+
+    /// Returns the number of states.
+    pub fn num_states(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Returns the current state.
+    pub fn state(&self) -> usize {
+        self.state
+    }
+
+    /// Set the current state.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `state` is not less than the number of states.
+    pub fn set_state(&mut self, state: usize) {
+        assert!(state < self.rows.len(), "MarkovChain::set_state: state out of range");
+        self.state = state;
+    }
+
+    /// Advance the chain by one step and return the new state.
+    pub fn next<R: Rng + ?Sized>(&mut self, rng: &mut R) -> usize {
+        self.state = self.rows[self.state].sample(rng);
+        self.state
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_markov_chain_stationary() {
+        let mut rng = crate::test::rng(300);
+        // A birth-death chain with stationary distribution [1/4, 1/2, 1/4]
+        let mut chain = MarkovChain::new(vec![
+            vec![1.0, 1.0, 0.0],
+            vec![1.0, 2.0, 1.0],
+            vec![0.0, 1.0, 1.0],
+        ])
+        .unwrap();
+        assert_eq!(chain.num_states(), 3);
+
+        let n = 60_000;
+        let mut counts = [0usize; 3];
+        let mut prev = chain.state();
+        for _ in 0..n {
+            let state = chain.next(&mut rng);
+            assert_eq!(state, chain.state());
+            // The states 0 and 2 are not adjacent
+            assert!(prev.max(state) - prev.min(state) <= 1);
+            counts[state] += 1;
+            prev = state;
+        }
+        for (&count, &p) in counts.iter().zip([0.25, 0.5, 0.25].iter()) {
+            assert!((count as f64 / n as f64 - p).abs() < 0.015, "{:?}", counts);
+        }
+    }
+
+    #[test]
+    fn test_markov_chain_set_state() {
+        let mut rng = crate::test::rng(301);
+        // A deterministic cycle 0 -> 1 -> 2 -> 0
+        let mut chain = MarkovChain::new(vec![
+            vec![0.0, 0.5, 0.0],
+            vec![0.0, 0.0, 3.0],
+            vec![1.0, 0.0, 0.0],
+        ])
+        .unwrap();
+        assert_eq!(chain.state(), 0);
+        chain.set_state(2);
+        let walk: Vec<usize> = (0..4).map(|_| chain.next(&mut rng)).collect();
+        assert_eq!(walk, [0, 1, 2, 0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_markov_chain_set_state_out_of_range() {
+        MarkovChain::new(vec![vec![1.0]]).unwrap().set_state(1);
+    }
+
+    #[test]
+    fn test_markov_chain_errors() {
+        use Error::*;
+        assert_eq!(MarkovChain::new(vec![]), Err(NotSquare));
+        assert_eq!(MarkovChain::new(vec![vec![1.0, 1.0]]), Err(NotSquare));
+        assert_eq!(MarkovChain::new(vec![vec![1.0], vec![1.0, 1.0]]), Err(NotSquare));
+        assert_eq!(MarkovChain::new(vec![vec![1.0, 0.0], vec![0.0, 0.0]]), Err(InvalidRow));
+        assert_eq!(MarkovChain::new(vec![vec![1.0, -1.0], vec![0.0, 1.0]]), Err(InvalidRow));
+        assert_eq!(
+            MarkovChain::new(vec![vec![1.0, f64::INFINITY], vec![0.0, 1.0]]),
+            Err(InvalidRow)
+        );
+        assert_eq!(MarkovChain::new(vec![vec![f64::NAN]]), Err(InvalidRow));
+        assert_eq!(MarkovChain::new(vec![vec![f64::MAX, f64::MAX], vec![1.0, 1.0]]), Err(InvalidRow));
+    }
+}
@@ -0,0 +1,243 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The multivariate normal distribution.
+#![cfg(feature = "alloc")]
+use crate::{Distribution, StandardNormal};
+use alloc::vec::Vec;
+use core::fmt;
+use num_traits::Float;
+use rand::Rng;
+
+/// The multivariate normal distribution `N(mean, cov)`.
+///
+/// Samples are vectors of the same dimension as `mean`. The Cholesky
+/// factorisation `cov = L Lᵀ` is computed on construction, and each sample
+/// is `mean + L z` for a vector `z` of independent standard normal variates.
+/// Construction costs `O(n³)` and sampling `O(n²)` for dimension `n`.
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::{Distribution, MultivariateNormal};
+///
+/// let mvn = MultivariateNormal::new(
+///     vec![0.0, 10.0],
+///     vec![vec![1.0, 0.5], vec![0.5, 2.0]],
+/// ).unwrap();
+/// let v = mvn.sample(&mut rand::thread_rng());
+/// assert_eq!(v.len(), 2);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde1", derive(serde::Serialize, serde::Deserialize))]
+pub struct MultivariateNormal {
+    mean: Vec<f64>,
+    /// The lower-triangular Cholesky factor, row-major
+    chol: Vec<f64>,
+}
+
+/// Error type returned from `MultivariateNormal::new`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The covariance matrix is not square with the dimension of the mean.
+    DimensionMismatch,
+    /// The mean or covariance matrix contains infinite or `nan` values.
+    NonFinite,
+    /// The covariance matrix is not symmetric.
+    NotSymmetric,
+    /// The covariance matrix is not positive-definite.
+    NotPositiveDefinite,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Error::DimensionMismatch => {
+                "covariance matrix is not square with the dimension of the mean in multivariate normal distribution"
+            }
+            Error::NonFinite => "mean or covariance is infinite or nan in multivariate normal distribution",
+            Error::NotSymmetric => "covariance matrix is not symmetric in multivariate normal distribution",
+            Error::NotPositiveDefinite => {
+                "covariance matrix is not positive-definite in multivariate normal distribution"
+            }
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
+impl std::error::Error for Error {}
+
+impl MultivariateNormal {
+    /// Construct a new `MultivariateNormal` from the `mean` vector and the
+    /// covariance matrix `cov`, given as a vector of rows.
+    ///
+    /// `cov` must be square with the dimension of `mean`, symmetric (up to a
+    /// relative tolerance of `1e-12`) and positive-definite.
+    pub fn new(mean: Vec<f64>, cov: Vec<Vec<f64>>) -> Result<MultivariateNormal, Error> {
+        if cov.len() != mean.len() || cov.iter().any(|row| row.len() != mean.len()) {
+            return Err(Error::DimensionMismatch);
+        }
+        Self::from_row_major(mean, cov.concat())
+    }
+
+    /// Construct a new `MultivariateNormal` from the `mean` vector and the
+    /// covariance matrix `cov`, given as a flat row-major vector of length
+    /// `n * n` for dimension `n`.
+    ///
+    /// The requirements on `cov` are those of [`MultivariateNormal::new`].
+    pub fn from_row_major(mean: Vec<f64>, mut cov: Vec<f64>) -> Result<MultivariateNormal, Error> {
+        let n = mean.len();
+        if cov.len() != n * n {
+            return Err(Error::DimensionMismatch);
+        }
+        if !mean.iter().chain(cov.iter()).all(|x| x.is_finite()) {
+            return Err(Error::NonFinite);
+        }
+        for i in 0..n {
+            for j in 0..i {
+                let (a, b) = (cov[i * n + j], cov[j * n + i]);
+                if (a - b).abs() > 1e-12 * (a.abs() + b.abs()) {
+                    return Err(Error::NotSymmetric);
+                }
+            }
+        }
+
+        // Cholesky–Banachiewicz, in place on the lower triangle
+        for i in 0..n {
+            for j in 0..=i {
+                let dot: f64 = (0..j).map(|k| cov[i * n + k] * cov[j * n + k]).sum();
+                let x = cov[i * n + j] - dot;
+                if i == j {
+                    if !(x > 0.0) {
+                        return Err(Error::NotPositiveDefinite);
+                    }
+                    cov[i * n + i] = Float::sqrt(x);
+                } else {
+                    cov[i * n + j] = x / cov[j * n + j];
+                }
+            }
+            for j in i + 1..n {
+                cov[i * n + j] = 0.0;
+            }
+        }
+        Ok(MultivariateNormal { mean, chol: cov })
+    }
+
+    /// Returns the dimension of samples.
+    pub fn dimension(&self) -> usize {
+        self.mean.len()
+    }
+
+    /// Returns the mean vector.
+    pub fn mean(&self) -> &[f64] {
+        &self.mean
+    }
+}
+
+impl Distribution<Vec<f64>> for MultivariateNormal {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec<f64> {
+        let n = self.mean.len();
+        let z: Vec<f64> = (0..n).map(|_| rng.sample(StandardNormal)).collect();
+        (0..n)
+            .map(|i| {
+                let row = &self.chol[i * n..i * n + i + 1];
+                self.mean[i] + row.iter().zip(&z).map(|(l, z)| l * z).sum::<f64>()
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_multivariate_normal_cholesky() {
+        let mvn = MultivariateNormal::new(
+            vec![0.0; 3],
+            vec![vec![4.0, 2.0, -2.0], vec![2.0, 10.0, 2.0], vec![-2.0, 2.0, 6.0]],
+        )
+        .unwrap();
+        let expected = [2.0, 0.0, 0.0, 1.0, 3.0, 0.0, -1.0, 1.0, 2.0];
+        for (a, b) in mvn.chol.iter().zip(expected.iter()) {
+            assert_almost_eq!(a, b, 1e-12);
+        }
+        assert_eq!(mvn.dimension(), 3);
+    }
+
+    #[test]
+    fn test_multivariate_normal_moments() {
+        let mean = [1.0, -2.0, 0.5];
+        let cov = [[4.0, 1.2, 0.0], [1.2, 2.0, -0.5], [0.0, -0.5, 1.0]];
+        let mvn = MultivariateNormal::new(mean.to_vec(), cov.iter().map(|r| r.to_vec()).collect())
+            .unwrap();
+        let mut rng = crate::test::rng(307);
+        let n = 50_000;
+        let samples: Vec<Vec<f64>> = (0..n).map(|_| mvn.sample(&mut rng)).collect();
+
+        let mut emp_mean = [0.0; 3];
+        for s in &samples {
+            for i in 0..3 {
+                emp_mean[i] += s[i] / n as f64;
+            }
+        }
+        for i in 0..3 {
+            assert_almost_eq!(emp_mean[i], mean[i], 0.05);
+            for j in 0..3 {
+                let c = samples
+                    .iter()
+                    .map(|s| (s[i] - emp_mean[i]) * (s[j] - emp_mean[j]))
+                    .sum::<f64>()
+                    / n as f64;
+                assert_almost_eq!(c, cov[i][j], 0.1);
+            }
+        }
+    }
+
+    #[test]
+    fn test_multivariate_normal_row_major() {
+        let a = MultivariateNormal::new(vec![1.0, 2.0], vec![vec![2.0, 1.0], vec![1.0, 2.0]]);
+        let b = MultivariateNormal::from_row_major(vec![1.0, 2.0], vec![2.0, 1.0, 1.0, 2.0]);
+        assert_eq!(a, b);
+
+        let empty = MultivariateNormal::new(vec![], vec![]).unwrap();
+        assert!(empty.sample(&mut crate::test::rng(308)).is_empty());
+    }
+
+    #[test]
+    fn test_multivariate_normal_invalid() {
+        let new = MultivariateNormal::new;
+        assert_eq!(new(vec![0.0; 2], vec![vec![1.0, 0.0]]), Err(Error::DimensionMismatch));
+        assert_eq!(
+            new(vec![0.0; 2], vec![vec![1.0, 0.0], vec![0.0]]),
+            Err(Error::DimensionMismatch)
+        );
+        assert_eq!(
+            MultivariateNormal::from_row_major(vec![0.0; 2], vec![1.0; 3]),
+            Err(Error::DimensionMismatch)
+        );
+        assert_eq!(
+            new(vec![f64::NAN], vec![vec![1.0]]),
+            Err(Error::NonFinite)
+        );
+        assert_eq!(
+            new(vec![0.0; 2], vec![vec![1.0, 0.5], vec![0.4, 1.0]]),
+            Err(Error::NotSymmetric)
+        );
+        assert_eq!(
+            new(vec![0.0; 2], vec![vec![1.0, 2.0], vec![2.0, 1.0]]),
+            Err(Error::NotPositiveDefinite)
+        );
+        assert_eq!(
+            new(vec![0.0; 2], vec![vec![1.0, 1.0], vec![1.0, 1.0]]),
+            Err(Error::NotPositiveDefinite)
+        );
+    }
+}
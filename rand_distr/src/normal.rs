@@ -9,9 +9,9 @@
 
 //! The normal and derived distributions.
 
-use crate::utils::ziggurat;
+use crate::utils::{normal_inverse_cdf, ziggurat};
 use num_traits::Float;
-use crate::{ziggurat_tables, Distribution, Open01};
+use crate::{ziggurat_tables, Density, Distribution, Open01};
 use rand::Rng;
 use core::fmt;
 
@@ -40,6 +40,38 @@ use core::fmt;
 #[cfg_attr(feature = "serde1", derive(serde::Serialize, serde::Deserialize))]
 pub struct StandardNormal;
 
+impl StandardNormal {
+    /// Transform a uniform value in `[0, 1]` to a standard normal value.
+    ///
+    /// This is the inverse of the cumulative distribution function (the
+    /// probit function), thus maps `u` sampled uniformly from `(0, 1)` to a
+    /// sample of `N(0, 1)`. It allows supplying uniforms from another source,
+    /// e.g. quasi-random sequences or antithetic pairs `u` and `1 - u`, which
+    /// map to `z` and (up to rounding) `-z`. Sampling `StandardNormal`
+    /// directly uses the faster Ziggurat method, thus produces other values.
+    ///
+    /// Uses algorithm AS 241[^1], with a relative error of about `1e-16`.
+    /// Returns negative or positive infinity for `0` and `1` respectively,
+    /// and NaN if `u` is outside `[0, 1]`.
+    ///
+    /// [^1]: Michael J. Wichura (1988). *Algorithm AS 241: The Percentage
+    ///       Points of the Normal Distribution*. Journal of the Royal
+    ///       Statistical Society, Series C 37(3), 477–484.
+    ///
+    /// # Example
+    /// ```
+    /// use rand::prelude::*;
+    /// use rand_distr::{Open01, StandardNormal};
+    ///
+    /// let u: f64 = thread_rng().sample(Open01);
+    /// let (z1, z2) = (StandardNormal::from_uniform(u), StandardNormal::from_uniform(1.0 - u));
+    /// assert!((z1 + z2).abs() < 1e-9);
+    /// ```
+    pub fn from_uniform(u: f64) -> f64 {
+        normal_inverse_cdf(u)
+    }
+}
+
 impl Distribution<f32> for StandardNormal {
     #[inline]
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f32 {
@@ -211,6 +243,13 @@ where F: Float, StandardNormal: Distribution<F>
     }
 }
 
+impl Density<f64> for Normal<f64> {
+    fn pdf(&self, x: f64) -> f64 {
+        let z = (x - self.mean) / self.std_dev;
+        Float::exp(-0.5 * z * z) / (self.std_dev * Float::sqrt(2.0 * core::f64::consts::PI))
+    }
+}
+
 
 /// The log-normal distribution `ln N(mean, std_dev**2)`.
 ///
@@ -328,6 +367,51 @@ mod tests {
         }
     }
     #[test]
+    fn test_standard_normal_from_uniform() {
+        assert_eq!(StandardNormal::from_uniform(0.5), 0.0);
+        assert_eq!(StandardNormal::from_uniform(0.0), f64::NEG_INFINITY);
+        assert_eq!(StandardNormal::from_uniform(1.0), f64::INFINITY);
+        assert!(StandardNormal::from_uniform(-0.1).is_nan());
+        assert!(StandardNormal::from_uniform(1.5).is_nan());
+        assert!(StandardNormal::from_uniform(f64::NAN).is_nan());
+
+        // Reference values, covering the central and both tail regions
+        let cases = [
+            (0.975, 1.959963984540054),
+            (0.8413447460685429, 1.0),
+            (0.15865525393145707, -1.0),
+            (0.0013498980316300946, -3.0),
+            (2.866515718791939e-7, -5.0),
+            (1e-10, -6.3613409024040575),
+            (1e-300, -37.04709629936121),
+        ];
+        for &(u, z) in &cases {
+            assert_almost_eq!(StandardNormal::from_uniform(u), z, 1e-14 * z.abs());
+        }
+
+        // Antithetic uniforms yield negated values
+        let mut rng = crate::test::rng(212);
+        for _ in 0..1000 {
+            let u: f64 = rng.sample(Open01);
+            let z = StandardNormal::from_uniform(u);
+            assert!(z.is_finite());
+            assert_almost_eq!(StandardNormal::from_uniform(1.0 - u), -z, 1e-9);
+            if u >= 0.5 {
+                // `1 - u` and `1 - (1 - u)` are exact
+                assert_eq!(StandardNormal::from_uniform(1.0 - u), -z);
+            }
+        }
+    }
+
+    #[test]
+    fn test_normal_pdf() {
+        let norm = Normal::new(1.0, 2.0).unwrap();
+        let peak = 1.0 / (2.0 * (2.0 * core::f64::consts::PI).sqrt());
+        assert!((norm.pdf(1.0) - peak).abs() < 1e-15);
+        assert!((norm.pdf(3.0) - peak * (-0.5f64).exp()).abs() < 1e-15);
+        assert_eq!(norm.pdf(0.0), norm.pdf(2.0));
+    }
+    #[test]
     fn test_normal_cv() {
         let norm = Normal::from_mean_cv(1024.0, 1.0 / 256.0).unwrap();
         assert_eq!((norm.mean, norm.std_dev), (1024.0, 4.0));
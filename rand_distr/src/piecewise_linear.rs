@@ -0,0 +1,209 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Piecewise-linear densities.
+
+#![cfg(feature = "alloc")]
+use crate::Distribution;
+use alloc::vec::Vec;
+use core::fmt;
+use rand::Rng;
+
+/// A continuous distribution with a piecewise-linear density.
+///
+/// The density is given at points `x_0 < x_1 < ... < x_n` and linearly
+/// interpolated between them; it is zero outside `[x_0, x_n]`. Densities need
+/// not be normalized.
+///
+/// A segment is chosen by inverting the cumulative distribution over segment
+/// areas (`O(log n)` time). Within a segment, the trapezoidal density is
+/// sampled as a mixture of a uniform and a triangular part, which avoids
+/// square roots (and so works without `std`).
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::{Distribution, PiecewiseLinear};
+///
+/// // A triangular distribution on [0, 2] with mode 1
+/// let dist = PiecewiseLinear::new(&[(0.0, 0.0), (1.0, 1.0), (2.0, 0.0)]).unwrap();
+/// let x = dist.sample(&mut rand::thread_rng());
+/// assert!((0.0..=2.0).contains(&x));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde1", derive(serde::Serialize, serde::Deserialize))]
+pub struct PiecewiseLinear {
+    points: Vec<(f64, f64)>,
+    /// Inclusive cumulative area of the segments
+    cumulative_areas: Vec<f64>,
+}
+
+/// Error type returned from `PiecewiseLinear::new`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// Fewer than two points.
+    TooFewPoints,
+    /// An `x` is not finite or not greater than the previous `x`.
+    NotIncreasing,
+    /// A density is negative or not finite.
+    InvalidDensity,
+    /// The total area is zero or not finite.
+    InvalidArea,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Error::TooFewPoints => "fewer than two points in PiecewiseLinear distribution",
+            Error::NotIncreasing => {
+                "x not finite and strictly increasing in PiecewiseLinear distribution"
+            }
+            Error::InvalidDensity => {
+                "density negative or not finite in PiecewiseLinear distribution"
+            }
+            Error::InvalidArea => {
+                "total area zero or not finite in PiecewiseLinear distribution"
+            }
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
+impl std::error::Error for Error {}
+
+impl PiecewiseLinear {
+    /// Construct from `(x, density)` pairs.
+    ///
+    /// Requires at least two points, `x` to be finite and strictly increasing,
+    /// densities to be finite and non-negative, and the total area to be
+    /// positive and finite.
+    pub fn new(points: &[(f64, f64)]) -> Result<PiecewiseLinear, Error> {
+        if points.len() < 2 {
+            return Err(Error::TooFewPoints);
+        }
+        for (i, &(x, d)) in points.iter().enumerate() {
+            if !x.is_finite() || (i > 0 && !(x > points[i - 1].0)) {
+                return Err(Error::NotIncreasing);
+            }
+            if !(d >= 0.0 && d.is_finite()) {
+                return Err(Error::InvalidDensity);
+            }
+        }
+
+        let mut total = 0.0;
+        let cumulative_areas: Vec<f64> = points
+            .windows(2)
+            .map(|w| {
+                total += (w[1].0 - w[0].0) * (w[0].1 + w[1].1) * 0.5;
+                total
+            })
+            .collect();
+        if !(total > 0.0 && total.is_finite()) {
+            return Err(Error::InvalidArea);
+        }
+
+        Ok(PiecewiseLinear {
+            points: points.to_vec(),
+            cumulative_areas,
+        })
+    }
+}
+
+impl Distribution<f64> for PiecewiseLinear {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        let total = *self.cumulative_areas.last().unwrap();
+        let chosen = rng.gen::<f64>() * total;
+        // Segments of zero area are never chosen. Rounding of the product may
+        // yield `total`, in which case take the last segment of non-zero area.
+        let mut k = self.cumulative_areas.partition_point(|a| *a <= chosen);
+        if k == self.cumulative_areas.len() {
+            k = self.cumulative_areas.partition_point(|a| *a < total);
+        }
+        let ((x0, d0), (x1, d1)) = (self.points[k], self.points[k + 1]);
+
+        // The density on the segment, as a function of `t` in `[0, 1]`, is
+        // `min(d0, d1)` (uniform) plus a triangle of height `|d1 - d0|`,
+        // whose area is half that of the equivalent rectangle.
+        let triangle = (d1 - d0).abs() / (d0 + d1);
+        let t = if rng.gen::<f64>() < triangle {
+            let (u1, u2) = (rng.gen::<f64>(), rng.gen::<f64>());
+            // The maximum of two uniform variates has density `2t`
+            if d1 > d0 { u1.max(u2) } else { u1.min(u2) }
+        } else {
+            rng.gen::<f64>()
+        };
+        x0 + t * (x1 - x0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_piecewise_linear_triangular() {
+        let mut rng = crate::test::rng(293);
+        // Triangular distribution on [a, b] with mode c
+        let (a, c, b) = (1.0, 2.0, 5.0);
+        let dist = PiecewiseLinear::new(&[(a, 0.0), (c, 3.0), (b, 0.0)]).unwrap();
+        let cdf = |x: f64| {
+            if x <= c {
+                (x - a) * (x - a) / ((b - a) * (c - a))
+            } else {
+                1.0 - (b - x) * (b - x) / ((b - a) * (b - c))
+            }
+        };
+
+        let n = 20000;
+        let mut samples: Vec<f64> = (0..n).map(|_| dist.sample(&mut rng)).collect();
+        samples.sort_by(|x, y| x.partial_cmp(y).unwrap());
+        assert!(samples[0] >= a && samples[n - 1] <= b);
+
+        // Kolmogorov-Smirnov statistic
+        let ks = samples
+            .iter()
+            .enumerate()
+            .map(|(i, &x)| (cdf(x) - i as f64 / n as f64).abs())
+            .fold(0.0, f64::max);
+        assert!(ks < 0.015, "KS statistic {}", ks);
+    }
+
+    #[test]
+    fn test_piecewise_linear_segments() {
+        let mut rng = crate::test::rng(294);
+        // The middle segment has zero density; the outer ones are uniform
+        let points = [
+            (0.0, 1.0),
+            (1.0, 1.0),
+            (1.0 + 1e-9, 0.0),
+            (2.0, 0.0),
+            (2.0 + 1e-9, 3.0),
+            (3.0, 3.0),
+        ];
+        let dist = PiecewiseLinear::new(&points).unwrap();
+        let mut counts = [0u32; 2];
+        for _ in 0..8000 {
+            let x = dist.sample(&mut rng);
+            assert!(!(1.0 + 1e-9..=2.0).contains(&x), "{}", x);
+            counts[(x > 2.0) as usize] += 1;
+        }
+        assert!(counts[0] > 1800 && counts[0] < 2200);
+    }
+
+    #[test]
+    fn test_piecewise_linear_errors() {
+        use Error::*;
+        assert_eq!(PiecewiseLinear::new(&[(0.0, 1.0)]), Err(TooFewPoints));
+        assert_eq!(PiecewiseLinear::new(&[(0.0, 1.0), (0.0, 1.0)]), Err(NotIncreasing));
+        assert_eq!(PiecewiseLinear::new(&[(0.0, 1.0), (f64::NAN, 1.0)]), Err(NotIncreasing));
+        assert_eq!(PiecewiseLinear::new(&[(0.0, 1.0), (1.0, -1.0)]), Err(InvalidDensity));
+        assert_eq!(PiecewiseLinear::new(&[(0.0, 0.0), (1.0, 0.0)]), Err(InvalidArea));
+        assert_eq!(PiecewiseLinear::new(&[(f64::MIN, 1.0), (f64::MAX, 1.0)]), Err(InvalidArea));
+    }
+}
@@ -10,7 +10,7 @@
 //! The Poisson distribution.
 
 use num_traits::{Float, FloatConst};
-use crate::{Cauchy, Distribution, Standard};
+use crate::{Distribution, Standard};
 use rand::Rng;
 use core::fmt;
 
@@ -19,6 +19,16 @@ use core::fmt;
 /// This distribution has a density function:
 /// `f(k) = lambda^k * exp(-lambda) / k!` for `k >= 0`.
 ///
+/// # Algorithm
+///
+/// For `lambda < 10`, Knuth's multiplication method is used, with expected
+/// cost `O(lambda)`. For larger `lambda`, the PTRS transformed rejection
+/// method of Hörmann is used, with bounded expected cost. The method is
+/// selected on construction.
+///
+/// W. Hörmann. *The transformed rejection method for generating Poisson
+/// random variables.* Insurance: Mathematics and Economics 12 (1993), 39–45.
+///
 /// # Example
 ///
 /// ```
@@ -34,11 +44,23 @@ pub struct Poisson<F>
 where F: Float + FloatConst, Standard: Distribution<F>
 {
     lambda: F,
-    // precalculated values
-    exp_lambda: F,
-    log_lambda: F,
-    sqrt_2lambda: F,
-    magic_val: F,
+    method: Method<F>,
+}
+
+/// The sampling method, with precalculated values
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde1", derive(serde::Serialize, serde::Deserialize))]
+enum Method<F> {
+    Knuth {
+        exp_lambda: F,
+    },
+    Ptrs {
+        log_lambda: F,
+        a: F,
+        b: F,
+        log_inv_alpha: F,
+        v_r: F,
+    },
 }
 
 /// Error type returned from `Poisson::new`.
@@ -69,20 +91,28 @@ where F: Float + FloatConst, Standard: Distribution<F>
     /// Construct a new `Poisson` with the given shape parameter
     /// `lambda`.
     pub fn new(lambda: F) -> Result<Poisson<F>, Error> {
-	if !lambda.is_finite() {
+        if !lambda.is_finite() {
             return Err(Error::NonFinite);
         }
         if !(lambda > F::zero()) {
             return Err(Error::ShapeTooSmall);
         }
-        let log_lambda = lambda.ln();
-        Ok(Poisson {
-            lambda,
-            exp_lambda: (-lambda).exp(),
-            log_lambda,
-            sqrt_2lambda: (F::from(2.0).unwrap() * lambda).sqrt(),
-            magic_val: lambda * log_lambda - crate::utils::log_gamma(F::one() + lambda),
-        })
+        let f = |x: f64| F::from(x).unwrap();
+        let method = if lambda < f(10.0) {
+            Method::Knuth {
+                exp_lambda: (-lambda).exp(),
+            }
+        } else {
+            let b = f(0.931) + f(2.53) * lambda.sqrt();
+            Method::Ptrs {
+                log_lambda: lambda.ln(),
+                a: f(-0.059) + f(0.02483) * b,
+                b,
+                log_inv_alpha: (f(1.1239) + f(1.1328) / (b - f(3.4))).ln(),
+                v_r: f(0.9277) - f(3.6224) / (b - f(2.0)),
+            }
+        };
+        Ok(Poisson { lambda, method })
     }
 }
 
@@ -91,59 +121,41 @@ where F: Float + FloatConst, Standard: Distribution<F>
 {
     #[inline]
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> F {
-        // using the algorithm from Numerical Recipes in C
-
-        // for low expected values use the Knuth method
-        if self.lambda < F::from(12.0).unwrap() {
-            let mut result = F::one();
-            let mut p = rng.gen::<F>();
-            while p > self.exp_lambda {
-                p = p*rng.gen::<F>();
-                result = result + F::one();
+        match self.method {
+            Method::Knuth { exp_lambda } => {
+                let mut result = F::one();
+                let mut p = rng.gen::<F>();
+                while p > exp_lambda {
+                    p = p*rng.gen::<F>();
+                    result = result + F::one();
+                }
+                result - F::one()
             }
-            result - F::one()
-        }
-        // high expected values - rejection method
-        else {
-            // we use the Cauchy distribution as the comparison distribution
-            // f(x) ~ 1/(1+x^2)
-            let cauchy = Cauchy::new(F::zero(), F::one()).unwrap();
-            let mut result;
-
-            loop {
-                let mut comp_dev;
-
+            Method::Ptrs { log_lambda, a, b, log_inv_alpha, v_r } => {
+                let f = |x: f64| F::from(x).unwrap();
+                let half = f(0.5);
                 loop {
-                    // draw from the Cauchy distribution
-                    comp_dev = rng.sample(cauchy);
-                    // shift the peak of the comparison distribution
-                    result = self.sqrt_2lambda * comp_dev + self.lambda;
-                    // repeat the drawing until we are in the range of possible values
-                    if result >= F::zero() {
-                        break;
+                    let u = rng.gen::<F>() - half;
+                    let v = rng.gen::<F>();
+                    let us = half - u.abs();
+                    let k = ((f(2.0) * a / us + b) * u + self.lambda + f(0.43)).floor();
+
+                    // squeeze: accept without evaluating the density
+                    if us >= f(0.07) && v <= v_r {
+                        return k;
+                    }
+                    if k < F::zero() || (us < f(0.013) && v > us) {
+                        continue;
+                    }
+                    // compare against the log of the Poisson density
+                    let lhs = v.ln() + log_inv_alpha - (a / (us * us) + b).ln();
+                    let rhs = -self.lambda + k * log_lambda
+                        - crate::utils::log_gamma(k + F::one());
+                    if lhs <= rhs {
+                        return k;
                     }
-                }
-                // now the result is a random variable greater than 0 with Cauchy distribution
-                // the result should be an integer value
-                result = result.floor();
-
-                // this is the ratio of the Poisson distribution to the comparison distribution
-                // the magic value scales the distribution function to a range of approximately 0-1
-                // since it is not exact, we multiply the ratio by 0.9 to avoid ratios greater than 1
-                // this doesn't change the resulting distribution, only increases the rate of failed drawings
-                let check = F::from(0.9).unwrap()
-                    * (F::one() + comp_dev * comp_dev)
-                    * (result * self.log_lambda
-                        - crate::utils::log_gamma(F::one() + result)
-                        - self.magic_val)
-                        .exp();
-
-                // check with uniform random value - if below the threshold, we are within the target distribution
-                if rng.gen::<F>() <= check {
-                    break;
                 }
             }
-            result
         }
     }
 }
@@ -167,17 +179,62 @@ mod test {
 
     #[test]
     fn test_poisson_avg() {
-        test_poisson_avg_gen::<f64>(10.0, 0.1);
-        test_poisson_avg_gen::<f64>(15.0, 0.1);
+        // The standard error of the average is about 0.1; see
+        // `test_poisson_large_lambda_moments` for a more precise check
+        test_poisson_avg_gen::<f64>(10.0, 0.4);
+        test_poisson_avg_gen::<f64>(15.0, 0.4);
 
-        test_poisson_avg_gen::<f32>(10.0, 0.1);
-        test_poisson_avg_gen::<f32>(15.0, 0.1);
+        test_poisson_avg_gen::<f32>(10.0, 0.4);
+        test_poisson_avg_gen::<f32>(15.0, 0.4);
 
         //Small lambda will use Knuth's method with exp_lambda == 1.0
         test_poisson_avg_gen::<f32>(0.00000000000000005, 0.1);
         test_poisson_avg_gen::<f64>(0.00000000000000005, 0.1);
     }
 
+    fn mean_and_variance(lambda: f64, n: usize) -> (f64, f64) {
+        let poisson = Poisson::new(lambda).unwrap();
+        let mut rng = crate::test::rng(306);
+        let (mut sum, mut sum_sq) = (0.0, 0.0);
+        for _ in 0..n {
+            let x: f64 = poisson.sample(&mut rng);
+            assert!(x >= 0.0 && x == x.floor());
+            sum += x;
+            sum_sq += x * x;
+        }
+        let mean = sum / n as f64;
+        (mean, sum_sq / n as f64 - mean * mean)
+    }
+
+    #[test]
+    fn test_poisson_large_lambda_moments() {
+        for &lambda in &[10.0, 15.0, 50.0, 1000.0, 1e6] {
+            let (mean, var) = mean_and_variance(lambda, 40_000);
+            // The standard errors are sqrt(lambda / n) and about lambda * sqrt(2 / n)
+            assert!((mean - lambda).abs() < 0.03 * lambda.sqrt(), "lambda {}: mean {}", lambda, mean);
+            assert!((var - lambda).abs() < 0.05 * lambda, "lambda {}: variance {}", lambda, var);
+        }
+    }
+
+    #[test]
+    fn test_poisson_small_lambda_knuth() {
+        // Below the threshold, samples are those of Knuth's method
+        for &lambda in &[0.5f64, 3.0, 9.99] {
+            let poisson = Poisson::new(lambda).unwrap();
+            let mut rng = crate::test::rng(307);
+            let mut reference = crate::test::rng(307);
+            for _ in 0..100 {
+                let mut k = 0.0;
+                let mut p: f64 = reference.gen();
+                while p > (-lambda).exp() {
+                    p *= reference.gen::<f64>();
+                    k += 1.0;
+                }
+                assert_eq!(poisson.sample(&mut rng), k);
+            }
+        }
+    }
+
     #[test]
     #[should_panic]
     fn test_poisson_invalid_lambda_zero() {
@@ -0,0 +1,182 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Poisson-disk (blue noise) point sets.
+
+#![cfg(feature = "alloc")]
+use alloc::vec;
+use alloc::vec::Vec;
+use core::f64::consts::FRAC_1_SQRT_2;
+use rand::Rng;
+
+/// Number of candidates tried around an active point before retiring it
+const CANDIDATES: usize = 30;
+
+/// Well-spaced random points in a rectangle, using Bridson's algorithm.
+///
+/// [`PoissonDisk::generate`] produces points in `[0, width) × [0, height)`
+/// such that no two points are closer than `min_dist`. Points are added
+/// around existing ones until no more fit (up to the randomness of the
+/// algorithm), so that the region is covered evenly without the clumps and
+/// gaps of uniformly sampled points.
+///
+/// The number of points generated is roughly proportional to
+/// `width * height / min_dist²`, as is the memory used.
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::PoissonDisk;
+///
+/// let disk = PoissonDisk { width: 10.0, height: 5.0, min_dist: 1.0 };
+/// let points = disk.generate(&mut rand::thread_rng());
+/// assert!(!points.is_empty());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde1", derive(serde::Serialize, serde::Deserialize))]
+pub struct PoissonDisk {
+    /// The width of the region
+    pub width: f64,
+    /// The height of the region
+    pub height: f64,
+    /// The minimum distance between any two points
+    pub min_dist: f64,
+}
+
+impl PoissonDisk {
+    /// Generate a set of points, in no particular order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `width`, `height` or `min_dist` is not positive and finite.
+    pub fn generate<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec<[f64; 2]> {
+        let (width, height, r) = (self.width, self.height, self.min_dist);
+        assert!(
+            width > 0.0 && width.is_finite() && height > 0.0 && height.is_finite(),
+            "PoissonDisk::generate: region must have positive, finite size"
+        );
+        assert!(
+            r > 0.0 && r.is_finite(),
+            "PoissonDisk::generate: min_dist must be positive and finite"
+        );
+
+        // With this cell size, each cell holds at most one point.
+        let cell = r * FRAC_1_SQRT_2;
+        let cols = (width / cell) as usize + 1;
+        let rows = (height / cell) as usize + 1;
+        let mut grid: Vec<Option<usize>> = vec![None; cols * rows];
+        let cell_of = |p: [f64; 2]| ((p[0] / cell) as usize, (p[1] / cell) as usize);
+
+        let mut points = Vec::new();
+        let mut active = Vec::new();
+
+        let first = [rng.gen_range(0.0..width), rng.gen_range(0.0..height)];
+        let (cx, cy) = cell_of(first);
+        grid[cy * cols + cx] = Some(0);
+        points.push(first);
+        active.push(0);
+
+        let r2 = r * r;
+        while !active.is_empty() {
+            let i = rng.gen_range(0..active.len());
+            let centre = points[active[i]];
+            let mut found = false;
+
+            for _ in 0..CANDIDATES {
+                // Uniform over the annulus [r, 2r) around the centre, by
+                // rejection from the enclosing square.
+                let (dx, dy) = loop {
+                    let dx = rng.gen_range(-2.0 * r..2.0 * r);
+                    let dy = rng.gen_range(-2.0 * r..2.0 * r);
+                    let d2 = dx * dx + dy * dy;
+                    if d2 >= r2 && d2 < 4.0 * r2 {
+                        break (dx, dy);
+                    }
+                };
+                let p = [centre[0] + dx, centre[1] + dy];
+                if !(p[0] >= 0.0 && p[0] < width && p[1] >= 0.0 && p[1] < height) {
+                    continue;
+                }
+
+                let (cx, cy) = cell_of(p);
+                let far = (cy.saturating_sub(2)..(cy + 3).min(rows)).all(|y| {
+                    (cx.saturating_sub(2)..(cx + 3).min(cols)).all(|x| match grid[y * cols + x] {
+                        Some(j) => {
+                            let (ex, ey) = (points[j][0] - p[0], points[j][1] - p[1]);
+                            ex * ex + ey * ey >= r2
+                        }
+                        None => true,
+                    })
+                });
+                if far {
+                    grid[cy * cols + cx] = Some(points.len());
+                    active.push(points.len());
+                    points.push(p);
+                    found = true;
+                    break;
+                }
+            }
+
+            if !found {
+                active.swap_remove(i);
+            }
+        }
+
+        points
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_poisson_disk_spacing() {
+        let mut rng = crate::test::rng(286);
+        for &(width, height, min_dist) in &[(10.0, 10.0, 1.0), (30.0, 4.0, 0.75), (0.5, 0.5, 2.0)] {
+            let disk = PoissonDisk { width, height, min_dist };
+            let points = disk.generate(&mut rng);
+            assert!(!points.is_empty());
+            for (i, p) in points.iter().enumerate() {
+                assert!(p[0] >= 0.0 && p[0] < width);
+                assert!(p[1] >= 0.0 && p[1] < height);
+                for q in &points[..i] {
+                    let (dx, dy) = (p[0] - q[0], p[1] - q[1]);
+                    assert!(dx * dx + dy * dy >= min_dist * min_dist);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_poisson_disk_coverage() {
+        let mut rng = crate::test::rng(287);
+        let disk = PoissonDisk { width: 20.0, height: 12.0, min_dist: 1.0 };
+        let points = disk.generate(&mut rng);
+
+        // Every square of side 3 * min_dist should contain a point: an empty
+        // one would leave room for further candidates.
+        let side = 3.0;
+        let (cols, rows) = ((20.0 / side) as usize, (12.0 / side) as usize);
+        for y in 0..rows {
+            for x in 0..cols {
+                let (x0, y0) = (x as f64 * side, y as f64 * side);
+                assert!(points.iter().any(|p| {
+                    p[0] >= x0 && p[0] < x0 + side && p[1] >= y0 && p[1] < y0 + side
+                }));
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_poisson_disk_invalid() {
+        let disk = PoissonDisk { width: 1.0, height: 1.0, min_dist: 0.0 };
+        disk.generate(&mut crate::test::rng(288));
+    }
+}
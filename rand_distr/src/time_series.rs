@@ -0,0 +1,150 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Synthetic time series.
+#![cfg(feature = "alloc")]
+use crate::StandardNormal;
+use alloc::vec::Vec;
+use num_traits::Float;
+use rand::Rng;
+
+/// Synthetic time series with a linear trend, seasonality and noise, e.g. for
+/// testing forecasting models.
+///
+/// [`TimeSeries::generate`] returns `length` values, where the value at time
+/// `t` (starting from `0`) is
+///
+/// `trend * t + seasonality_amp * sin(2π t / seasonality_period) + noise * z_t`
+///
+/// with independent standard normal `z_t`. Thus `noise` is the standard
+/// deviation of the noise. A `seasonality_period` of `0` disables seasonality.
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::TimeSeries;
+///
+/// let series = TimeSeries {
+///     length: 365,
+///     trend: 0.1,
+///     seasonality_period: 7,
+///     seasonality_amp: 2.0,
+///     noise: 0.5,
+/// };
+/// let values = series.generate(&mut rand::thread_rng());
+/// assert_eq!(values.len(), 365);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde1", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimeSeries {
+    /// The number of values
+    pub length: usize,
+    /// The increase per time step
+    pub trend: f64,
+    /// The period of the seasonal component, in time steps (`0` for none)
+    pub seasonality_period: usize,
+    /// The amplitude of the seasonal component
+    pub seasonality_amp: f64,
+    /// The standard deviation of the noise
+    pub noise: f64,
+}
+
+impl TimeSeries {
+    /// The value at time `t` without noise
+    pub fn expected(&self, t: usize) -> f64 {
+        let t = t as f64;
+        let mut value = self.trend * t;
+        if self.seasonality_period > 0 {
+            let phase = 2.0 * core::f64::consts::PI * t / self.seasonality_period as f64;
+            value += self.seasonality_amp * Float::sin(phase);
+        }
+        value
+    }
+
+    /// Generate a time series.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `noise` is negative or not finite.
+    pub fn generate<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec<f64> {
+        assert!(
+            self.noise >= 0.0 && self.noise.is_finite(),
+            "TimeSeries::generate: noise must be non-negative and finite"
+        );
+        (0..self.length)
+            .map(|t| {
+                let z: f64 = rng.sample(StandardNormal);
+                self.expected(t) + self.noise * z
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_time_series_residuals() {
+        let mut rng = crate::test::rng(297);
+        let series = TimeSeries {
+            length: 10000,
+            trend: 0.3,
+            seasonality_period: 12,
+            seasonality_amp: 5.0,
+            noise: 1.5,
+        };
+        let values = series.generate(&mut rng);
+        assert_eq!(values.len(), 10000);
+
+        // Removing the trend and seasonality leaves the noise
+        let residuals: Vec<f64> = values
+            .iter()
+            .enumerate()
+            .map(|(t, &x)| x - series.expected(t))
+            .collect();
+        let n = residuals.len() as f64;
+        let mean = residuals.iter().sum::<f64>() / n;
+        let variance = residuals.iter().map(|r| (r - mean) * (r - mean)).sum::<f64>() / n;
+        assert!(mean.abs() < 0.05);
+        assert!((variance - 2.25).abs() < 0.1, "variance {}", variance);
+    }
+
+    #[test]
+    fn test_time_series_no_noise() {
+        let mut rng = crate::test::rng(298);
+        let series = TimeSeries {
+            length: 9,
+            trend: 1.0,
+            seasonality_period: 4,
+            seasonality_amp: 2.0,
+            noise: 0.0,
+        };
+        let values = series.generate(&mut rng);
+        let expected = [0.0, 3.0, 2.0, 1.0, 4.0, 7.0, 6.0, 5.0, 8.0];
+        for (x, e) in values.iter().zip(expected.iter()) {
+            assert!((x - e).abs() < 1e-12);
+        }
+
+        let flat = TimeSeries { seasonality_period: 0, ..series };
+        assert_eq!(flat.generate(&mut rng), (0..9).map(|t| t as f64).collect::<Vec<_>>());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_time_series_invalid_noise() {
+        let series = TimeSeries {
+            length: 1,
+            trend: 0.0,
+            seasonality_period: 0,
+            seasonality_amp: 0.0,
+            noise: -1.0,
+        };
+        series.generate(&mut crate::test::rng(299));
+    }
+}
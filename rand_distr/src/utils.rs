@@ -55,6 +55,134 @@ pub(crate) fn log_gamma<F: Float>(x: F) -> F {
     log + (F::from(2.5066282746310005).unwrap() * a / x).ln()
 }
 
+/// Calculates the standard normal cumulative distribution function.
+///
+/// Uses the Chebyshev approximation of `erfc` from Numerical Recipes, with
+/// fractional error below `1.2e-7` everywhere.
+pub(crate) fn normal_cdf(x: f64) -> f64 {
+    let z = x.abs() * core::f64::consts::FRAC_1_SQRT_2;
+    let t = 1.0 / (1.0 + 0.5 * z);
+    let poly = -1.26551223
+        + t * (1.00002368
+            + t * (0.37409196
+                + t * (0.09678418
+                    + t * (-0.18628806
+                        + t * (0.27886807
+                            + t * (-1.13520398
+                                + t * (1.48851587 + t * (-0.82215223 + t * 0.17087277))))))));
+    // erfc(|x| / sqrt(2))
+    let erfc = t * Float::exp(-z * z + poly);
+    if x >= 0.0 {
+        1.0 - 0.5 * erfc
+    } else {
+        0.5 * erfc
+    }
+}
+
+/// Calculates the inverse of the standard normal cumulative distribution
+/// function (the probit function).
+///
+/// Uses algorithm AS 241 (Wichura, 1988), with relative error about `1e-16`.
+/// Returns `-inf` for `p = 0`, `inf` for `p = 1` and NaN outside `[0, 1]`.
+pub(crate) fn normal_inverse_cdf(p: f64) -> f64 {
+    #[inline]
+    fn ratio(x: f64, num: &[f64; 8], den: &[f64; 7]) -> f64 {
+        let n = num.iter().rev().fold(0.0, |acc, c| acc * x + c);
+        let d = den.iter().rev().fold(0.0, |acc, c| acc * x + c) * x + 1.0;
+        n / d
+    }
+
+    if !(0.0..=1.0).contains(&p) {
+        return f64::NAN;
+    }
+    if p == 0.0 {
+        return f64::NEG_INFINITY;
+    } else if p == 1.0 {
+        return f64::INFINITY;
+    }
+    let q = p - 0.5;
+    if q.abs() <= 0.425 {
+        let r = 0.180625 - q * q;
+        return q * ratio(
+            r,
+            &[
+                3.3871328727963666080e0,
+                1.3314166789178437745e+2,
+                1.9715909503065514427e+3,
+                1.3731693765509461125e+4,
+                4.5921953931549871457e+4,
+                6.7265770927008700853e+4,
+                3.3430575583588128105e+4,
+                2.5090809287301226727e+3,
+            ],
+            &[
+                4.2313330701600911252e+1,
+                6.8718700749205790830e+2,
+                5.3941960214247511077e+3,
+                2.1213794301586595867e+4,
+                3.9307895800092710610e+4,
+                2.8729085735721942674e+4,
+                5.2264952788528545610e+3,
+            ],
+        );
+    }
+
+    // Distance into the nearer tail
+    let r = Float::sqrt(-Float::ln(if q < 0.0 { p } else { 1.0 - p }));
+    let x = if r <= 5.0 {
+        ratio(
+            r - 1.6,
+            &[
+                1.42343711074968357734e0,
+                4.63033784615654529590e0,
+                5.76949722146069140550e0,
+                3.64784832476320460504e0,
+                1.27045825245236838258e0,
+                2.41780725177450611770e-1,
+                2.27238449892691845833e-2,
+                7.74545014278341407640e-4,
+            ],
+            &[
+                2.05319162663775882187e0,
+                1.67638483018380384940e0,
+                6.89767334985100004550e-1,
+                1.48103976427480074590e-1,
+                1.51986665636164571966e-2,
+                5.47593808499534494600e-4,
+                1.05075007164441684324e-9,
+            ],
+        )
+    } else {
+        ratio(
+            r - 5.0,
+            &[
+                6.65790464350110377720e0,
+                5.46378491116411436990e0,
+                1.78482653991729133580e0,
+                2.96560571828504891230e-1,
+                2.65321895265761230930e-2,
+                1.24266094738807843860e-3,
+                2.71155556874348757815e-5,
+                2.01033439929228813265e-7,
+            ],
+            &[
+                5.99832206555887937690e-1,
+                1.36929880922735805310e-1,
+                1.48753612908506148525e-2,
+                7.86869131145613259100e-4,
+                1.84631831751005468180e-5,
+                1.42151175831644588870e-7,
+                2.04426310338993978564e-15,
+            ],
+        )
+    };
+    if q < 0.0 {
+        -x
+    } else {
+        x
+    }
+}
+
 /// Sample a random number using the Ziggurat method (specifically the
 /// ZIGNOR variant from Doornik 2005). Most of the arguments are
 /// directly from the paper:
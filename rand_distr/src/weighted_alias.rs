@@ -97,11 +97,11 @@ impl<W: AliasableWeight> WeightedAliasIndex<W> {
         let max_weight_size = W::try_from_u32_lossy(n)
             .map(|n| W::MAX / n)
             .unwrap_or(W::ZERO);
-        if !weights
+        if let Some(index) = weights
             .iter()
-            .all(|&w| W::ZERO <= w && w <= max_weight_size)
+            .position(|&w| !(W::ZERO <= w && w <= max_weight_size))
         {
-            return Err(WeightedError::InvalidWeight);
+            return Err(WeightedError::InvalidWeight { index });
         }
 
         // The sum of weights will represent 100% of no alias odds.
@@ -382,7 +382,7 @@ mod test {
         // Floating point special cases
         assert_eq!(
             WeightedAliasIndex::new(vec![::core::f32::INFINITY]).unwrap_err(),
-            WeightedError::InvalidWeight
+            WeightedError::InvalidWeight { index: 0 }
         );
         assert_eq!(
             WeightedAliasIndex::new(vec![-0_f32]).unwrap_err(),
@@ -390,18 +390,47 @@ mod test {
         );
         assert_eq!(
             WeightedAliasIndex::new(vec![-1_f32]).unwrap_err(),
-            WeightedError::InvalidWeight
+            WeightedError::InvalidWeight { index: 0 }
         );
         assert_eq!(
             WeightedAliasIndex::new(vec![-::core::f32::INFINITY]).unwrap_err(),
-            WeightedError::InvalidWeight
+            WeightedError::InvalidWeight { index: 0 }
         );
         assert_eq!(
             WeightedAliasIndex::new(vec![::core::f32::NAN]).unwrap_err(),
-            WeightedError::InvalidWeight
+            WeightedError::InvalidWeight { index: 0 }
         );
     }
 
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn test_weighted_index_f64_chi_squared() {
+        // Weights of differing magnitude, such that the small/large bucket
+        // partitioning is non-trivial
+        let weights = vec![0.1f64, 1.0, 2.5, 10.0, 0.5, 7.0, 3.0, 40.0];
+        let total: f64 = weights.iter().sum();
+        let distr = WeightedAliasIndex::new(weights.clone()).unwrap();
+
+        let mut rng = crate::test::rng(295);
+        let n = 100_000;
+        let mut counts = vec![0u32; weights.len()];
+        for _ in 0..n {
+            counts[distr.sample(&mut rng)] += 1;
+        }
+
+        let chi_squared: f64 = counts
+            .iter()
+            .zip(weights.iter())
+            .map(|(&c, &w)| {
+                let expected = w / total * n as f64;
+                (c as f64 - expected) * (c as f64 - expected) / expected
+            })
+            .sum();
+        // 99.9% quantile of the chi-squared distribution with 7 degrees of
+        // freedom
+        assert!(chi_squared < 24.32, "chi-squared statistic {}", chi_squared);
+    }
+
     #[test]
     #[cfg_attr(miri, ignore)] // Miri is too slow
     fn test_weighted_index_u128() {
@@ -416,11 +445,11 @@ mod test {
         // Signed integer special cases
         assert_eq!(
             WeightedAliasIndex::new(vec![-1_i128]).unwrap_err(),
-            WeightedError::InvalidWeight
+            WeightedError::InvalidWeight { index: 0 }
         );
         assert_eq!(
             WeightedAliasIndex::new(vec![::core::i128::MIN]).unwrap_err(),
-            WeightedError::InvalidWeight
+            WeightedError::InvalidWeight { index: 0 }
         );
     }
 
@@ -438,11 +467,11 @@ mod test {
         // Signed integer special cases
         assert_eq!(
             WeightedAliasIndex::new(vec![-1_i8]).unwrap_err(),
-            WeightedError::InvalidWeight
+            WeightedError::InvalidWeight { index: 0 }
         );
         assert_eq!(
             WeightedAliasIndex::new(vec![::core::i8::MIN]).unwrap_err(),
-            WeightedError::InvalidWeight
+            WeightedError::InvalidWeight { index: 0 }
         );
     }
 
@@ -494,7 +523,7 @@ mod test {
         );
         assert_eq!(
             WeightedAliasIndex::new(vec![W::MAX, W::MAX]).unwrap_err(),
-            WeightedError::InvalidWeight
+            WeightedError::InvalidWeight { index: 0 }
         );
     }
 
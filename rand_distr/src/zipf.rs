@@ -343,11 +343,34 @@ mod tests {
     fn zipf_sample_s_0() {
         let d = Zipf::new(10, 0.).unwrap();
         let mut rng = crate::test::rng(2);
-        for _ in 0..1000 {
+        let mut counts = [0u32; 10];
+        for _ in 0..10000 {
             let r = d.sample(&mut rng);
-            assert!(r >= 1.);
+            assert!(r >= 1. && r <= 10. && r == r.floor());
+            counts[r as usize - 1] += 1;
+        }
+        // With `s == 0`, all ranks are equally likely
+        assert!(counts.iter().all(|&c| c > 850 && c < 1150), "{:?}", counts);
+    }
+
+    #[test]
+    fn zipf_sample_frequencies() {
+        let n = 5;
+        let s = 1.2f64;
+        let d = Zipf::new(n, s).unwrap();
+        let mut rng = crate::test::rng(291);
+        let mut counts = [0u32; 5];
+        let samples = 20000;
+        for _ in 0..samples {
+            counts[d.sample(&mut rng) as usize - 1] += 1;
+        }
+        // Lower ranks are drawn more frequently, in proportion to `1 / k^s`
+        assert!(counts.windows(2).all(|w| w[0] > w[1]), "{:?}", counts);
+        let norm: f64 = (1..=n).map(|k| (k as f64).powf(-s)).sum();
+        for (k, &c) in (1..=n).zip(counts.iter()) {
+            let expected = samples as f64 * (k as f64).powf(-s) / norm;
+            assert!((c as f64 - expected).abs() < 0.05 * expected + 50.0);
         }
-        // TODO: verify that this is a uniform distribution
     }
 
     #[test]
@@ -144,7 +144,7 @@ fn pareto_stability() {
 fn poisson_stability() {
     test_samples(223, Poisson::new(7.0).unwrap(), &[5.0f32, 11.0, 6.0, 5.0]);
     test_samples(223, Poisson::new(7.0).unwrap(), &[9.0f64, 5.0, 7.0, 6.0]);
-    test_samples(223, Poisson::new(27.0).unwrap(), &[28.0f32, 32.0, 36.0, 36.0]);
+    test_samples(223, Poisson::new(27.0).unwrap(), &[17.0f32, 22.0, 26.0, 24.0]);
 }
 
 
@@ -112,19 +112,37 @@ impl Bernoulli {
     /// `numerator`-in-`denominator`. I.e. `new_ratio(2, 3)` will return
     /// a `Bernoulli` with a 2-in-3 chance, or about 67%, of returning `true`.
     ///
-    /// return `true`. If `numerator == 0` it will always return `false`.
     /// For `numerator > denominator` and `denominator == 0`, this returns an
     /// error. Otherwise, for `numerator == denominator`, samples are always
     /// true; for `numerator == 0` samples are always false.
+    ///
+    /// # Precision
+    ///
+    /// The probability is computed with integer arithmetic, without
+    /// conversion to `f64`: it is `numerator / denominator` rounded down to a
+    /// multiple of 2<sup>-64</sup>, so the error is less than 2<sup>-64</sup>.
+    /// By contrast, `Bernoulli::new(1.0 / 3.0)` has an error of about
+    /// 2<sup>-55</sup> due to rounding of the `f64` argument.
     #[inline]
     pub fn from_ratio(numerator: u32, denominator: u32) -> Result<Bernoulli, BernoulliError> {
+        Self::from_ratio_u64(u64::from(numerator), u64::from(denominator))
+    }
+
+    /// Construct a new `Bernoulli` with the probability of success of
+    /// `numerator`-in-`denominator`, for denominators too large for
+    /// [`Bernoulli::from_ratio`].
+    ///
+    /// Errors and precision are as for [`Bernoulli::from_ratio`].
+    #[inline]
+    pub fn from_ratio_u64(numerator: u64, denominator: u64) -> Result<Bernoulli, BernoulliError> {
         if numerator > denominator || denominator == 0 {
             return Err(BernoulliError::InvalidProbability);
         }
         if numerator == denominator {
             return Ok(Bernoulli { p_int: ALWAYS_TRUE });
         }
-        let p_int = ((f64::from(numerator) / f64::from(denominator)) * SCALE) as u64;
+        // Since `numerator < denominator`, the quotient is less than 2^64
+        let p_int = ((u128::from(numerator) << 64) / u128::from(denominator)) as u64;
         Ok(Bernoulli { p_int })
     }
 }
@@ -200,6 +218,35 @@ mod test {
         assert!((avg2 - (NUM as f64) / (DENOM as f64)).abs() < 5e-3);
     }
 
+    #[test]
+    fn test_from_ratio_exact() {
+        // 2^64 - 1 is divisible by 3, so floor(2^64 / 3) = (2^64 - 1) / 3
+        let third = Bernoulli::from_ratio(1, 3).unwrap();
+        assert_eq!(third.p_int, u64::MAX / 3);
+        assert_ne!(Bernoulli::new(1.0 / 3.0).unwrap().p_int, third.p_int);
+        assert_eq!(Bernoulli::from_ratio(2, 3).unwrap().p_int, u64::MAX / 3 * 2);
+        assert_eq!(Bernoulli::from_ratio(1, 2).unwrap().p_int, 1 << 63);
+        assert_eq!(Bernoulli::from_ratio(0, 7).unwrap().p_int, 0);
+        assert_eq!(
+            Bernoulli::from_ratio(u32::MAX - 1, u32::MAX).unwrap().p_int,
+            ((u128::from(u32::MAX - 1) << 64) / u128::from(u32::MAX)) as u64
+        );
+
+        assert!(Bernoulli::from_ratio(4, 3).is_err());
+        assert!(Bernoulli::from_ratio(0, 0).is_err());
+
+        let big = Bernoulli::from_ratio_u64(1 << 40, 3 << 40).unwrap();
+        assert_eq!(big, third);
+        assert_eq!(Bernoulli::from_ratio_u64(u64::MAX - 1, u64::MAX).unwrap().p_int, u64::MAX - 1);
+        assert_eq!(Bernoulli::from_ratio_u64(u64::MAX, u64::MAX).unwrap().p_int, super::ALWAYS_TRUE);
+        assert!(Bernoulli::from_ratio_u64(u64::MAX, u64::MAX - 1).is_err());
+
+        let mut rng = crate::test::rng(299);
+        let n = 300_000;
+        let count = (0..n).filter(|_| third.sample(&mut rng)).count();
+        assert!((count as f64 / n as f64 - 1.0 / 3.0).abs() < 3e-3);
+    }
+
     #[test]
     fn value_stability() {
         let mut rng = crate::test::rng(3);
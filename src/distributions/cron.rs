@@ -0,0 +1,143 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Random cron expressions.
+
+use crate::distributions::Distribution;
+use crate::Rng;
+use alloc::string::String;
+use core::fmt::{self, Write};
+
+#[cfg(feature = "serde1")]
+use serde::{Serialize, Deserialize};
+
+/// The inclusive domains of the five cron fields: minute, hour, day of month,
+/// month and day of week.
+const FIELDS: [(u32, u32); 5] = [(0, 59), (0, 23), (1, 31), (1, 12), (0, 6)];
+
+/// A distribution over valid five-field cron expressions, e.g. for testing
+/// cron parsers.
+///
+/// Each of the fields (minute, hour, day of month, month and day of week) is
+/// independently one of:
+///
+/// - `*`
+/// - a step over the whole domain, e.g. `*/5`
+/// - a single value, e.g. `7`
+/// - a range, e.g. `1-5`, optionally with a step, e.g. `0-30/10`
+/// - a list of two or three single values, e.g. `1,15`
+///
+/// All values lie within the field's domain and all ranges are non-empty
+/// (the start does not exceed the end). Names (e.g. `MON`) and non-standard
+/// extensions (e.g. `L`, `?` or a seconds field) are not generated.
+///
+/// # Example
+///
+/// ```
+/// use rand::Rng;
+/// use rand::distributions::CronExpr;
+///
+/// let expr: String = rand::thread_rng().sample(CronExpr);
+/// assert_eq!(expr.split(' ').count(), 5);
+/// ```
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct CronExpr;
+
+impl Distribution<String> for CronExpr {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> String {
+        let mut s = String::with_capacity(32);
+        for (i, &(min, max)) in FIELDS.iter().enumerate() {
+            if i > 0 {
+                s.push(' ');
+            }
+            // Writing to a `String` never fails
+            let _ = write_field(rng, &mut s, min, max);
+        }
+        s
+    }
+}
+
+fn write_field<R: Rng + ?Sized>(rng: &mut R, s: &mut String, min: u32, max: u32) -> fmt::Result {
+    match rng.gen_range(0..10) {
+        0 | 1 => write!(s, "*"),
+        2 => write!(s, "*/{}", rng.gen_range(1..=(max - min + 1) / 2)),
+        3 | 4 => write!(s, "{}", rng.gen_range(min..=max)),
+        5 | 6 => {
+            let a = rng.gen_range(min..max);
+            let b = rng.gen_range(a + 1..=max);
+            write!(s, "{}-{}", a, b)
+        }
+        7 => {
+            let a = rng.gen_range(min..max);
+            let b = rng.gen_range(a + 1..=max);
+            write!(s, "{}-{}/{}", a, b, rng.gen_range(1..=b - a))
+        }
+        _ => {
+            write!(s, "{}", rng.gen_range(min..=max))?;
+            for _ in 1..rng.gen_range(2..=3) {
+                write!(s, ",{}", rng.gen_range(min..=max))?;
+            }
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn parse_value(s: &str, (min, max): (u32, u32)) -> u32 {
+        let v: u32 = s.parse().unwrap();
+        assert!(min <= v && v <= max, "{} outside {}-{}", v, min, max);
+        v
+    }
+
+    // A minimal validator for the subset of cron syntax we generate
+    fn validate_field(field: &str, domain: (u32, u32)) {
+        for item in field.split(',') {
+            let (range, step) = match item.find('/') {
+                Some(i) => (&item[..i], Some(&item[i + 1..])),
+                None => (item, None),
+            };
+            if let Some(step) = step {
+                assert!(step.parse::<u32>().unwrap() >= 1);
+            }
+            if range == "*" {
+                continue;
+            }
+            match range.find('-') {
+                Some(i) => {
+                    let a = parse_value(&range[..i], domain);
+                    let b = parse_value(&range[i + 1..], domain);
+                    assert!(a <= b);
+                }
+                None => {
+                    assert!(step.is_none(), "step on single value: {}", item);
+                    parse_value(range, domain);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_cron_valid() {
+        let mut rng = crate::test::rng(282);
+        let mut saw_star = false;
+        for _ in 0..1000 {
+            let expr: String = rng.sample(CronExpr);
+            let fields: alloc::vec::Vec<&str> = expr.split(' ').collect();
+            assert_eq!(fields.len(), 5, "{}", expr);
+            for (field, &domain) in fields.iter().zip(FIELDS.iter()) {
+                validate_field(field, domain);
+                saw_star |= *field == "*";
+            }
+        }
+        assert!(saw_star);
+    }
+}
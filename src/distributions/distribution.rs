@@ -36,6 +36,28 @@ pub trait Distribution<T> {
     /// Generate a random value of `T`, using `rng` as the source of randomness.
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> T;
 
+    /// Fill `dest` with random values of `T`, using `rng` as the source of
+    /// randomness.
+    ///
+    /// This is equivalent to sampling each element in turn, which is also the
+    /// default implementation. Distributions may override this where a faster
+    /// method exists, but must produce the same values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::distributions::{Distribution, Standard};
+    ///
+    /// let mut v = vec![0f64; 1000];
+    /// Standard.fill(&mut rand::thread_rng(), &mut v);
+    /// assert!(v.iter().all(|&x| (0.0..1.0).contains(&x)));
+    /// ```
+    fn fill<R: Rng + ?Sized>(&self, rng: &mut R, dest: &mut [T]) {
+        for x in dest {
+            *x = self.sample(rng);
+        }
+    }
+
     /// Create an iterator that generates random values of `T`, using `rng` as
     /// the source of randomness.
     ///
@@ -82,6 +104,36 @@ pub trait Distribution<T> {
         }
     }
 
+    /// Create an iterator that generates random values of `T`, borrowing both
+    /// the distribution and `rng`
+    ///
+    /// Unlike [`Distribution::sample_iter`], neither argument is consumed;
+    /// both may be used again once the iterator is dropped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::thread_rng;
+    /// use rand::distributions::{Distribution, Uniform};
+    ///
+    /// let mut rng = thread_rng();
+    /// let die = Uniform::new_inclusive(1, 6).unwrap();
+    ///
+    /// let sixes = die.sample_iter_mut(&mut rng).take(60).filter(|&x| x == 6).count();
+    /// let next_roll = die.sample(&mut rng);
+    /// # let _ = (sixes, next_roll);
+    /// ```
+    fn sample_iter_mut<'a, R>(&'a self, rng: &'a mut R) -> DistIter<&'a Self, &'a mut R, T>
+    where
+        R: Rng + ?Sized,
+    {
+        DistIter {
+            distr: self,
+            rng,
+            phantom: ::core::marker::PhantomData,
+        }
+    }
+
     /// Create a distribution of values of 'S' by mapping the output of `Self`
     /// through the closure `F`
     ///
@@ -110,12 +162,42 @@ pub trait Distribution<T> {
             phantom: ::core::marker::PhantomData,
         }
     }
+
+    /// Create a distribution of pairs, sampling from `self` then from `other`.
+    ///
+    /// Each sample of the resulting distribution is `(a, b)` where `a` is
+    /// sampled from `self` and `b` from `other`, in this order. The two
+    /// components are thus independent.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::distributions::{Distribution, Standard, Uniform};
+    ///
+    /// let dist = Uniform::new(0, 10).unwrap().zip(Standard);
+    /// let (n, flag): (u32, bool) = dist.sample(&mut rand::thread_rng());
+    /// assert!(n < 10);
+    /// println!("{} {}", n, flag);
+    /// ```
+    fn zip<D2>(self, other: D2) -> DistZip<Self, D2>
+    where
+        Self: Sized,
+    {
+        DistZip {
+            first: self,
+            second: other,
+        }
+    }
 }
 
 impl<'a, T, D: Distribution<T> + ?Sized> Distribution<T> for &'a D {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> T {
         (*self).sample(rng)
     }
+
+    fn fill<R: Rng + ?Sized>(&self, rng: &mut R, dest: &mut [T]) {
+        (*self).fill(rng, dest)
+    }
 }
 
 /// An iterator that generates random values of `T` with distribution `D`,
@@ -189,6 +271,29 @@ where
     }
 }
 
+/// A distribution of pairs, sampling from `D1` then from `D2`.
+///
+/// This `struct` is created by the [`Distribution::zip`] method.
+/// See its documentation for more.
+#[derive(Debug, Clone, Copy)]
+pub struct DistZip<D1, D2> {
+    first: D1,
+    second: D2,
+}
+
+impl<D1, D2, T, U> Distribution<(T, U)> for DistZip<D1, D2>
+where
+    D1: Distribution<T>,
+    D2: Distribution<U>,
+{
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> (T, U) {
+        let a = self.first.sample(rng);
+        let b = self.second.sample(rng);
+        (a, b)
+    }
+}
+
 /// `String` sampler
 ///
 /// Sampling a `String` of random characters is not quite the same as collecting
@@ -225,6 +330,25 @@ mod tests {
         assert!(0. < sum && sum < 100.);
     }
 
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_distributions_iter_mut() {
+        let mut rng = crate::test::rng(211);
+        let distr = Uniform::new(0u32, 100).unwrap();
+        let a: alloc::vec::Vec<u32> = distr.sample_iter_mut(&mut rng).skip(1).step_by(2).take(5).collect();
+        assert_eq!(a.len(), 5);
+        assert!(a.iter().all(|&x| x < 100));
+        // Both the distribution and the RNG remain usable
+        assert!(distr.sample(&mut rng) < 100);
+
+        let mut reference = crate::test::rng(212);
+        let mut rng = crate::test::rng(212);
+        let v: alloc::vec::Vec<u32> = rng.gen_iter::<u32>().take(5).collect();
+        let w: alloc::vec::Vec<u32> = (0..5).map(|_| reference.gen()).collect();
+        assert_eq!(v, w);
+        assert_eq!(rng.gen::<u64>(), reference.gen::<u64>());
+    }
+
     #[test]
     fn test_distributions_map() {
         let dist = Uniform::new_inclusive(0, 5).unwrap().map(|val| val + 15);
@@ -234,6 +358,85 @@ mod tests {
         assert!((15..=20).contains(&val));
     }
 
+    #[test]
+    fn test_distributions_map_matches_inner() {
+        use crate::distributions::Standard;
+
+        let dist = Standard.map(|x: u8| x & 0x7f);
+        let mut rng1 = crate::test::rng(288);
+        let mut rng2 = crate::test::rng(288);
+        for _ in 0..100 {
+            let x: u8 = Standard.sample(&mut rng2);
+            assert_eq!(dist.sample(&mut rng1), x & 0x7f);
+        }
+    }
+
+    #[test]
+    fn test_distributions_zip() {
+        use crate::distributions::Standard;
+
+        let dist = Uniform::new(0u32, 10).unwrap().zip(Standard);
+        let mut rng1 = crate::test::rng(289);
+        let mut rng2 = crate::test::rng(289);
+        for _ in 0..100 {
+            let (a, b): (u32, u64) = dist.sample(&mut rng1);
+            // The first component is drawn first
+            assert_eq!(a, Uniform::new(0u32, 10).unwrap().sample(&mut rng2));
+            let expected: u64 = Standard.sample(&mut rng2);
+            assert_eq!(b, expected);
+        }
+    }
+
+    fn check_fill<T, D, R>(distr: D, mut new_rng: impl FnMut() -> R)
+    where
+        T: Copy + Default + PartialEq + core::fmt::Debug,
+        D: Distribution<T>,
+        R: Rng,
+    {
+        for &len in &[0, 1, 63, 64, 65, 200] {
+            let mut rng1 = new_rng();
+            let mut rng2 = new_rng();
+            let mut buf = [T::default(); 200];
+            distr.fill(&mut rng1, &mut buf[..len]);
+            for x in &buf[..len] {
+                assert_eq!(*x, distr.sample(&mut rng2));
+            }
+            // The same amount of randomness was consumed
+            assert_eq!(rng1.gen::<u64>(), rng2.gen::<u64>());
+        }
+    }
+
+    fn check_fill_all<R: Rng>(mut new_rng: impl FnMut() -> R) {
+        use crate::distributions::Standard;
+
+        check_fill::<u64, _, _>(Standard, &mut new_rng);
+        check_fill::<f64, _, _>(Standard, &mut new_rng);
+        check_fill::<f32, _, _>(Standard, &mut new_rng);
+        check_fill::<u8, _, _>(Standard, &mut new_rng);
+        check_fill(Uniform::new(-3.0f64, 7.5).unwrap(), &mut new_rng);
+        check_fill(Uniform::new(-3.0f32, 7.5).unwrap(), &mut new_rng);
+        check_fill(Uniform::new(10u64, 1000).unwrap(), &mut new_rng);
+        // Forwarded through references
+        let distr: &Uniform<f64> = &Uniform::new(0.0, 1.0).unwrap();
+        check_fill(distr, &mut new_rng);
+    }
+
+    #[test]
+    fn test_fill_matches_sample() {
+        check_fill_all(|| crate::test::rng(290));
+        check_fill_all(|| crate::rngs::mock::StepRng::new(0x0123_4567_89ab_cdef, 0x9e37_79b9_7f4a_7c15));
+        #[cfg(feature = "small_rng")]
+        {
+            use crate::SeedableRng;
+            check_fill_all(|| crate::rngs::SmallRng::seed_from_u64(291));
+        }
+        #[cfg(feature = "std_rng")]
+        {
+            use crate::SeedableRng;
+            check_fill_all(|| crate::rngs::StdRng::seed_from_u64(292));
+        }
+    }
+
     #[test]
     fn test_make_an_iter() {
         fn ten_dice_rolls_other_than_five<R: Rng>(
@@ -0,0 +1,174 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A type-erased distribution.
+
+use crate::distributions::uniform::SampleUniform;
+use crate::distributions::{
+    Alphanumeric, Bernoulli, Distribution, Open01, OpenClosed01, Standard, Uniform, WeightedIndex,
+};
+use crate::{Rng, RngCore};
+use alloc::boxed::Box;
+use core::fmt;
+
+/// Object-safe form of [`Distribution`], sampling with a `dyn RngCore`
+trait DynSample<T> {
+    fn sample_dyn(&self, rng: &mut dyn RngCore) -> T;
+}
+
+impl<T, D: Distribution<T>> DynSample<T> for D {
+    fn sample_dyn(&self, rng: &mut dyn RngCore) -> T {
+        self.sample(rng)
+    }
+}
+
+/// A distribution of `T` whose concrete type is chosen at run time.
+///
+/// [`Distribution`] cannot be used as a trait object, since its methods are
+/// generic over the RNG. `DynDistribution` boxes any distribution of `T`,
+/// such that distributions of different types may be stored in the same
+/// variable or collection without a generic parameter. Sampling forwards to
+/// the boxed distribution, with one dynamic call per sample.
+///
+/// Distributions may be boxed with [`DynDistribution::new`] or, for several
+/// distributions of this crate, with `From`/`Into`.
+///
+/// # Example
+///
+/// ```
+/// use rand::distributions::{Distribution, DynDistribution, Uniform};
+///
+/// fn loot_roll(kind: &str) -> DynDistribution<f64> {
+///     let fair = Uniform::new(0.0, 1.0).unwrap();
+///     match kind {
+///         "fair" => fair.into(),
+///         _ => DynDistribution::new(fair.map(|x: f64| x * x)),
+///     }
+/// }
+///
+/// let roll = loot_roll("skewed");
+/// let x = roll.sample(&mut rand::thread_rng());
+/// assert!((0.0..1.0).contains(&x));
+/// ```
+pub struct DynDistribution<T> {
+    inner: Box<dyn DynSample<T> + Send + Sync>,
+}
+
+impl<T> DynDistribution<T> {
+    /// Box `distr`, erasing its type
+    pub fn new<D>(distr: D) -> Self
+    where D: Distribution<T> + Send + Sync + 'static {
+        DynDistribution {
+            inner: Box::new(distr),
+        }
+    }
+}
+
+impl<T> Distribution<T> for DynDistribution<T> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> T {
+        // `&mut R` is sized, hence coerces to `&mut dyn RngCore`
+        let mut rng = rng;
+        self.inner.sample_dyn(&mut rng)
+    }
+}
+
+impl<T> fmt::Debug for DynDistribution<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("DynDistribution").finish_non_exhaustive()
+    }
+}
+
+impl<X> From<Uniform<X>> for DynDistribution<X>
+where
+    X: SampleUniform,
+    Uniform<X>: Send + Sync + 'static,
+{
+    fn from(distr: Uniform<X>) -> Self {
+        DynDistribution::new(distr)
+    }
+}
+
+impl<X> From<WeightedIndex<X>> for DynDistribution<usize>
+where
+    X: SampleUniform + PartialOrd,
+    WeightedIndex<X>: Send + Sync + 'static,
+{
+    fn from(distr: WeightedIndex<X>) -> Self {
+        DynDistribution::new(distr)
+    }
+}
+
+impl From<Bernoulli> for DynDistribution<bool> {
+    fn from(distr: Bernoulli) -> Self {
+        DynDistribution::new(distr)
+    }
+}
+
+impl From<Alphanumeric> for DynDistribution<u8> {
+    fn from(distr: Alphanumeric) -> Self {
+        DynDistribution::new(distr)
+    }
+}
+
+macro_rules! from_generic {
+    ($($distr:ident),*) => {$(
+        impl<T> From<$distr> for DynDistribution<T>
+        where $distr: Distribution<T>
+        {
+            fn from(distr: $distr) -> Self {
+                DynDistribution::new(distr)
+            }
+        }
+    )*}
+}
+from_generic!(Standard, Open01, OpenClosed01);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_dyn_distribution_forwards() {
+        let uniform = Uniform::new(0.0, 10.0).unwrap();
+        let distrs: Vec<DynDistribution<f64>> = alloc::vec![
+            uniform.into(),
+            Standard.into(),
+            Open01.into(),
+            DynDistribution::new(uniform.map(|x: f64| -x)),
+        ];
+
+        let mut r1 = crate::test::rng(313);
+        let mut r2 = crate::test::rng(313);
+        for _ in 0..100 {
+            assert_eq!(distrs[0].sample(&mut r1), uniform.sample(&mut r2));
+            assert_eq!(distrs[1].sample(&mut r1), r2.gen::<f64>());
+            assert_eq!(distrs[2].sample(&mut r1), r2.sample::<f64, _>(Open01));
+            assert_eq!(distrs[3].sample(&mut r1), -uniform.sample(&mut r2));
+        }
+
+        // Sampling works through unsized RNGs
+        let rng: &mut dyn RngCore = &mut r1;
+        let x = distrs[0].sample(rng);
+        assert!((0.0..10.0).contains(&x));
+    }
+
+    #[test]
+    fn test_dyn_distribution_from() {
+        let mut rng = crate::test::rng(314);
+        let coin: DynDistribution<bool> = Bernoulli::new(1.0).unwrap().into();
+        assert!(coin.sample(&mut rng));
+        let index: DynDistribution<usize> = WeightedIndex::new([0, 0, 1]).unwrap().into();
+        assert_eq!(index.sample(&mut rng), 2);
+        let c: DynDistribution<u8> = Alphanumeric.into();
+        assert!(c.sample(&mut rng).is_ascii_alphanumeric());
+        let n: DynDistribution<u32> = Uniform::new_inclusive(5, 5).unwrap().into();
+        assert_eq!(n.sample(&mut rng), 5);
+        assert_eq!(alloc::format!("{:?}", n), "DynDistribution { .. }");
+    }
+}
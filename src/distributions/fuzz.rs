@@ -0,0 +1,137 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Floating-point values for fuzzing.
+
+use crate::distributions::{Bernoulli, BernoulliError, Distribution};
+use crate::Rng;
+
+#[cfg(feature = "serde1")]
+use serde::{Serialize, Deserialize};
+
+/// The number of kinds of special value, see [`FuzzF64`]
+const SPECIAL_KINDS: u32 = 9;
+
+/// A distribution over `f64` emphasizing edge cases, for numeric fuzzing.
+///
+/// With probability `special_probability`, a special value is returned, chosen
+/// uniformly from the following kinds:
+///
+/// -   `NaN`
+/// -   `INFINITY` and `NEG_INFINITY`
+/// -   `0.0` and `-0.0`
+/// -   a subnormal number of random sign and mantissa
+/// -   `MAX`, `MIN` and `MIN_POSITIVE`
+///
+/// Otherwise, the value has a uniformly random bit pattern. Such values have
+/// a huge range of magnitudes and may themselves be infinite or `NaN` (with
+/// probability about `2^-11`).
+///
+/// # Example
+///
+/// ```
+/// use rand::distributions::{Distribution, FuzzF64};
+///
+/// let dist = FuzzF64::new(0.2).unwrap();
+/// let x: f64 = dist.sample(&mut rand::thread_rng());
+/// // Test your code with `x` ...
+/// # let _ = x;
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct FuzzF64 {
+    special: Bernoulli,
+}
+
+impl FuzzF64 {
+    /// Construct, returning special values with probability
+    /// `special_probability`.
+    ///
+    /// Returns an error if `special_probability` is not in `[0, 1]`.
+    pub fn new(special_probability: f64) -> Result<FuzzF64, BernoulliError> {
+        Ok(FuzzF64 {
+            special: Bernoulli::new(special_probability)?,
+        })
+    }
+}
+
+impl Default for FuzzF64 {
+    /// Returns special values with probability `0.25`.
+    fn default() -> Self {
+        FuzzF64::new(0.25).unwrap()
+    }
+}
+
+impl Distribution<f64> for FuzzF64 {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        if !self.special.sample(rng) {
+            return f64::from_bits(rng.next_u64());
+        }
+        match rng.gen_range(0..SPECIAL_KINDS) {
+            0 => f64::NAN,
+            1 => f64::INFINITY,
+            2 => f64::NEG_INFINITY,
+            3 => 0.0,
+            4 => -0.0,
+            5 => {
+                // Sign bit and a non-zero mantissa; the exponent bits are zero
+                let bits = rng.gen_range(1..1u64 << 52) | (rng.gen::<u64>() & (1 << 63));
+                f64::from_bits(bits)
+            }
+            6 => f64::MAX,
+            7 => f64::MIN,
+            _ => f64::MIN_POSITIVE,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_fuzz_f64() {
+        let mut rng = crate::test::rng(291);
+        let dist = FuzzF64::default();
+
+        let mut seen = [false; 10];
+        for _ in 0..2000 {
+            let x: f64 = dist.sample(&mut rng);
+            let kind = if x.is_nan() {
+                0
+            } else if x == f64::INFINITY {
+                1
+            } else if x == f64::NEG_INFINITY {
+                2
+            } else if x == 0.0 && x.is_sign_positive() {
+                3
+            } else if x == 0.0 {
+                4
+            } else if x.is_subnormal() {
+                5
+            } else if x == f64::MAX {
+                6
+            } else if x == f64::MIN {
+                7
+            } else if x == f64::MIN_POSITIVE {
+                8
+            } else {
+                9
+            };
+            seen[kind] = true;
+        }
+        assert!(seen.iter().all(|&s| s));
+
+        let always = FuzzF64::new(1.0).unwrap();
+        for _ in 0..100 {
+            let x: f64 = always.sample(&mut rng);
+            assert!(!x.is_normal() || x.abs() == f64::MAX || x == f64::MIN_POSITIVE);
+        }
+        assert!(FuzzF64::new(1.5).is_err());
+    }
+}
@@ -0,0 +1,134 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Integers with a fixed number of set bits.
+
+use crate::distributions::Distribution;
+use crate::Rng;
+use core::fmt;
+
+#[cfg(feature = "serde1")]
+use serde::{Serialize, Deserialize};
+
+/// A distribution over integers with exactly `weight` of the lowest `bits`
+/// bits set, e.g. for bitmask fuzzing.
+///
+/// Every such integer is equally likely; all other bits are zero.
+///
+/// # Example
+///
+/// ```
+/// use rand::distributions::{Distribution, FixedHammingWeight};
+///
+/// let dist = FixedHammingWeight::new(16, 3).unwrap();
+/// let mask: u64 = dist.sample(&mut rand::thread_rng());
+/// assert_eq!(mask.count_ones(), 3);
+/// assert!(mask < 1 << 16);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct FixedHammingWeight {
+    bits: u32,
+    weight: u32,
+}
+
+/// Error type returned from `FixedHammingWeight::new`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FixedHammingWeightError {
+    /// `bits > 64`.
+    TooManyBits,
+    /// `weight > bits`.
+    WeightTooLarge,
+}
+
+impl fmt::Display for FixedHammingWeightError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            FixedHammingWeightError::TooManyBits => "bits > 64 in FixedHammingWeight distribution",
+            FixedHammingWeightError::WeightTooLarge => {
+                "weight > bits in FixedHammingWeight distribution"
+            }
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl ::std::error::Error for FixedHammingWeightError {}
+
+impl FixedHammingWeight {
+    /// Construct a new `FixedHammingWeight` over the lowest `bits` bits, of
+    /// which `weight` are set.
+    ///
+    /// Requires `weight <= bits <= 64`.
+    pub fn new(bits: u32, weight: u32) -> Result<FixedHammingWeight, FixedHammingWeightError> {
+        if bits > 64 {
+            return Err(FixedHammingWeightError::TooManyBits);
+        }
+        if weight > bits {
+            return Err(FixedHammingWeightError::WeightTooLarge);
+        }
+        Ok(FixedHammingWeight { bits, weight })
+    }
+}
+
+impl Distribution<u64> for FixedHammingWeight {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> u64 {
+        // Select `weight` distinct positions using Floyd's combination
+        // algorithm, with the result doubling as the set of chosen positions.
+        let mut mask = 0u64;
+        for j in (self.bits - self.weight)..self.bits {
+            let t = rng.gen_range(0..=j);
+            mask |= if mask & (1 << t) == 0 { 1 << t } else { 1 << j };
+        }
+        mask
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_hamming_weight() {
+        let mut rng = crate::test::rng(281);
+        for &(bits, weight) in &[(0, 0), (1, 0), (1, 1), (8, 3), (33, 17), (64, 0), (64, 1), (64, 63), (64, 64)] {
+            let dist = FixedHammingWeight::new(bits, weight).unwrap();
+            for _ in 0..100 {
+                let x = dist.sample(&mut rng);
+                assert_eq!(x.count_ones(), weight);
+                if bits < 64 {
+                    assert!(x < 1 << bits);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_hamming_uniform() {
+        // All C(4, 2) = 6 masks appear with similar frequency
+        let mut rng = crate::test::rng(282);
+        let dist = FixedHammingWeight::new(4, 2).unwrap();
+        let mut counts = [0u32; 16];
+        for _ in 0..6000 {
+            counts[dist.sample(&mut rng) as usize] += 1;
+        }
+        for (x, &c) in counts.iter().enumerate() {
+            if (x as u32).count_ones() == 2 {
+                assert!(c > 850 && c < 1150, "{} sampled {} times", x, c);
+            } else {
+                assert_eq!(c, 0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_hamming_invalid() {
+        assert_eq!(FixedHammingWeight::new(65, 1), Err(FixedHammingWeightError::TooManyBits));
+        assert_eq!(FixedHammingWeight::new(8, 9), Err(FixedHammingWeightError::WeightTooLarge));
+    }
+}
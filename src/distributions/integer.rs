@@ -20,6 +20,8 @@ use core::arch::x86_64::__m512i;
 use core::arch::x86_64::{__m128i, __m256i};
 use core::num::{NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
     NonZeroU128};
+use core::num::{NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize,
+    NonZeroI128};
 #[cfg(feature = "simd_support")] use core::simd::*;
 use core::mem;
 
@@ -93,6 +95,7 @@ impl_int_from_uint! { i64, u64 }
 impl_int_from_uint! { i128, u128 }
 impl_int_from_uint! { isize, usize }
 
+// Zero is rejected, such that all non-zero values are equally likely
 macro_rules! impl_nzint {
     ($ty:ty, $new:path) => {
         impl Distribution<$ty> for Standard {
@@ -113,6 +116,12 @@ impl_nzint!(NonZeroU32, NonZeroU32::new);
 impl_nzint!(NonZeroU64, NonZeroU64::new);
 impl_nzint!(NonZeroU128, NonZeroU128::new);
 impl_nzint!(NonZeroUsize, NonZeroUsize::new);
+impl_nzint!(NonZeroI8, NonZeroI8::new);
+impl_nzint!(NonZeroI16, NonZeroI16::new);
+impl_nzint!(NonZeroI32, NonZeroI32::new);
+impl_nzint!(NonZeroI64, NonZeroI64::new);
+impl_nzint!(NonZeroI128, NonZeroI128::new);
+impl_nzint!(NonZeroIsize, NonZeroIsize::new);
 
 macro_rules! x86_intrinsic_impl {
     ($($intrinsic:ident),+) => {$(
@@ -186,6 +195,48 @@ mod tests {
         rng.sample::<u128, _>(Standard);
     }
 
+    #[test]
+    fn test_nonzero() {
+        let mut rng = crate::test::rng(808);
+
+        // Never zero, even if the underlying integer is
+        let mut zero_then_one = crate::rngs::mock::StepRng::new(0, 1);
+        assert_eq!(zero_then_one.sample::<NonZeroU32, _>(Standard).get(), 1);
+        for _ in 0..1000 {
+            rng.sample::<NonZeroU16, _>(Standard);
+            rng.sample::<NonZeroU64, _>(Standard);
+            rng.sample::<NonZeroU128, _>(Standard);
+            rng.sample::<NonZeroUsize, _>(Standard);
+            rng.sample::<NonZeroI16, _>(Standard);
+            rng.sample::<NonZeroI32, _>(Standard);
+            rng.sample::<NonZeroI64, _>(Standard);
+            rng.sample::<NonZeroI128, _>(Standard);
+            rng.sample::<NonZeroIsize, _>(Standard);
+        }
+
+        // Uniform over the 255 non-zero values
+        const N: usize = 255 * 400;
+        let mut counts = [0usize; 256];
+        for _ in 0..N {
+            counts[usize::from(rng.sample::<NonZeroU8, _>(Standard).get())] += 1;
+        }
+        let mut counts_signed = [0usize; 256];
+        for _ in 0..N {
+            counts_signed[rng.sample::<NonZeroI8, _>(Standard).get() as u8 as usize] += 1;
+        }
+        for counts in &[counts, counts_signed] {
+            assert_eq!(counts[0], 0);
+            // Chi-squared test with 254 degrees of freedom; the critical value
+            // for p = 0.001 is about 330.
+            let expected = (N / 255) as f64;
+            let chi2: f64 = counts[1..]
+                .iter()
+                .map(|&c| (c as f64 - expected) * (c as f64 - expected) / expected)
+                .sum();
+            assert!(chi2 < 330.0, "{}", chi2);
+        }
+    }
+
     #[test]
     fn value_stability() {
         fn test_samples<T: Copy + core::fmt::Debug + PartialEq>(zero: T, expected: &[T])
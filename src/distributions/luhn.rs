@@ -0,0 +1,130 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Numbers with a Luhn check digit.
+
+use crate::distributions::Distribution;
+use crate::Rng;
+use alloc::string::String;
+
+/// Sample decimal numbers with a valid Luhn check digit, e.g. fake
+/// credit-card-like numbers for testing.
+///
+/// Each sample is a `String` of `length` ASCII digits starting with `prefix`
+/// (given as ASCII digits, e.g. `b"4"`). The digits after the prefix are
+/// uniformly random, except for the last, which is the Luhn check digit.
+///
+/// # Panics
+///
+/// Sampling panics if `prefix` contains a byte which is not an ASCII digit,
+/// or if `prefix.len() >= length` (leaving no room for the check digit).
+///
+/// # Example
+///
+/// ```
+/// use rand::distributions::{Distribution, LuhnNumber};
+///
+/// let dist = LuhnNumber { length: 16, prefix: b"4" };
+/// let card = dist.sample(&mut rand::thread_rng());
+/// assert_eq!(card.len(), 16);
+/// assert!(card.starts_with('4'));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LuhnNumber<'a> {
+    /// The total number of digits, including the check digit
+    pub length: usize,
+    /// The leading digits, as ASCII
+    pub prefix: &'a [u8],
+}
+
+impl Distribution<String> for LuhnNumber<'_> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> String {
+        assert!(
+            self.prefix.iter().all(u8::is_ascii_digit),
+            "LuhnNumber: prefix must consist of ASCII digits"
+        );
+        assert!(
+            self.prefix.len() < self.length,
+            "LuhnNumber: prefix must be shorter than length"
+        );
+
+        let mut digits = String::with_capacity(self.length);
+        digits.extend(self.prefix.iter().map(|&b| char::from(b)));
+        while digits.len() < self.length - 1 {
+            digits.push(char::from(b'0' + rng.gen_range(0..10u8)));
+        }
+        let check = (10 - luhn_sum(digits.as_bytes(), true) % 10) % 10;
+        digits.push(char::from(b'0' + check));
+        digits
+    }
+}
+
+/// The Luhn sum of ASCII `digits`, doubling every second digit from the
+/// right, starting with the last if `double_last`
+fn luhn_sum(digits: &[u8], double_last: bool) -> u8 {
+    let mut sum = 0;
+    for (i, &b) in digits.iter().rev().enumerate() {
+        let mut d = b - b'0';
+        if (i % 2 == 0) == double_last {
+            d *= 2;
+            if d > 9 {
+                d -= 9;
+            }
+        }
+        sum = (sum + d) % 10;
+    }
+    sum
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn luhn_valid(number: &str) -> bool {
+        number.bytes().all(|b| b.is_ascii_digit()) && luhn_sum(number.as_bytes(), false) == 0
+    }
+
+    #[test]
+    fn test_luhn_check() {
+        assert!(luhn_valid("79927398713"));
+        assert!(luhn_valid("4111111111111111"));
+        assert!(!luhn_valid("79927398710"));
+        assert!(!luhn_valid("4111111111111112"));
+    }
+
+    #[test]
+    fn test_luhn_number() {
+        let mut rng = crate::test::rng(301);
+        for &(length, prefix) in &[(16, &b"4"[..]), (15, b"37"), (1, b""), (2, b"5"), (19, b"6011")] {
+            let dist = LuhnNumber { length, prefix };
+            for _ in 0..100 {
+                let number = dist.sample(&mut rng);
+                assert_eq!(number.len(), length);
+                assert!(number.as_bytes().starts_with(prefix));
+                assert!(luhn_valid(&number), "{}", number);
+            }
+        }
+
+        // The free digits vary
+        let dist = LuhnNumber { length: 12, prefix: b"" };
+        let first = dist.sample(&mut rng);
+        assert!((0..10).any(|_| dist.sample(&mut rng) != first));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_luhn_number_prefix_too_long() {
+        LuhnNumber { length: 4, prefix: b"1234" }.sample(&mut crate::test::rng(302));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_luhn_number_invalid_prefix() {
+        LuhnNumber { length: 4, prefix: b"4x" }.sample(&mut crate::test::rng(303));
+    }
+}
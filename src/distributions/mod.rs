@@ -38,13 +38,15 @@
 //!
 //! Implementing `Distribution<T>` for [`Standard`] for user types `T` makes it
 //! possible to generate type `T` with [`Rng::gen`], and by extension also
-//! with the [`random`] function.
+//! with the [`random`] function. For fieldless enums, such an implementation
+//! sampling each variant with equal probability may be derived with
+//! `#[derive(RandUniform)]` (requires the `derive` feature).
 //!
 //! ## Random characters
 //!
 //! [`Alphanumeric`] is a simple distribution to sample random letters and
 //! numbers of the `char` type; in contrast [`Standard`] may sample any valid
-//! `char`.
+//! `char`. [`PrintableUnicode`] samples any printable character.
 //!
 //!
 //! # Uniform numeric ranges
@@ -79,7 +81,8 @@
 //! the [`Bernoulli`] distribution (this is used by [`Rng::gen_bool`]).
 //!
 //! For weighted sampling from a sequence of discrete values, use the
-//! [`WeightedIndex`] distribution.
+//! [`WeightedIndex`] distribution, or [`Pmf`] to sample values from a table
+//! of probabilities.
 //!
 //! This crate no longer includes other non-uniform distributions; instead
 //! it is recommended that you use either [`rand_distr`] or [`statrs`].
@@ -94,11 +97,32 @@
 //! [`statrs`]: https://crates.io/crates/statrs
 
 mod bernoulli;
+#[cfg(feature = "alloc")]
+mod cron;
 mod distribution;
+#[cfg(feature = "alloc")]
+mod dyn_distribution;
 mod float;
+mod fuzz;
+mod hamming;
 mod integer;
+#[cfg(feature = "alloc")]
+mod jittered_grid;
+#[cfg(feature = "alloc")]
+mod luhn;
 mod other;
+#[cfg(feature = "alloc")]
+mod phone;
+#[cfg(feature = "alloc")]
+mod pmf;
+#[cfg(feature = "alloc")]
+mod quantile;
 mod slice;
+#[cfg(feature = "alloc")]
+mod tree;
+#[cfg(feature = "alloc")]
+mod until_sum;
+mod unicode;
 mod utils;
 #[cfg(feature = "alloc")]
 mod weighted_index;
@@ -117,16 +141,40 @@ pub mod uniform;
 pub mod weighted;
 
 pub use self::bernoulli::{Bernoulli, BernoulliError};
-pub use self::distribution::{Distribution, DistIter, DistMap};
+#[cfg(feature = "alloc")]
+pub use self::cron::CronExpr;
+pub use self::distribution::{Distribution, DistIter, DistMap, DistZip};
 #[cfg(feature = "alloc")]
 pub use self::distribution::DistString;
+#[cfg(feature = "alloc")]
+pub use self::dyn_distribution::DynDistribution;
 pub use self::float::{Open01, OpenClosed01};
+pub use self::fuzz::FuzzF64;
+pub use self::hamming::{FixedHammingWeight, FixedHammingWeightError};
+#[cfg(feature = "alloc")]
+pub use self::jittered_grid::JitteredGrid;
+#[cfg(feature = "alloc")]
+pub use self::luhn::LuhnNumber;
 pub use self::other::Alphanumeric;
+#[cfg(feature = "alloc")]
+pub use self::phone::{PhoneLike, Region};
+#[cfg(feature = "alloc")]
+pub use self::pmf::{Pmf, PmfError};
+#[cfg(feature = "alloc")]
+pub use self::quantile::sample_quantiles;
 pub use self::slice::Slice;
+#[cfg(feature = "alloc")]
+pub use self::tree::{RandomTreeData, TreeNode};
+#[cfg(feature = "alloc")]
+pub use self::until_sum::sample_until_sum;
 #[doc(inline)]
 pub use self::uniform::Uniform;
+pub use self::unicode::PrintableUnicode;
+#[cfg(feature = "derive")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "derive")))]
+pub use rand_derive::RandUniform;
 #[cfg(feature = "alloc")]
-pub use self::weighted_index::{WeightedError, WeightedIndex};
+pub use self::weighted_index::{WeightedError, WeightedIndex, WeightedIndexExtended, WeightedLabels};
 
 #[allow(unused)]
 use crate::Rng;
@@ -151,8 +199,9 @@ use crate::Rng;
 ///   half-open range `[0, 1)`. See notes below.
 /// * Wrapping integers ([`Wrapping<T>`]), besides the type identical to their
 ///   normal integer variants.
-/// * Non-zero integers ([`NonZeroU8`]), which are like their normal integer
-///   variants but cannot produce zero.
+/// * Non-zero integers ([`NonZeroU8`], [`NonZeroI8`], etc.), which are like
+///   their normal integer variants but cannot produce zero: zero is rejected
+///   and sampled again, thus all non-zero values are equally likely.
 /// * SIMD types like x86's [`__m128i`], `std::simd`'s [`u32x4`]/[`f32x4`]/
 ///   [`mask32x4`] (requires [`simd_support`]), where each lane is distributed
 ///   like their scalar `Standard` variants. See the list of `Standard`
@@ -162,7 +211,7 @@ use crate::Rng;
 /// compound types where all component types are supported:
 ///
 /// *   Tuples (up to 12 elements): each element is generated sequentially.
-/// *   Arrays: each element is generated sequentially;
+/// *   Arrays `[T; N]` of any length: each element is generated sequentially;
 ///     see also [`Rng::fill`] which supports arbitrary array length for integer
 ///     and float types and tends to be faster for `u32` and smaller types.
 ///     Note that [`Rng::fill`] and `Standard`'s array support are *not* equivalent:
@@ -219,6 +268,7 @@ use crate::Rng;
 /// [`Uniform`]: uniform::Uniform
 /// [`Wrapping<T>`]: std::num::Wrapping
 /// [`NonZeroU8`]: std::num::NonZeroU8
+/// [`NonZeroI8`]: std::num::NonZeroI8
 /// [`__m128i`]: https://doc.rust-lang.org/core/arch/x86/struct.__m128i.html
 /// [`u32x4`]: std::simd::u32x4
 /// [`f32x4`]: std::simd::f32x4
@@ -303,6 +303,29 @@ mod tests {
         assert!(!word.is_empty());
     }
 
+    #[test]
+    fn test_large_arrays_and_tuples() {
+        let mut rng = crate::test::rng(808);
+
+        let nonce: [u8; 64] = rng.gen();
+        assert!(nonce.iter().any(|&x| x != nonce[0]));
+        let words: [u64; 100] = rng.gen();
+        for (i, x) in words.iter().enumerate() {
+            assert!(words[i + 1..].iter().all(|y| y != x));
+        }
+        let nested: [[u32; 4]; 33] = rng.gen();
+        assert!(nested.iter().any(|a| a != &nested[0]));
+
+        type Twelve = (u64, u64, u64, u64, u64, u64, u64, u64, u64, u64, u64, u64);
+        let t: Twelve = rng.gen();
+        let elems = [t.0, t.1, t.2, t.3, t.4, t.5, t.6, t.7, t.8, t.9, t.10, t.11];
+        for (i, x) in elems.iter().enumerate() {
+            assert!(elems[i + 1..].iter().all(|y| y != x));
+        }
+        type Mixed = (u8, bool, char, f32, f64, i16, u32, i64, u128, usize, Option<u8>, [u8; 2]);
+        let _: Mixed = rng.gen();
+    }
+
     #[test]
     fn test_alphanumeric() {
         let mut rng = crate::test::rng(806);
@@ -0,0 +1,146 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Phone-number-like strings.
+
+use crate::distributions::Distribution;
+use crate::Rng;
+use alloc::string::String;
+
+/// A region whose phone number format is known to [`PhoneLike`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Region {
+    /// North American Numbering Plan (US, Canada, ...): `+1 NXX-NXX-XXXX`
+    NorthAmerica,
+    /// UK mobile numbers: `+44 7XXX XXXXXX`
+    UnitedKingdom,
+    /// French mobile numbers: `+33 6 XX XX XX XX`
+    France,
+    /// German (Berlin) landline numbers: `+49 30 XXXXXXXX`
+    Germany,
+    /// Japanese mobile numbers: `+81 90-XXXX-XXXX`
+    Japan,
+    /// Australian mobile numbers: `+61 4XX XXX XXX`
+    Australia,
+}
+
+impl Region {
+    /// All supported regions
+    pub const ALL: [Region; 6] = [
+        Region::NorthAmerica,
+        Region::UnitedKingdom,
+        Region::France,
+        Region::Germany,
+        Region::Japan,
+        Region::Australia,
+    ];
+
+    /// The format of numbers in this region
+    ///
+    /// In the pattern, `X` stands for any digit and `N` for a digit from 2
+    /// to 9; all other characters appear literally.
+    pub fn pattern(self) -> &'static str {
+        match self {
+            Region::NorthAmerica => "+1 NXX-NXX-XXXX",
+            Region::UnitedKingdom => "+44 7XXX XXXXXX",
+            Region::France => "+33 6 XX XX XX XX",
+            Region::Germany => "+49 30 XXXXXXXX",
+            Region::Japan => "+81 90-XXXX-XXXX",
+            Region::Australia => "+61 4XX XXX XXX",
+        }
+    }
+}
+
+/// Sample strings formatted like phone numbers of a [`Region`], e.g. for
+/// testing forms.
+///
+/// Each sample follows [`Region::pattern`], with its country code, area or
+/// mobile prefix and digit grouping, and uniformly random digits in the
+/// remaining positions. The numbers are syntactically plausible but are not
+/// checked against allocated ranges, so they may or may not be in service.
+///
+/// # Example
+///
+/// ```
+/// use rand::distributions::{Distribution, PhoneLike, Region};
+///
+/// let dist = PhoneLike { region: Region::France };
+/// let number = dist.sample(&mut rand::thread_rng());
+/// assert!(number.starts_with("+33 6 "));
+/// assert_eq!(number.len(), "+33 6 XX XX XX XX".len());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PhoneLike {
+    /// The region whose format to follow
+    pub region: Region,
+}
+
+impl Distribution<String> for PhoneLike {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> String {
+        self.region
+            .pattern()
+            .chars()
+            .map(|c| match c {
+                'X' => char::from(b'0' + rng.gen_range(0..10u8)),
+                'N' => char::from(b'0' + rng.gen_range(2..10u8)),
+                c => c,
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn matches(number: &str, pattern: &str) -> bool {
+        number.len() == pattern.len()
+            && number.chars().zip(pattern.chars()).all(|(c, p)| match p {
+                'X' => c.is_ascii_digit(),
+                'N' => ('2'..='9').contains(&c),
+                p => c == p,
+            })
+    }
+
+    #[test]
+    fn test_phone_like_formats() {
+        let mut rng = crate::test::rng(307);
+        for &region in Region::ALL.iter() {
+            let dist = PhoneLike { region };
+            for _ in 0..200 {
+                let number = dist.sample(&mut rng);
+                assert!(matches(&number, region.pattern()), "{:?}: {}", region, number);
+            }
+        }
+    }
+
+    #[test]
+    fn test_phone_like_examples() {
+        assert!(matches("+1 212-555-0123", Region::NorthAmerica.pattern()));
+        assert!(!matches("+1 112-555-0123", Region::NorthAmerica.pattern()));
+        assert!(matches("+44 7700 900123", Region::UnitedKingdom.pattern()));
+        assert!(matches("+81 90-1234-5678", Region::Japan.pattern()));
+        assert!(!matches("+61 412 345 67", Region::Australia.pattern()));
+    }
+
+    #[test]
+    fn test_phone_like_digits_vary() {
+        let mut rng = crate::test::rng(308);
+        let dist = PhoneLike { region: Region::NorthAmerica };
+        let mut seen = [false; 10];
+        for _ in 0..100 {
+            let number = dist.sample(&mut rng);
+            // The first digit of the area code is never 0 or 1
+            let area = number.as_bytes()[3];
+            assert!((b'2'..=b'9').contains(&area));
+            seen[usize::from(number.as_bytes()[14] - b'0')] = true;
+        }
+        assert!(seen.iter().all(|&s| s));
+    }
+}
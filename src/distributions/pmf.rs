@@ -0,0 +1,175 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Sampling from a probability mass function given as a table.
+
+use crate::distributions::Distribution;
+use crate::Rng;
+use alloc::vec::Vec;
+use core::fmt;
+
+#[cfg(feature = "serde1")]
+use serde::{Serialize, Deserialize};
+
+/// A discrete distribution over arbitrary values, given by a table of
+/// `(value, probability)` pairs.
+///
+/// The probabilities must sum to 1, up to a tolerance of `1e-6` to allow for
+/// rounding, e.g. of an empirical PMF: such sums are accepted and the
+/// probabilities normalized (divided by their sum). Other sums are rejected
+/// with [`PmfError::InvalidSum`]; for arbitrary weights, see
+/// [`WeightedIndex`](super::WeightedIndex).
+///
+/// Values are sampled by a binary search of the cumulative probabilities
+/// (`O(log n)` time). Values with probability zero are never sampled.
+/// [`Pmf::sample_ref`] returns a reference to the value, while the
+/// [`Distribution`] implementation clones it.
+///
+/// # Example
+///
+/// ```
+/// use rand::distributions::{Distribution, Pmf};
+///
+/// let pmf = Pmf::new(vec![(0.5, 0.2), (1.5, 0.5), (2.5, 0.3)]).unwrap();
+/// let x: f64 = pmf.sample(&mut rand::thread_rng());
+/// assert!(x == 0.5 || x == 1.5 || x == 2.5);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct Pmf<T> {
+    values: Vec<T>,
+    /// Inclusive cumulative probabilities, ending with `1.0`
+    cumulative: Vec<f64>,
+}
+
+/// Error type returned from [`Pmf::new`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PmfError {
+    /// The table is empty.
+    Empty,
+    /// A probability is negative or not finite.
+    InvalidProbability,
+    /// The probabilities do not sum to 1 (within the tolerance).
+    InvalidSum,
+}
+
+impl fmt::Display for PmfError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            PmfError::Empty => "empty table in Pmf distribution",
+            PmfError::InvalidProbability => "probability negative or not finite in Pmf distribution",
+            PmfError::InvalidSum => "probabilities do not sum to 1 in Pmf distribution",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl ::std::error::Error for PmfError {}
+
+impl<T> Pmf<T> {
+    /// Construct from `(value, probability)` pairs.
+    ///
+    /// Requires the table to be non-empty, the probabilities to be finite
+    /// and non-negative, and their sum to be within `1e-6` of 1.
+    pub fn new(table: Vec<(T, f64)>) -> Result<Pmf<T>, PmfError> {
+        if table.is_empty() {
+            return Err(PmfError::Empty);
+        }
+        let mut total = 0.0;
+        let mut cumulative = Vec::with_capacity(table.len());
+        for &(_, p) in &table {
+            if !(p >= 0.0 && p.is_finite()) {
+                return Err(PmfError::InvalidProbability);
+            }
+            total += p;
+            cumulative.push(total);
+        }
+        if !((total - 1.0).abs() <= 1e-6) {
+            return Err(PmfError::InvalidSum);
+        }
+
+        // Normalize, such that the last value of non-zero probability ends
+        // at exactly 1 (and `rng.gen::<f64>() < 1` always finds a value)
+        let last = cumulative.iter().rposition(|&c| c < total).map_or(0, |i| i + 1);
+        for c in &mut cumulative[..last] {
+            *c /= total;
+        }
+        for c in &mut cumulative[last..] {
+            *c = 1.0;
+        }
+
+        Ok(Pmf {
+            values: table.into_iter().map(|(value, _)| value).collect(),
+            cumulative,
+        })
+    }
+
+    /// Sample a value, returning a reference to it.
+    pub fn sample_ref<R: Rng + ?Sized>(&self, rng: &mut R) -> &T {
+        let u = rng.gen::<f64>();
+        &self.values[self.cumulative.partition_point(|&c| c <= u)]
+    }
+}
+
+impl<T: Clone> Distribution<T> for Pmf<T> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> T {
+        self.sample_ref(rng).clone()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_pmf() {
+        let mut rng = crate::test::rng(426);
+        let table = [("a", 0.1), ("b", 0.0), ("c", 0.25), ("d", 0.65), ("e", 0.0)];
+        let pmf = Pmf::new(table.to_vec()).unwrap();
+
+        const N: usize = 100_000;
+        let mut counts = [0usize; 5];
+        for _ in 0..N {
+            let value = pmf.sample_ref(&mut rng);
+            counts[table.iter().position(|(v, _)| v == value).unwrap()] += 1;
+        }
+        for (&count, &(_, p)) in counts.iter().zip(table.iter()) {
+            let expected = p * N as f64;
+            // More than 5 standard deviations is very unlikely
+            assert!((count as f64 - expected).abs() <= 5.0 * expected.sqrt(), "{:?}", counts);
+        }
+
+        // Non-integer values, cloned
+        let pmf = Pmf::new(vec![(0.5, 0.5), (-1.5, 0.5)]).unwrap();
+        let sum: f64 = (0..1000).map(|_| pmf.sample(&mut rng)).sum();
+        assert!(sum > -600.0 && sum < -400.0);
+
+        let pmf = Pmf::new(vec![('x', 1.0)]).unwrap();
+        assert_eq!(pmf.sample(&mut rng), 'x');
+    }
+
+    #[test]
+    fn test_pmf_sum() {
+        // Sums close to 1 are normalized
+        let pmf = Pmf::new(vec![(1, 0.3), (2, 0.3), (3, 0.4000005), (4, 0.0)]).unwrap();
+        assert_eq!(*pmf.cumulative.last().unwrap(), 1.0);
+        assert_eq!(pmf.cumulative[2], 1.0);
+        assert!((pmf.cumulative[0] - 0.3 / 1.0000005).abs() < 1e-15);
+        let pmf = Pmf::new(vec![(1, 1.0 / 3.0), (2, 1.0 / 3.0), (3, 1.0 / 3.0)]).unwrap();
+        assert_eq!(pmf.cumulative[2], 1.0);
+
+        assert_eq!(Pmf::new(vec![(1, 0.5), (2, 0.49)]), Err(PmfError::InvalidSum));
+        assert_eq!(Pmf::new(vec![(1, 0.5), (2, 0.51)]), Err(PmfError::InvalidSum));
+        assert_eq!(Pmf::new(vec![(1, 0.0)]), Err(PmfError::InvalidSum));
+        assert_eq!(Pmf::<u8>::new(vec![]), Err(PmfError::Empty));
+        assert_eq!(Pmf::new(vec![(1, 1.5), (2, -0.5)]), Err(PmfError::InvalidProbability));
+        assert_eq!(Pmf::new(vec![(1, f64::NAN), (2, 1.0)]), Err(PmfError::InvalidProbability));
+        assert_eq!(Pmf::new(vec![(1, f64::INFINITY)]), Err(PmfError::InvalidProbability));
+    }
+}
@@ -0,0 +1,127 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Sample quantiles
+
+use crate::distributions::Distribution;
+use crate::Rng;
+use alloc::vec::Vec;
+
+/// Estimate quantiles of a distribution from `n` samples, e.g. for quick
+/// plotting.
+///
+/// Draws `n` samples from `dist`, sorts them and returns the value at each of
+/// the requested `quantiles`, in the same order. Values between two samples
+/// are linearly interpolated: the quantile `q` is at position `q * (n - 1)`
+/// of the sorted samples, thus `0.0` yields the minimum and `1.0` the maximum.
+///
+/// # Panics
+///
+/// Panics if `n == 0`, if any quantile is not within `[0, 1]` or if `dist`
+/// yields a NaN sample.
+///
+/// # Example
+///
+/// ```
+/// use rand::distributions::{sample_quantiles, Uniform};
+///
+/// let dist = Uniform::new(0.0, 10.0).unwrap();
+/// let q = sample_quantiles(&mut rand::thread_rng(), &dist, 1000, &[0.25, 0.5, 0.75]);
+/// assert!(q[0] <= q[1] && q[1] <= q[2]);
+/// ```
+#[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+pub fn sample_quantiles<R, D>(rng: &mut R, dist: &D, n: usize, quantiles: &[f64]) -> Vec<f64>
+where
+    R: Rng + ?Sized,
+    D: Distribution<f64> + ?Sized,
+{
+    assert!(n > 0, "sample_quantiles: n must be positive");
+    for &q in quantiles {
+        assert!(
+            (0.0..=1.0).contains(&q),
+            "sample_quantiles: quantile {} not in [0, 1]",
+            q
+        );
+    }
+
+    let mut samples: Vec<f64> = (0..n).map(|_| dist.sample(rng)).collect();
+    // Samples other than NaN are totally ordered
+    samples.sort_by(|a, b| a.partial_cmp(b).expect("sample_quantiles: NaN sample"));
+
+    quantiles
+        .iter()
+        .map(|&q| {
+            let pos = q * (n - 1) as f64;
+            let lo = pos as usize;
+            if lo + 1 >= n {
+                samples[n - 1]
+            } else {
+                let frac = pos - lo as f64;
+                samples[lo] + frac * (samples[lo + 1] - samples[lo])
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::distributions::{Uniform, Standard};
+
+    #[test]
+    fn test_uniform_quantiles() {
+        let mut rng = crate::test::rng(279);
+        let q = sample_quantiles(&mut rng, &Standard, 10000, &[0.0, 0.1, 0.5, 0.9, 1.0]);
+        assert_eq!(q.len(), 5);
+        assert!(q[0] >= 0.0 && q[0] < 0.01);
+        assert!((q[1] - 0.1).abs() < 0.02);
+        assert!((q[2] - 0.5).abs() < 0.02);
+        assert!((q[3] - 0.9).abs() < 0.02);
+        assert!(q[4] > 0.99 && q[4] < 1.0);
+    }
+
+    #[test]
+    fn test_interpolation() {
+        // A constant distribution
+        let mut rng = crate::test::rng(280);
+        let dist = Uniform::new_inclusive(3.0, 3.0).unwrap();
+        assert_eq!(sample_quantiles(&mut rng, &dist, 7, &[0.0, 0.3, 1.0]), [3.0, 3.0, 3.0]);
+
+        // Two samples: 0 and 1 from the mock RNG, interpolated in between
+        let mut rng = crate::rngs::mock::StepRng::new(0, 1 << 63);
+        let dist = Uniform::new_inclusive(0.0, 1.0).unwrap();
+        let q = sample_quantiles(&mut rng, &dist, 2, &[0.0, 0.25, 1.0]);
+        assert_eq!(q[0], 0.0);
+        assert_eq!(q[1], 0.25 * q[2]);
+        assert!(q[2] > 0.0);
+
+        assert!(sample_quantiles(&mut rng, &dist, 5, &[]).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_invalid_quantile() {
+        let mut rng = crate::test::rng(281);
+        sample_quantiles(&mut rng, &Standard, 10, &[0.5, 1.5]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_nan_quantile() {
+        let mut rng = crate::test::rng(282);
+        sample_quantiles(&mut rng, &Standard, 10, &[f64::NAN]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_nan_sample() {
+        let mut rng = crate::test::rng(283);
+        let dist = Standard.map(|x: f64| if x < 0.5 { f64::NAN } else { x });
+        sample_quantiles(&mut rng, &dist, 10, &[0.5]);
+    }
+}
@@ -0,0 +1,160 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Random tree-shaped data.
+
+use crate::distributions::Distribution;
+use crate::Rng;
+use alloc::vec;
+use alloc::vec::Vec;
+#[cfg(feature = "serde1")]
+use serde::{Serialize, Deserialize};
+
+/// A node of a tree sampled from [`RandomTreeData`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TreeNode<T> {
+    /// A node without children
+    Leaf(T),
+    /// A node with one or more children
+    Node(T, Vec<TreeNode<T>>),
+}
+
+impl<T> TreeNode<T> {
+    /// The value stored in this node
+    pub fn value(&self) -> &T {
+        match self {
+            TreeNode::Leaf(v) | TreeNode::Node(v, _) => v,
+        }
+    }
+
+    /// The children of this node (empty for a leaf)
+    pub fn children(&self) -> &[TreeNode<T>] {
+        match self {
+            TreeNode::Leaf(_) => &[],
+            TreeNode::Node(_, children) => children,
+        }
+    }
+
+    /// The number of nodes in this tree, including the root
+    pub fn node_count(&self) -> usize {
+        let mut count = 0;
+        let mut stack = vec![self];
+        while let Some(node) = stack.pop() {
+            count += 1;
+            stack.extend(node.children());
+        }
+        count
+    }
+}
+
+/// A distribution over random trees of bounded size, e.g. for fuzzing
+/// serializers of recursive data.
+///
+/// Each sample has between `1` and `max_nodes` nodes (inclusive; a
+/// `max_nodes` of `0` is treated as `1` since a tree always has a root). The
+/// value of every node is sampled from `value_dist`.
+///
+/// The shape is that of a *random recursive tree*: node `i` is attached to a
+/// parent chosen uniformly among nodes `0..i`. Such trees have logarithmic
+/// expected depth, making them cheap to traverse recursively.
+///
+/// # Example
+///
+/// ```
+/// use rand::Rng;
+/// use rand::distributions::{RandomTreeData, Standard};
+///
+/// let dist = RandomTreeData { max_nodes: 20, value_dist: Standard };
+/// let tree: rand::distributions::TreeNode<u8> = rand::thread_rng().sample(&dist);
+/// assert!(tree.node_count() <= 20);
+/// ```
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct RandomTreeData<D> {
+    /// The maximum number of nodes in a sampled tree
+    pub max_nodes: usize,
+    /// The distribution of node values
+    pub value_dist: D,
+}
+
+impl<T, D: Distribution<T>> Distribution<TreeNode<T>> for RandomTreeData<D> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> TreeNode<T> {
+        let n = rng.gen_range(1..=self.max_nodes.max(1));
+
+        let mut values: Vec<T> = Vec::with_capacity(n);
+        let mut parents: Vec<usize> = Vec::with_capacity(n);
+        for i in 0..n {
+            values.push(self.value_dist.sample(rng));
+            parents.push(if i == 0 { 0 } else { rng.gen_range(0..i) });
+        }
+
+        // Since every parent has a lower index than its children, building
+        // from the highest index down completes each subtree before it is
+        // attached to its parent.
+        let mut children: Vec<Vec<TreeNode<T>>> = (0..n).map(|_| Vec::new()).collect();
+        for i in (0..n).rev() {
+            let value = values.pop().unwrap();
+            let mut kids = core::mem::take(&mut children[i]);
+            let node = if kids.is_empty() {
+                TreeNode::Leaf(value)
+            } else {
+                kids.reverse();
+                TreeNode::Node(value, kids)
+            };
+            if i == 0 {
+                return node;
+            }
+            children[parents[i]].push(node);
+        }
+        unreachable!()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::distributions::Uniform;
+
+    #[test]
+    fn test_tree_budget() {
+        let mut rng = crate::test::rng(277);
+        for &max_nodes in &[0, 1, 2, 5, 100] {
+            let dist = RandomTreeData { max_nodes, value_dist: Uniform::new(10u32, 20).unwrap() };
+            let mut seen_max = 0;
+            for _ in 0..200 {
+                let tree: TreeNode<u32> = rng.sample(dist);
+                let count = tree.node_count();
+                assert!(count >= 1 && count <= max_nodes.max(1));
+                seen_max = seen_max.max(count);
+            }
+            // Sizes are uniform; with 200 samples the bound should be reached
+            // for small budgets.
+            if max_nodes <= 5 {
+                assert_eq!(seen_max, max_nodes.max(1));
+            }
+        }
+    }
+
+    #[test]
+    fn test_tree_well_formed() {
+        let mut rng = crate::test::rng(278);
+        let dist = RandomTreeData { max_nodes: 50, value_dist: Uniform::new(10u32, 20).unwrap() };
+        for _ in 0..100 {
+            let tree: TreeNode<u32> = rng.sample(dist);
+            let mut stack = vec![&tree];
+            while let Some(node) = stack.pop() {
+                assert!((10..20).contains(node.value()));
+                match node {
+                    TreeNode::Leaf(_) => assert!(node.children().is_empty()),
+                    TreeNode::Node(_, children) => assert!(!children.is_empty()),
+                }
+                stack.extend(node.children());
+            }
+        }
+    }
+}
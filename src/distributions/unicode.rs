@@ -0,0 +1,292 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The printable Unicode distribution.
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+#[cfg(feature = "alloc")]
+use crate::distributions::DistString;
+use crate::distributions::Distribution;
+use crate::Rng;
+#[cfg(feature = "serde1")]
+use serde::{Serialize, Deserialize};
+
+/// Sample a `char`, uniformly distributed over printable Unicode characters.
+///
+/// These are the 144516 assigned characters of Unicode 14.0.0 except for
+/// those of the general categories *Other* (control and format characters,
+/// surrogates, private use and unassigned code points) and *Separator*, but
+/// including the ASCII space. This corresponds to `str.isprintable` in
+/// Python. Unicode versions after 14.0.0 assigned further characters; these
+/// are not produced, and the set will not change without a breaking release.
+///
+/// Most of the characters are CJK ideographs, and almost all are encoded
+/// with three or four bytes in UTF-8.
+///
+/// # Example
+///
+/// ```
+/// use rand::distributions::{DistString, PrintableUnicode};
+///
+/// let s = PrintableUnicode.sample_string(&mut rand::thread_rng(), 8);
+/// assert_eq!(s.chars().count(), 8);
+/// assert!(s.chars().all(|c| !c.is_control()));
+/// println!("{}", s);
+/// ```
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct PrintableUnicode;
+
+impl Distribution<char> for PrintableUnicode {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> char {
+        let k = rng.gen_range(0..TOTAL);
+        // The last range starting at or before `k` in the flattened sequence
+        let i = RANGES.partition_point(|&(_, offset)| offset <= k) - 1;
+        let (start, offset) = RANGES[i];
+        char::from_u32(start + (k - offset)).unwrap()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl DistString for PrintableUnicode {
+    fn append_string<R: Rng + ?Sized>(&self, rng: &mut R, s: &mut String, len: usize) {
+        s.reserve(4 * len);
+        s.extend(self.sample_iter(rng).take(len));
+    }
+}
+
+/// The number of printable characters
+const TOTAL: u32 = 144516;
+
+/// Ranges of printable characters as `(first code point, offset)`, where
+/// `offset` is the number of printable characters in preceding ranges. Each
+/// range ends where the offset of the next range is reached.
+///
+/// Generated from the Unicode Character Database, version 14.0.0.
+#[rustfmt::skip]
+const RANGES: [(u32, u32); 700] = [
+    (0x00020,      0), (0x000a1,     95), (0x000ae,    107), (0x0037a,    821),
+    (0x00384,    827), (0x0038c,    834), (0x0038e,    835), (0x003a3,    855),
+    (0x00531,   1252), (0x00559,   1290), (0x0058d,   1340), (0x00591,   1343),
+    (0x005d0,   1398), (0x005ef,   1425), (0x00606,   1431), (0x0061d,   1453),
+    (0x006de,   1645), (0x00710,   1693), (0x0074d,   1752), (0x007c0,   1853),
+    (0x007fd,   1912), (0x00830,   1961), (0x00840,   1976), (0x0085e,   2004),
+    (0x00860,   2005), (0x00870,   2016), (0x00898,   2047), (0x008e3,   2121),
+    (0x00985,   2282), (0x0098f,   2290), (0x00993,   2292), (0x009aa,   2314),
+    (0x009b2,   2321), (0x009b6,   2322), (0x009bc,   2326), (0x009c7,   2335),
+    (0x009cb,   2337), (0x009d7,   2341), (0x009dc,   2342), (0x009df,   2344),
+    (0x009e6,   2349), (0x00a01,   2374), (0x00a05,   2377), (0x00a0f,   2383),
+    (0x00a13,   2385), (0x00a2a,   2407), (0x00a32,   2414), (0x00a35,   2416),
+    (0x00a38,   2418), (0x00a3c,   2420), (0x00a3e,   2421), (0x00a47,   2426),
+    (0x00a4b,   2428), (0x00a51,   2431), (0x00a59,   2432), (0x00a5e,   2436),
+    (0x00a66,   2437), (0x00a81,   2454), (0x00a85,   2457), (0x00a8f,   2466),
+    (0x00a93,   2469), (0x00aaa,   2491), (0x00ab2,   2498), (0x00ab5,   2500),
+    (0x00abc,   2505), (0x00ac7,   2515), (0x00acb,   2518), (0x00ad0,   2521),
+    (0x00ae0,   2522), (0x00ae6,   2526), (0x00af9,   2538), (0x00b01,   2545),
+    (0x00b05,   2548), (0x00b0f,   2556), (0x00b13,   2558), (0x00b2a,   2580),
+    (0x00b32,   2587), (0x00b35,   2589), (0x00b3c,   2594), (0x00b47,   2603),
+    (0x00b4b,   2605), (0x00b55,   2608), (0x00b5c,   2611), (0x00b5f,   2613),
+    (0x00b66,   2618), (0x00b82,   2636), (0x00b85,   2638), (0x00b8e,   2644),
+    (0x00b92,   2647), (0x00b99,   2651), (0x00b9c,   2653), (0x00b9e,   2654),
+    (0x00ba3,   2656), (0x00ba8,   2658), (0x00bae,   2661), (0x00bbe,   2673),
+    (0x00bc6,   2678), (0x00bca,   2681), (0x00bd0,   2685), (0x00bd7,   2686),
+    (0x00be6,   2687), (0x00c00,   2708), (0x00c0e,   2721), (0x00c12,   2724),
+    (0x00c2a,   2747), (0x00c3c,   2763), (0x00c46,   2772), (0x00c4a,   2775),
+    (0x00c55,   2779), (0x00c58,   2781), (0x00c5d,   2784), (0x00c60,   2785),
+    (0x00c66,   2789), (0x00c77,   2799), (0x00c8e,   2821), (0x00c92,   2824),
+    (0x00caa,   2847), (0x00cb5,   2857), (0x00cbc,   2862), (0x00cc6,   2871),
+    (0x00cca,   2874), (0x00cd5,   2878), (0x00cdd,   2880), (0x00ce0,   2882),
+    (0x00ce6,   2886), (0x00cf1,   2896), (0x00d00,   2898), (0x00d0e,   2911),
+    (0x00d12,   2914), (0x00d46,   2965), (0x00d4a,   2968), (0x00d54,   2974),
+    (0x00d66,   2990), (0x00d81,   3016), (0x00d85,   3019), (0x00d9a,   3037),
+    (0x00db3,   3061), (0x00dbd,   3070), (0x00dc0,   3071), (0x00dca,   3078),
+    (0x00dcf,   3079), (0x00dd6,   3085), (0x00dd8,   3086), (0x00de6,   3094),
+    (0x00df2,   3104), (0x00e01,   3107), (0x00e3f,   3165), (0x00e81,   3194),
+    (0x00e84,   3196), (0x00e86,   3197), (0x00e8c,   3202), (0x00ea5,   3226),
+    (0x00ea7,   3227), (0x00ec0,   3250), (0x00ec6,   3255), (0x00ec8,   3256),
+    (0x00ed0,   3262), (0x00edc,   3272), (0x00f00,   3276), (0x00f49,   3348),
+    (0x00f71,   3384), (0x00f99,   3423), (0x00fbe,   3459), (0x00fce,   3474),
+    (0x01000,   3487), (0x010c7,   3685), (0x010cd,   3686), (0x010d0,   3687),
+    (0x0124a,   4064), (0x01250,   4068), (0x01258,   4075), (0x0125a,   4076),
+    (0x01260,   4080), (0x0128a,   4121), (0x01290,   4125), (0x012b2,   4158),
+    (0x012b8,   4162), (0x012c0,   4169), (0x012c2,   4170), (0x012c8,   4174),
+    (0x012d8,   4189), (0x01312,   4246), (0x01318,   4250), (0x0135d,   4317),
+    (0x01380,   4349), (0x013a0,   4375), (0x013f8,   4461), (0x01400,   4467),
+    (0x01681,   5107), (0x016a0,   5135), (0x01700,   5224), (0x0171f,   5246),
+    (0x01740,   5270), (0x01760,   5290), (0x0176e,   5303), (0x01772,   5306),
+    (0x01780,   5308), (0x017e0,   5402), (0x017f0,   5412), (0x01800,   5422),
+    (0x0180f,   5436), (0x01820,   5447), (0x01880,   5536), (0x018b0,   5579),
+    (0x01900,   5649), (0x01920,   5680), (0x01930,   5692), (0x01940,   5704),
+    (0x01944,   5705), (0x01970,   5747), (0x01980,   5752), (0x019b0,   5796),
+    (0x019d0,   5822), (0x019de,   5833), (0x01a1e,   5895), (0x01a60,   5960),
+    (0x01a7f,   5989), (0x01a90,   6000), (0x01aa0,   6010), (0x01ab0,   6024),
+    (0x01b00,   6055), (0x01b50,   6132), (0x01b80,   6179), (0x01bfc,   6295),
+    (0x01c3b,   6355), (0x01c4d,   6370), (0x01c90,   6430), (0x01cbd,   6473),
+    (0x01cd0,   6484), (0x01d00,   6527), (0x01f18,   7061), (0x01f20,   7067),
+    (0x01f48,   7105), (0x01f50,   7111), (0x01f59,   7119), (0x01f5b,   7120),
+    (0x01f5d,   7121), (0x01f5f,   7122), (0x01f80,   7153), (0x01fb6,   7206),
+    (0x01fc6,   7221), (0x01fd6,   7235), (0x01fdd,   7241), (0x01ff2,   7260),
+    (0x01ff6,   7263), (0x02010,   7272), (0x02030,   7296), (0x02070,   7343),
+    (0x02074,   7345), (0x02090,   7372), (0x020a0,   7385), (0x020d0,   7418),
+    (0x02100,   7451), (0x02190,   7591), (0x02440,   8254), (0x02460,   8265),
+    (0x02b76,  10077), (0x02b97,  10109), (0x02cf9,  10458), (0x02d27,  10503),
+    (0x02d2d,  10504), (0x02d30,  10505), (0x02d6f,  10561), (0x02d7f,  10563),
+    (0x02da0,  10587), (0x02da8,  10594), (0x02db0,  10601), (0x02db8,  10608),
+    (0x02dc0,  10615), (0x02dc8,  10622), (0x02dd0,  10629), (0x02dd8,  10636),
+    (0x02de0,  10643), (0x02e80,  10769), (0x02e9b,  10795), (0x02f00,  10884),
+    (0x02ff0,  11098), (0x03001,  11110), (0x03041,  11173), (0x03099,  11259),
+    (0x03105,  11362), (0x03131,  11405), (0x03190,  11499), (0x031f0,  11583),
+    (0x03220,  11630), (0x0a490,  40923), (0x0a4d0,  40978), (0x0a640,  41326),
+    (0x0a700,  41510), (0x0a7d0,  41713), (0x0a7d3,  41715), (0x0a7d5,  41716),
+    (0x0a7f2,  41721), (0x0a830,  41780), (0x0a840,  41790), (0x0a880,  41846),
+    (0x0a8ce,  41916), (0x0a8e0,  41928), (0x0a95f,  42044), (0x0a980,  42074),
+    (0x0a9cf,  42152), (0x0a9de,  42163), (0x0aa00,  42196), (0x0aa40,  42251),
+    (0x0aa50,  42265), (0x0aa5c,  42275), (0x0aadb,  42378), (0x0ab01,  42406),
+    (0x0ab09,  42412), (0x0ab11,  42418), (0x0ab20,  42424), (0x0ab28,  42431),
+    (0x0ab30,  42438), (0x0ab70,  42498), (0x0abf0,  42624), (0x0ac00,  42634),
+    (0x0d7b0,  53806), (0x0d7cb,  53829), (0x0f900,  53878), (0x0fa70,  54244),
+    (0x0fb00,  54350), (0x0fb13,  54357), (0x0fb1d,  54362), (0x0fb38,  54388),
+    (0x0fb3e,  54393), (0x0fb40,  54394), (0x0fb43,  54396), (0x0fb46,  54398),
+    (0x0fbd3,  54523), (0x0fd92,  54968), (0x0fdcf,  55022), (0x0fdf0,  55023),
+    (0x0fe20,  55065), (0x0fe54,  55116), (0x0fe68,  55135), (0x0fe70,  55139),
+    (0x0fe76,  55144), (0x0ff01,  55279), (0x0ffc2,  55469), (0x0ffca,  55475),
+    (0x0ffd2,  55481), (0x0ffda,  55487), (0x0ffe0,  55490), (0x0ffe8,  55497),
+    (0x0fffc,  55504), (0x10000,  55506), (0x1000d,  55518), (0x10028,  55544),
+    (0x1003c,  55563), (0x1003f,  55565), (0x10050,  55580), (0x10080,  55594),
+    (0x10100,  55717), (0x10107,  55720), (0x10137,  55765), (0x10190,  55853),
+    (0x101a0,  55866), (0x101d0,  55867), (0x10280,  55913), (0x102a0,  55942),
+    (0x102e0,  55991), (0x10300,  56019), (0x1032d,  56055), (0x10350,  56085),
+    (0x10380,  56128), (0x1039f,  56158), (0x103c8,  56195), (0x10400,  56209),
+    (0x104a0,  56367), (0x104b0,  56377), (0x104d8,  56413), (0x10500,  56449),
+    (0x10530,  56489), (0x1056f,  56541), (0x1057c,  56553), (0x1058c,  56568),
+    (0x10594,  56575), (0x10597,  56577), (0x105a3,  56588), (0x105b3,  56603),
+    (0x105bb,  56610), (0x10600,  56612), (0x10740,  56923), (0x10760,  56945),
+    (0x10780,  56953), (0x10787,  56959), (0x107b2,  57001), (0x10800,  57010),
+    (0x10808,  57016), (0x1080a,  57017), (0x10837,  57061), (0x1083c,  57063),
+    (0x1083f,  57064), (0x10857,  57087), (0x108a7,  57159), (0x108e0,  57168),
+    (0x108f4,  57187), (0x108fb,  57189), (0x1091f,  57222), (0x1093f,  57249),
+    (0x10980,  57250), (0x109bc,  57306), (0x109d2,  57326), (0x10a05,  57376),
+    (0x10a0c,  57378), (0x10a15,  57386), (0x10a19,  57389), (0x10a38,  57418),
+    (0x10a3f,  57421), (0x10a50,  57431), (0x10a60,  57440), (0x10ac0,  57504),
+    (0x10aeb,  57543), (0x10b00,  57555), (0x10b39,  57609), (0x10b58,  57638),
+    (0x10b78,  57665), (0x10b99,  57691), (0x10ba9,  57695), (0x10c00,  57702),
+    (0x10c80,  57775), (0x10cc0,  57826), (0x10cfa,  57877), (0x10d30,  57923),
+    (0x10e60,  57933), (0x10e80,  57964), (0x10eab,  58006), (0x10eb0,  58009),
+    (0x10f00,  58011), (0x10f30,  58051), (0x10f70,  58093), (0x10fb0,  58119),
+    (0x10fe0,  58147), (0x11000,  58170), (0x11052,  58248), (0x1107f,  58284),
+    (0x110be,  58346), (0x110d0,  58351), (0x110f0,  58376), (0x11100,  58386),
+    (0x11136,  58439), (0x11150,  58457), (0x11180,  58496), (0x111e1,  58592),
+    (0x11200,  58612), (0x11213,  58630), (0x11280,  58674), (0x11288,  58681),
+    (0x1128a,  58682), (0x1128f,  58686), (0x1129f,  58701), (0x112b0,  58712),
+    (0x112f0,  58771), (0x11300,  58781), (0x11305,  58785), (0x1130f,  58793),
+    (0x11313,  58795), (0x1132a,  58817), (0x11332,  58824), (0x11335,  58826),
+    (0x1133b,  58831), (0x11347,  58841), (0x1134b,  58843), (0x11350,  58846),
+    (0x11357,  58847), (0x1135d,  58848), (0x11366,  58855), (0x11370,  58862),
+    (0x11400,  58867), (0x1145d,  58959), (0x11480,  58964), (0x114d0,  59036),
+    (0x11580,  59046), (0x115b8,  59100), (0x11600,  59138), (0x11650,  59207),
+    (0x11660,  59217), (0x11680,  59230), (0x116c0,  59288), (0x11700,  59298),
+    (0x1171d,  59325), (0x11730,  59340), (0x11800,  59363), (0x118a0,  59423),
+    (0x118ff,  59506), (0x11909,  59514), (0x1190c,  59515), (0x11915,  59523),
+    (0x11918,  59525), (0x11937,  59555), (0x1193b,  59557), (0x11950,  59569),
+    (0x119a0,  59579), (0x119aa,  59587), (0x119da,  59633), (0x11a00,  59644),
+    (0x11a50,  59716), (0x11ab0,  59799), (0x11c00,  59872), (0x11c0a,  59881),
+    (0x11c38,  59926), (0x11c50,  59940), (0x11c70,  59969), (0x11c92,  60001),
+    (0x11ca9,  60023), (0x11d00,  60037), (0x11d08,  60044), (0x11d0b,  60046),
+    (0x11d3a,  60090), (0x11d3c,  60091), (0x11d3f,  60093), (0x11d50,  60102),
+    (0x11d60,  60112), (0x11d67,  60118), (0x11d6a,  60120), (0x11d90,  60157),
+    (0x11d93,  60159), (0x11da0,  60165), (0x11ee0,  60175), (0x11fb0,  60200),
+    (0x11fc0,  60201), (0x11fff,  60251), (0x12400,  61174), (0x12470,  61285),
+    (0x12480,  61290), (0x12f90,  61486), (0x13000,  61585), (0x14400,  62656),
+    (0x16800,  63239), (0x16a40,  63808), (0x16a60,  63839), (0x16a6e,  63849),
+    (0x16ac0,  63930), (0x16ad0,  63940), (0x16af0,  63970), (0x16b00,  63976),
+    (0x16b50,  64046), (0x16b5b,  64056), (0x16b63,  64063), (0x16b7d,  64084),
+    (0x16e40,  64103), (0x16f00,  64194), (0x16f4f,  64269), (0x16f8f,  64326),
+    (0x16fe0,  64343), (0x16ff0,  64348), (0x17000,  64350), (0x18800,  70486),
+    (0x18d00,  71724), (0x1aff0,  71733), (0x1aff5,  71737), (0x1affd,  71744),
+    (0x1b000,  71746), (0x1b150,  72037), (0x1b164,  72040), (0x1b170,  72044),
+    (0x1bc00,  72440), (0x1bc70,  72547), (0x1bc80,  72560), (0x1bc90,  72569),
+    (0x1bc9c,  72579), (0x1cf00,  72583), (0x1cf30,  72629), (0x1cf50,  72652),
+    (0x1d000,  72768), (0x1d100,  73014), (0x1d129,  73053), (0x1d17b,  73127),
+    (0x1d200,  73239), (0x1d2e0,  73309), (0x1d300,  73329), (0x1d360,  73416),
+    (0x1d400,  73441), (0x1d456,  73526), (0x1d49e,  73597), (0x1d4a2,  73599),
+    (0x1d4a5,  73600), (0x1d4a9,  73602), (0x1d4ae,  73606), (0x1d4bb,  73618),
+    (0x1d4bd,  73619), (0x1d4c5,  73626), (0x1d507,  73691), (0x1d50d,  73695),
+    (0x1d516,  73703), (0x1d51e,  73710), (0x1d53b,  73738), (0x1d540,  73742),
+    (0x1d546,  73747), (0x1d54a,  73748), (0x1d552,  73755), (0x1d6a8,  74095),
+    (0x1d7ce,  74387), (0x1da9b,  75089), (0x1daa1,  75094), (0x1df00,  75109),
+    (0x1e000,  75140), (0x1e008,  75147), (0x1e01b,  75164), (0x1e023,  75171),
+    (0x1e026,  75173), (0x1e100,  75178), (0x1e130,  75223), (0x1e140,  75237),
+    (0x1e14e,  75247), (0x1e290,  75249), (0x1e2c0,  75280), (0x1e2ff,  75338),
+    (0x1e7e0,  75339), (0x1e7e8,  75346), (0x1e7ed,  75350), (0x1e7f0,  75352),
+    (0x1e800,  75367), (0x1e8c7,  75564), (0x1e900,  75580), (0x1e950,  75656),
+    (0x1e95e,  75666), (0x1ec71,  75668), (0x1ed01,  75736), (0x1ee00,  75797),
+    (0x1ee05,  75801), (0x1ee21,  75828), (0x1ee24,  75830), (0x1ee27,  75831),
+    (0x1ee29,  75832), (0x1ee34,  75842), (0x1ee39,  75846), (0x1ee3b,  75847),
+    (0x1ee42,  75848), (0x1ee47,  75849), (0x1ee49,  75850), (0x1ee4b,  75851),
+    (0x1ee4d,  75852), (0x1ee51,  75855), (0x1ee54,  75857), (0x1ee57,  75858),
+    (0x1ee59,  75859), (0x1ee5b,  75860), (0x1ee5d,  75861), (0x1ee5f,  75862),
+    (0x1ee61,  75863), (0x1ee64,  75865), (0x1ee67,  75866), (0x1ee6c,  75870),
+    (0x1ee74,  75877), (0x1ee79,  75881), (0x1ee7e,  75885), (0x1ee80,  75886),
+    (0x1ee8b,  75896), (0x1eea1,  75913), (0x1eea5,  75916), (0x1eeab,  75921),
+    (0x1eef0,  75938), (0x1f000,  75940), (0x1f030,  75984), (0x1f0a0,  76084),
+    (0x1f0b1,  76099), (0x1f0c1,  76114), (0x1f0d1,  76129), (0x1f100,  76166),
+    (0x1f1e6,  76340), (0x1f210,  76369), (0x1f240,  76413), (0x1f250,  76422),
+    (0x1f260,  76424), (0x1f300,  76430), (0x1f6dd,  77414), (0x1f6f0,  77430),
+    (0x1f700,  77443), (0x1f780,  77559), (0x1f7e0,  77648), (0x1f7f0,  77660),
+    (0x1f800,  77661), (0x1f810,  77673), (0x1f850,  77729), (0x1f860,  77739),
+    (0x1f890,  77779), (0x1f8b0,  77809), (0x1f900,  77811), (0x1fa60,  78151),
+    (0x1fa70,  78165), (0x1fa78,  78170), (0x1fa80,  78175), (0x1fa90,  78182),
+    (0x1fab0,  78211), (0x1fac0,  78222), (0x1fad0,  78228), (0x1fae0,  78238),
+    (0x1faf0,  78246), (0x1fb00,  78253), (0x1fb94,  78400), (0x1fbf0,  78455),
+    (0x20000,  78465), (0x2a700, 121185), (0x2b740, 125338), (0x2b820, 125560),
+    (0x2ceb0, 131322), (0x2f800, 138795), (0x30000, 139337), (0xe0100, 144276),
+];
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_ranges() {
+        let mut prev_end = 0;
+        for (i, &(start, offset)) in RANGES.iter().enumerate() {
+            let end = RANGES.get(i + 1).map_or(TOTAL, |r| r.1) - offset + start;
+            // Sorted, disjoint and not adjacent
+            assert!(i == 0 || start > prev_end, "{:x}", start);
+            for c in start..end {
+                let c = char::from_u32(c).unwrap();
+                assert!(!c.is_control() && (c == ' ' || !c.is_whitespace()), "{:?}", c);
+            }
+            prev_end = end;
+        }
+        assert_eq!(RANGES[0], (0x20, 0));
+    }
+
+    #[test]
+    fn test_printable_unicode() {
+        let mut rng = crate::test::rng(820);
+        let mut multibyte = [0usize; 5];
+        for _ in 0..1000 {
+            let c = PrintableUnicode.sample(&mut rng);
+            assert!(!c.is_control());
+            assert!(!(0xD800..0xE000).contains(&(c as u32)));
+            assert!(!(0xE000..0xF900).contains(&(c as u32)), "private use: {:?}", c);
+            multibyte[c.len_utf8()] += 1;
+        }
+        // About 1% use two bytes, 37% three and 62% four
+        assert!(multibyte[3] > 250 && multibyte[4] > 500, "{:?}", multibyte);
+
+        #[cfg(feature = "alloc")]
+        {
+            let s = PrintableUnicode.sample_string(&mut rng, 100);
+            assert_eq!(s.chars().count(), 100);
+        }
+    }
+}
@@ -233,6 +233,10 @@ impl<X: SampleUniform> Distribution<X> for Uniform<X> {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> X {
         self.0.sample(rng)
     }
+
+    fn fill<R: Rng + ?Sized>(&self, rng: &mut R, dest: &mut [X]) {
+        self.0.fill(rng, dest)
+    }
 }
 
 /// Helper trait for creating objects using the correct implementation of
@@ -282,6 +286,17 @@ pub trait UniformSampler: Sized {
     /// Sample a value.
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::X;
 
+    /// Fill `dest` with sampled values.
+    ///
+    /// By default this samples each element in turn. Implementations may
+    /// override this, but should produce the same values as the default (see
+    /// [`Distribution::fill`]).
+    fn fill<R: Rng + ?Sized>(&self, rng: &mut R, dest: &mut [Self::X]) {
+        for x in dest {
+            *x = self.sample(rng);
+        }
+    }
+
     /// Sample a single value uniformly from a range with inclusive lower bound
     /// and exclusive upper bound `[low, high)`.
     ///
@@ -436,9 +451,13 @@ impl<T: SampleUniform + PartialOrd> SampleRange<T> for RangeInclusive<T> {
 ///
 /// The optimum `zone` is the largest product of `range` which fits in our
 /// (unsigned) target type. We calculate this by calculating how many numbers we
-/// must reject: `reject = (MAX + 1) % range = (MAX - range + 1) % range`. Any (large)
-/// product of `range` will suffice, thus in `sample_single` we multiply by a
-/// power of 2 via bit-shifting (faster but may cause more rejections).
+/// must reject: `reject = (MAX + 1) % range = (MAX - range + 1) % range`.
+/// Computing `reject` requires a division, which is worthwhile only when
+/// sampling repeatedly. Hence `sample_single` and `sample_single_inclusive`
+/// instead use Canon's method, which needs no division: the high word of a
+/// widening multiply is corrected using a second random word in the rare case
+/// that the low word indicates possible bias. This leaves a tiny bias unless
+/// the `unbiased` feature is enabled.
 ///
 /// The smallest integer PRNGs generate is `u32`. For 8- and 16-bit outputs we
 /// use `u32` for our `zone` and samples (because it's not slower and because
@@ -502,6 +521,8 @@ macro_rules! uniform_int_impl {
                 let range = high.wrapping_sub(low).wrapping_add(1) as $uty;
                 let thresh = if range > 0 {
                     let range = $sample_ty::from(range);
+                    // `2^n mod range`, where `n` is the width of `$sample_ty`;
+                    // this does not overflow even for `range = MAX`
                     (range.wrapping_neg() % range)
                 } else {
                     0
@@ -859,6 +880,10 @@ impl UniformSampler for UniformChar {
 /// multiply and addition. Values produced this way have what equals 23 bits of
 /// random digits for an `f32`, and 52 for an `f64`.
 ///
+/// As a consequence, `high` is practically never sampled from an inclusive
+/// range; see [`UniformFloatInclusive`] for a sampler which maps the extreme
+/// random inputs to `low` and `high` exactly.
+///
 /// [`new`]: UniformSampler::new
 /// [`new_inclusive`]: UniformSampler::new_inclusive
 /// [`Standard`]: crate::distributions::Standard
@@ -1093,6 +1118,85 @@ uniform_float_impl! { f64x4, u64x4, f64, u64, 64 - 52 }
 #[cfg(feature = "simd_support")]
 uniform_float_impl! { f64x8, u64x8, f64, u64, 64 - 52 }
 
+/// Sample floats uniformly from a closed interval `[low, high]`, where both
+/// endpoints are reachable.
+///
+/// This scales a random integer `n` from the full range of `u64` (or `u32`
+/// for `f32`) to `low + n / MAX * (high - low)`, returning `low` exactly for
+/// `n == 0` and `high` exactly for `n == MAX`, and never a value outside of
+/// `[low, high]`.
+///
+/// This does not make the endpoints likely: as results are rounded to the
+/// nearest representable float, an endpoint is returned with the probability
+/// of about one grid step next to it, where a step is the spacing of floats
+/// there or `1 / MAX` of the range, whichever is larger. For `[0.0, 1.0]` as
+/// `f64` this is about 2<sup>-54</sup> for `high`, the same as with
+/// [`Uniform::new_inclusive`], and 2<sup>-64</sup> for `low`. The endpoints
+/// are only observed in practice where the range spans few representable
+/// floats.
+///
+/// # Example
+///
+/// ```
+/// use rand::distributions::{Distribution, uniform::UniformFloatInclusive};
+///
+/// let dist = UniformFloatInclusive::new(0.0, 1.0).unwrap();
+/// let x: f64 = dist.sample(&mut rand::thread_rng());
+/// assert!((0.0..=1.0).contains(&x));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct UniformFloatInclusive<X> {
+    low: X,
+    high: X,
+    scale: X,
+}
+
+impl<X> UniformFloatInclusive<X>
+where X: SampleUniform<Sampler = UniformFloat<X>> + PartialOrd + Copy + core::ops::Sub<Output = X>
+{
+    /// Construct, sampling from `[low, high]`.
+    ///
+    /// Returns an error under the same conditions as
+    /// [`Uniform::new_inclusive`].
+    pub fn new(low: X, high: X) -> Result<Self, Error> {
+        Uniform::<X>::new_inclusive(low, high)?;
+        Ok(UniformFloatInclusive {
+            low,
+            high,
+            scale: high - low,
+        })
+    }
+}
+
+macro_rules! uniform_float_inclusive_impl {
+    ($ty:ty, $uty:ident) => {
+        impl Distribution<$ty> for UniformFloatInclusive<$ty> {
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $ty {
+                let n: $uty = rng.gen();
+                if n == $uty::MAX {
+                    return self.high;
+                }
+                let t = n as $ty / $uty::MAX as $ty;
+                // Rounding may push the result beyond `high`
+                let x = t * self.scale + self.low;
+                if x > self.high { self.high } else { x }
+            }
+        }
+
+        impl TryFrom<RangeInclusive<$ty>> for UniformFloatInclusive<$ty> {
+            type Error = Error;
+
+            fn try_from(r: RangeInclusive<$ty>) -> Result<Self, Error> {
+                UniformFloatInclusive::new(*r.start(), *r.end())
+            }
+        }
+    };
+}
+
+uniform_float_inclusive_impl! { f32, u32 }
+uniform_float_inclusive_impl! { f64, u64 }
+
 
 /// The back-end implementing [`UniformSampler`] for `Duration`.
 ///
@@ -1271,6 +1375,62 @@ mod tests {
         assert_eq!(Uniform::new(10, 5), Err(Error::EmptyRange));
     }
 
+    #[test]
+    fn test_u128_full_width() {
+        // An RNG yielding `x` from `gen::<u128>()`
+        fn rng_for(x: u128) -> StepRng {
+            let (lo, hi) = (x as u64, (x >> 64) as u64);
+            StepRng::new(lo, hi.wrapping_sub(lo))
+        }
+
+        // The widening multiply is exact at the extremes
+        assert_eq!(u128::MAX.wmul(u128::MAX), (u128::MAX - 1, 1));
+        assert_eq!(u128::MAX.wmul(1), (0, u128::MAX));
+        let x = 0x0123_4567_89ab_cdef_fedc_ba98_7654_3210u128;
+        // x * (2^128 - 1) = (x - 1) * 2^128 + (2^128 - x)
+        assert_eq!(x.wmul(u128::MAX), (x - 1, x.wrapping_neg()));
+        assert_eq!((1u128 << 64).wmul(1 << 64), (1, 0));
+
+        // Ranges of width `u128::MAX`: only a sample of zero is rejected
+        for &(low, high) in &[(0, u128::MAX - 1), (1, u128::MAX)] {
+            let uniform = Uniform::new_inclusive(low, high).unwrap();
+            assert_eq!(uniform.0.range, u128::MAX);
+            assert_eq!(uniform.0.thresh, 1);
+            assert_eq!(uniform.sample(&mut rng_for(1)), low);
+            assert_eq!(uniform.sample(&mut rng_for(u128::MAX)), high);
+            assert_eq!(uniform.sample(&mut rng_for(1 << 127)), low + (1 << 127) - 1);
+
+            assert_eq!(rng_for(0).gen_range(low..=high), low);
+            assert_eq!(rng_for(u128::MAX).gen_range(low..=high), high);
+        }
+        assert_eq!(Uniform::new(0, u128::MAX).unwrap(), Uniform::new_inclusive(0, u128::MAX - 1).unwrap());
+        assert_eq!(rng_for(u128::MAX).gen_range(0..u128::MAX), u128::MAX - 1);
+
+        // The full range
+        let uniform = Uniform::new_inclusive(0, u128::MAX).unwrap();
+        assert_eq!(uniform.sample(&mut rng_for(0)), 0);
+        assert_eq!(uniform.sample(&mut rng_for(u128::MAX)), u128::MAX);
+        assert_eq!(rng_for(u128::MAX).gen_range(0..=u128::MAX), u128::MAX);
+
+        // Signed ranges of width `u128::MAX`
+        let uniform = Uniform::new_inclusive(i128::MIN + 1, i128::MAX).unwrap();
+        assert_eq!(uniform.sample(&mut rng_for(1)), i128::MIN + 1);
+        assert_eq!(uniform.sample(&mut rng_for(u128::MAX)), i128::MAX);
+        assert_eq!(rng_for(0).gen_range(i128::MIN..i128::MAX), i128::MIN);
+        assert_eq!(rng_for(u128::MAX).gen_range(i128::MIN..i128::MAX), i128::MAX - 1);
+
+        // A range just over half the full width rejects about half of all
+        // samples; accepted samples must still be uniform
+        let half = 1u128 << 127;
+        let uniform = Uniform::new_inclusive(0, half).unwrap();
+        assert_eq!(uniform.0.thresh, half - 1);
+        let mut rng = crate::test::rng(322);
+        let n = 10_000;
+        let upper = (0..n).filter(|_| uniform.sample(&mut rng) >= half / 2).count();
+        // Binomial(10000, 1/2) with standard deviation 50
+        assert!((4_800..5_200).contains(&upper), "{}", upper);
+    }
+
     #[test]
     #[cfg_attr(miri, ignore)] // Miri is too slow
     fn test_integers() {
@@ -1357,6 +1517,43 @@ mod tests {
         }
     }
 
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn test_single_inclusive_uniform() {
+        let mut rng = crate::test::rng(303);
+        macro_rules! t {
+            ($ty:ident, $low:expr) => {{
+                // 7 values, a range which does not divide any power of 2
+                let low: $ty = $low;
+                let mut counts = [0u32; 7];
+                for _ in 0..14_000 {
+                    let v = <$ty as SampleUniform>::Sampler::sample_single_inclusive(low, low + 6, &mut rng).unwrap();
+                    counts[(v - low) as usize] += 1;
+                }
+                assert!(counts.iter().all(|&c| c > 1800 && c < 2200), "{:?}", counts);
+
+                // Both endpoints are reachable in a two-value range at the
+                // limits of the type
+                let mut seen = [false; 2];
+                for _ in 0..100 {
+                    let v = <$ty as SampleUniform>::Sampler::sample_single_inclusive($ty::MAX - 1, $ty::MAX, &mut rng).unwrap();
+                    seen[(v == $ty::MAX) as usize] = true;
+                }
+                assert_eq!(seen, [true, true]);
+                assert_eq!(
+                    <$ty as SampleUniform>::Sampler::sample_single_inclusive($ty::MIN, $ty::MIN, &mut rng),
+                    Ok($ty::MIN)
+                );
+            }};
+        }
+        t!(u8, 3);
+        t!(i8, -3);
+        t!(u16, 1000);
+        t!(i32, -100_000);
+        t!(u64, u64::MAX - 10);
+        t!(i128, i128::MIN);
+    }
+
     #[test]
     #[cfg_attr(miri, ignore)] // Miri is too slow
     fn test_char() {
@@ -1378,6 +1575,46 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_char_endpoints_and_surrogates() {
+        let mut rng = crate::test::rng(297);
+
+        // Both endpoints of inclusive ranges are reachable
+        let mut seen = [false; 26];
+        for _ in 0..1000 {
+            let c = rng.gen_range('a'..='z');
+            seen[(c as u8 - b'a') as usize] = true;
+        }
+        assert_eq!(seen, [true; 26]);
+
+        // The two scalar values adjacent to the surrogate gap are equally
+        // likely, and nothing in between is produced
+        let (below, above) = ('\u{D7FF}', '\u{E000}');
+        let d = Uniform::new_inclusive(below, above).unwrap();
+        let mut count = 0;
+        for _ in 0..1000 {
+            let c = d.sample(&mut rng);
+            assert!(c == below || c == above);
+            count += (c == below) as u32;
+        }
+        assert!(count > 430 && count < 570);
+
+        // Exclusive ranges ending just after the gap
+        let d = Uniform::new('\u{D7FE}', above).unwrap();
+        for _ in 0..100 {
+            let c = d.sample(&mut rng);
+            assert!(c == '\u{D7FE}' || c == below);
+        }
+
+        // Sampling the full range maps around the gap
+        let d = Uniform::new_inclusive('\0', char::MAX).unwrap();
+        for _ in 0..10000 {
+            let c = d.sample(&mut rng) as u32;
+            assert!(!(0xD800..=0xDFFF).contains(&c));
+        }
+        assert_eq!(rng.gen_range(char::MAX..=char::MAX), char::MAX);
+    }
+
     #[test]
     #[cfg_attr(miri, ignore)] // Miri is too slow
     fn test_floats() {
@@ -1562,6 +1799,43 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_float_inclusive_endpoints() {
+        let mut zero_rng = StepRng::new(0, 0);
+        let mut max_rng = StepRng::new(u64::MAX, 0);
+        for &(low, high) in &[(0.0, 1.0), (-3.5, 1e10), (-f64::MAX / 2.0, f64::MAX / 4.0), (2.0, 2.0)] {
+            let dist = UniformFloatInclusive::new(low, high).unwrap();
+            assert_eq!(dist.sample(&mut zero_rng), low);
+            assert_eq!(dist.sample(&mut max_rng), high);
+        }
+        let dist = UniformFloatInclusive::<f32>::try_from(-1.0..=1.0).unwrap();
+        assert_eq!(dist.sample(&mut zero_rng), -1.0f32);
+        assert_eq!(dist.sample(&mut max_rng), 1.0f32);
+
+        // Five representable values: both endpoints are observed
+        let mut rng = crate::test::rng(300);
+        let high = 1.0 + 4.0 * f64::EPSILON;
+        let dist = UniformFloatInclusive::new(1.0, high).unwrap();
+        let (mut lows, mut highs) = (0, 0);
+        for _ in 0..1000 {
+            let x = dist.sample(&mut rng);
+            assert!((1.0..=high).contains(&x));
+            lows += (x == 1.0) as u32;
+            highs += (x == high) as u32;
+        }
+        assert!(lows > 80 && lows < 170, "{}", lows);
+        assert!(highs > 80 && highs < 170, "{}", highs);
+
+        let mut rng = crate::test::rng(301);
+        let dist = UniformFloatInclusive::new(0.0, 1.0).unwrap();
+        let mean = (0..10_000).map(|_| dist.sample(&mut rng)).sum::<f64>() / 10_000.0;
+        assert!((mean - 0.5).abs() < 0.01);
+
+        assert_eq!(UniformFloatInclusive::new(1.0, 0.0), Err(Error::EmptyRange));
+        assert!(UniformFloatInclusive::new(0.0, f64::NAN).is_err());
+        assert_eq!(UniformFloatInclusive::new(f32::MIN, f32::MAX), Err(Error::NonFinite));
+    }
+
 
     #[test]
     #[cfg_attr(miri, ignore)] // Miri is too slow
@@ -1585,6 +1859,40 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_durations_edge_cases() {
+        let mut rng = crate::test::rng(296);
+
+        // Zero-width ranges
+        let d = Duration::new(5, 123);
+        assert_eq!(Uniform::new(d, d), Err(Error::EmptyRange));
+        let single = Uniform::new_inclusive(d, d).unwrap();
+        for _ in 0..10 {
+            assert_eq!(rng.sample(single), d);
+        }
+        assert_eq!(rng.gen_range(d..=d), d);
+
+        // A range spanning a whole-second boundary: both sides are sampled
+        let (low, high) = (Duration::new(1, 999_999_990), Duration::new(2, 10));
+        let mut seen = [false; 2];
+        for _ in 0..1000 {
+            let v = rng.gen_range(low..high);
+            assert!(low <= v && v < high);
+            seen[v.as_secs() as usize - 1] = true;
+        }
+        assert_eq!(seen, [true; 2]);
+
+        // Ranges of several seconds plus nanoseconds
+        let (low, high) = (Duration::new(3, 700_000_000), Duration::new(7, 200_000_000));
+        let mut total = 0.0;
+        for _ in 0..1000 {
+            let v = rng.gen_range(low..=high);
+            assert!(low <= v && v <= high);
+            total += v.as_secs_f64();
+        }
+        assert!((total / 1000.0 - 5.45).abs() < 0.15);
+    }
+
     #[test]
     fn test_custom_uniform() {
         use crate::distributions::uniform::{
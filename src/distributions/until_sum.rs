@@ -0,0 +1,95 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Sampling until a cumulative sum is reached
+
+use crate::distributions::Distribution;
+use crate::Rng;
+use alloc::vec::Vec;
+
+/// Draw samples from `dist` until their sum exceeds `threshold`, e.g. for
+/// capacity-filling simulations.
+///
+/// Returns the samples drawn, in order; the last one is the first to bring the
+/// running sum above `threshold`. At most `max` samples are drawn: if the sum
+/// does not exceed `threshold` after `max` draws, all `max` samples are
+/// returned and the sum of the result is `<= threshold`. If `threshold < 0`,
+/// no samples are needed and the result is empty.
+///
+/// This is intended for distributions of positive values. Zero and negative
+/// samples are included and simply added to the running sum, thus the sum may
+/// never exceed `threshold`, in which case exactly `max` samples are drawn.
+/// The same applies if a sample is NaN.
+///
+/// # Example
+///
+/// ```
+/// use rand::distributions::{sample_until_sum, Uniform};
+///
+/// let dist = Uniform::new(0.5, 1.5).unwrap();
+/// let v = sample_until_sum(&mut rand::thread_rng(), &dist, 10.0, 100);
+/// assert!(v.iter().sum::<f64>() > 10.0);
+/// assert!(v.len() <= 21);
+/// ```
+#[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+pub fn sample_until_sum<R, D>(rng: &mut R, dist: &D, threshold: f64, max: usize) -> Vec<f64>
+where
+    R: Rng + ?Sized,
+    D: Distribution<f64> + ?Sized,
+{
+    let mut values = Vec::new();
+    let mut sum = 0.0;
+    while !(sum > threshold) && values.len() < max {
+        let x = dist.sample(rng);
+        sum += x;
+        values.push(x);
+    }
+    values
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::distributions::{Standard, Uniform};
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_exponential_count() {
+        // The number of Exp(1) samples needed to exceed t is 1 + Poisson(t)
+        let mut rng = crate::test::rng(283);
+        let exp = Standard.map(|u: f64| -(1.0 - u).ln());
+        let t = 10.0;
+        let n = 2000;
+        let mut counts = Vec::with_capacity(n);
+        for _ in 0..n {
+            let v = sample_until_sum(&mut rng, &exp, t, 1000);
+            let sum: f64 = v.iter().sum();
+            assert!(sum > t);
+            assert!(sum - v[v.len() - 1] <= t);
+            counts.push(v.len() as f64);
+        }
+        let mean = counts.iter().sum::<f64>() / n as f64;
+        let var = counts.iter().map(|c| (c - mean).powi(2)).sum::<f64>() / (n - 1) as f64;
+        assert!((mean - (t + 1.0)).abs() < 0.3, "mean {}", mean);
+        assert!((var - t).abs() < 1.5, "variance {}", var);
+    }
+
+    #[test]
+    fn test_edge_cases() {
+        let mut rng = crate::test::rng(284);
+        let dist = Uniform::new(1.0, 2.0).unwrap();
+        assert!(sample_until_sum(&mut rng, &dist, -1.0, 10).is_empty());
+        assert_eq!(sample_until_sum(&mut rng, &dist, 0.0, 10).len(), 1);
+        assert_eq!(sample_until_sum(&mut rng, &dist, 100.0, 10).len(), 10);
+        assert!(sample_until_sum(&mut rng, &dist, 100.0, 0).is_empty());
+
+        // Non-positive values never reach the threshold
+        let neg = Uniform::new(-1.0, 0.0).unwrap();
+        assert_eq!(sample_until_sum(&mut rng, &neg, 0.5, 25).len(), 25);
+    }
+}
@@ -91,8 +91,15 @@ impl<X: SampleUniform + PartialOrd> WeightedIndex<X> {
     /// in `weights`. The weights can use any type `X` for which an
     /// implementation of [`Uniform<X>`] exists.
     ///
-    /// Returns an error if the iterator is empty, if any weight is `< 0`, or
-    /// if its total value is 0.
+    /// `weights` is iterated once, computing the cumulative weights as it
+    /// goes; it need not be cloneable or collected first. See
+    /// [`WeightedIndex::from_iter_len`] to avoid reallocations when the
+    /// iterator does not know its length.
+    ///
+    /// Returns an error if the iterator is empty, if any weight is `< 0`, if
+    /// the total value is 0 or if it is not supported by [`Uniform<X>`]
+    /// (e.g. infinite). The total of integer weights must not overflow `X`:
+    /// as with ordinary integer arithmetic, this panics in debug builds.
     ///
     /// [`Uniform<X>`]: crate::distributions::uniform::Uniform
     pub fn new<I>(weights: I) -> Result<WeightedIndex<X>, WeightedError>
@@ -101,20 +108,63 @@ impl<X: SampleUniform + PartialOrd> WeightedIndex<X> {
         I::Item: SampleBorrow<X>,
         X: for<'a> ::core::ops::AddAssign<&'a X> + Clone + Default,
     {
-        let mut iter = weights.into_iter();
+        let iter = weights.into_iter();
+        let capacity = iter.size_hint().0;
+        Self::with_capacity(iter, capacity)
+    }
+
+    /// Creates a new `WeightedIndex` from `len` weights yielded by a
+    /// single-pass iterator.
+    ///
+    /// This is equivalent to [`WeightedIndex::new`], but allocates the
+    /// storage for the cumulative weights up front. This is useful where
+    /// `weights` is computed lazily (e.g. with [`Iterator::filter`]) and its
+    /// [`Iterator::size_hint`] is not exact. `len` is only used for
+    /// allocation: if the iterator yields a different number of weights, the
+    /// result is still correct.
+    ///
+    /// Errors are as for [`WeightedIndex::new`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::distributions::WeightedIndex;
+    ///
+    /// let scores = [3, -1, 4, 1, -5, 9];
+    /// let n = scores.iter().filter(|&&s| s > 0).count();
+    /// let dist = WeightedIndex::from_iter_len(scores.iter().filter(|&&s| s > 0).map(|&s| s as u32), n)
+    ///     .unwrap();
+    /// # let _ = dist;
+    /// ```
+    pub fn from_iter_len<I>(weights: I, len: usize) -> Result<WeightedIndex<X>, WeightedError>
+    where
+        I: IntoIterator,
+        I::Item: SampleBorrow<X>,
+        X: for<'a> ::core::ops::AddAssign<&'a X> + Clone + Default,
+    {
+        Self::with_capacity(weights.into_iter(), len)
+    }
+
+    fn with_capacity<I>(mut iter: I, capacity: usize) -> Result<WeightedIndex<X>, WeightedError>
+    where
+        I: Iterator,
+        I::Item: SampleBorrow<X>,
+        X: for<'a> ::core::ops::AddAssign<&'a X> + Clone + Default,
+    {
         let mut total_weight: X = iter.next().ok_or(WeightedError::NoItem)?.borrow().clone();
 
         let zero = <X as Default>::default();
         if !(total_weight >= zero) {
-            return Err(WeightedError::InvalidWeight);
+            return Err(WeightedError::InvalidWeight { index: 0 });
         }
 
-        let mut weights = Vec::<X>::with_capacity(iter.size_hint().0);
-        for w in iter {
+        // The first weight is not stored
+        let mut weights = Vec::<X>::with_capacity(capacity.saturating_sub(1));
+        for (index, w) in (1..).zip(iter) {
             // Note that `!(w >= x)` is not equivalent to `w < x` for partially
             // ordered types due to NaNs which are equal to nothing.
             if !(w.borrow() >= &zero) {
-                return Err(WeightedError::InvalidWeight);
+                return Err(WeightedError::InvalidWeight { index });
             }
             weights.push(total_weight.clone());
             total_weight += w.borrow();
@@ -123,7 +173,8 @@ impl<X: SampleUniform + PartialOrd> WeightedIndex<X> {
         if total_weight == zero {
             return Err(WeightedError::AllWeightsZero);
         }
-        let distr = X::Sampler::new(zero, total_weight.clone()).unwrap();
+        let distr = X::Sampler::new(zero, total_weight.clone())
+            .map_err(|_| WeightedError::Overflow)?;
 
         Ok(WeightedIndex {
             cumulative_weights: weights,
@@ -138,7 +189,8 @@ impl<X: SampleUniform + PartialOrd> WeightedIndex<X> {
     ///
     /// Using this method instead of `new` might be more efficient if only a small number of
     /// weights is modified. No allocations are performed, unless the weight type `X` uses
-    /// allocation internally.
+    /// allocation internally. The cumulative weights are recomputed from the smallest updated
+    /// index onwards, thus the cost is `O(n - i)` for `n` weights and smallest index `i`.
     ///
     /// In case of error, `self` is not modified.
     /// 
@@ -164,11 +216,11 @@ impl<X: SampleUniform + PartialOrd> WeightedIndex<X> {
         for &(i, w) in new_weights {
             if let Some(old_i) = prev_i {
                 if old_i >= i {
-                    return Err(WeightedError::InvalidWeight);
+                    return Err(WeightedError::InvalidWeight { index: i });
                 }
             }
             if !(*w >= zero) {
-                return Err(WeightedError::InvalidWeight);
+                return Err(WeightedError::InvalidWeight { index: i });
             }
             if i > self.cumulative_weights.len() {
                 return Err(WeightedError::TooMany);
@@ -236,9 +288,178 @@ where X: SampleUniform + PartialOrd
     }
 }
 
+impl WeightedIndex<f64> {
+    /// Creates a [`WeightedIndexExtended`] distribution, suitable for `f64`
+    /// weights spanning many orders of magnitude.
+    ///
+    /// With [`WeightedIndex::new`], a weight smaller than about `2^-53` times
+    /// the total weight is lost to rounding and that item can never be picked.
+    /// [`WeightedIndexExtended`] retains the correct (if minuscule) probability
+    /// of such items.
+    ///
+    /// Returns an error if the iterator is empty, if any weight is `< 0` or not
+    /// finite, if the total weight is not finite, or if all weights are zero.
+    pub fn new_extended<I>(weights: I) -> Result<WeightedIndexExtended, WeightedError>
+    where
+        I: IntoIterator,
+        I::Item: SampleBorrow<f64>,
+    {
+        WeightedIndexExtended::new(weights)
+    }
+}
+
+/// A weighted index distribution over `f64` weights which remains accurate
+/// for extreme weight ratios
+///
+/// This is constructed by [`WeightedIndex::new_extended`]. Items are ordered by
+/// increasing weight and the cumulative weights are summed smallest first, so
+/// that the boundaries of tiny items are small numbers, which floating-point
+/// represents precisely. Sampling then uses a uniform variate with full
+/// precision near zero (not limited to multiples of `2^-53`), so every item
+/// with non-zero weight has non-zero probability of being picked.
+///
+/// Construction costs `O(N log N)` time; sampling costs `O(log N)` time and
+/// usually consumes two `u64` values from the RNG.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+pub struct WeightedIndexExtended {
+    /// Original indices of items with non-zero weight, by increasing weight
+    order: Vec<usize>,
+    /// Inclusive cumulative weights, in the same order as `order`
+    cumulative_weights: Vec<f64>,
+    total_weight: f64,
+}
+
+impl WeightedIndexExtended {
+    fn new<I>(weights: I) -> Result<WeightedIndexExtended, WeightedError>
+    where
+        I: IntoIterator,
+        I::Item: SampleBorrow<f64>,
+    {
+        let mut items = Vec::new();
+        for (i, w) in weights.into_iter().enumerate() {
+            let w = *w.borrow();
+            if !(w >= 0.0 && w.is_finite()) {
+                return Err(WeightedError::InvalidWeight { index: i });
+            }
+            items.push((w, i));
+        }
+        if items.is_empty() {
+            return Err(WeightedError::NoItem);
+        }
+        items.retain(|&(w, _)| w > 0.0);
+        if items.is_empty() {
+            return Err(WeightedError::AllWeightsZero);
+        }
+        // No weight is NaN, thus `partial_cmp` never fails
+        items.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+
+        let mut total_weight = 0.0;
+        let mut cumulative_weights = Vec::with_capacity(items.len());
+        let mut order = Vec::with_capacity(items.len());
+        for (w, i) in items {
+            total_weight += w;
+            cumulative_weights.push(total_weight);
+            order.push(i);
+        }
+        if !total_weight.is_finite() {
+            return Err(WeightedError::Overflow);
+        }
+
+        Ok(WeightedIndexExtended {
+            order,
+            cumulative_weights,
+            total_weight,
+        })
+    }
+}
+
+impl Distribution<usize> for WeightedIndexExtended {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> usize {
+        let chosen_weight = sample_full_precision(rng) * self.total_weight;
+        // Find the first item whose cumulative weight is *higher* than the
+        // chosen weight. Rounding of the product may yield `total_weight`.
+        let k = self.cumulative_weights.partition_point(|w| *w <= chosen_weight);
+        self.order[k.min(self.order.len() - 1)]
+    }
+}
+
+/// Sample a value uniformly from `[0, 1)`, where small values retain full
+/// precision (all bits of the mantissa are random regardless of exponent).
+fn sample_full_precision<R: Rng + ?Sized>(rng: &mut R) -> f64 {
+    // The exponent follows a geometric distribution: the result lies in
+    // `[2^-(k+1), 2^-k)` with probability `2^-(k+1)`.
+    let mut exp = -1i32;
+    loop {
+        let bits = rng.next_u64();
+        if bits != 0 {
+            exp -= bits.leading_zeros() as i32;
+            break;
+        }
+        exp -= 64;
+        if exp < -1022 {
+            // Probability 2^-1022: not worth handling subnormals
+            return 0.0;
+        }
+    }
+    if exp < -1022 {
+        return 0.0;
+    }
+    let mantissa = rng.next_u64() >> 12;
+    f64::from_bits((((exp + 1023) as u64) << 52) | mantissa)
+}
+
+/// A distribution over arbitrary labels with `f64` weights
+///
+/// This pairs a [`WeightedIndex`] with the labels themselves, such that
+/// sampling returns a label (e.g. a negative integer) rather than its index.
+/// The chance of a label being picked is proportional to its weight; labels
+/// with zero weight are never picked.
+///
+/// # Example
+///
+/// ```
+/// use rand::distributions::{Distribution, WeightedLabels};
+///
+/// let dist = WeightedLabels::new(vec![(-5, 1.0), (0, 2.0), (3, 7.0)]).unwrap();
+/// let x: i32 = dist.sample(&mut rand::thread_rng());
+/// assert!([-5, 0, 3].contains(&x));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+pub struct WeightedLabels<T> {
+    labels: Vec<T>,
+    index: WeightedIndex<f64>,
+}
+
+impl<T> WeightedLabels<T> {
+    /// Creates a new `WeightedLabels` from `(label, weight)` pairs.
+    ///
+    /// Errors are as for [`WeightedIndex::new`].
+    pub fn new(pairs: Vec<(T, f64)>) -> Result<WeightedLabels<T>, WeightedError> {
+        let index = WeightedIndex::new(pairs.iter().map(|p| p.1))?;
+        let labels = pairs.into_iter().map(|p| p.0).collect();
+        Ok(WeightedLabels { labels, index })
+    }
+
+    /// The labels, in the order given on construction
+    pub fn labels(&self) -> &[T] {
+        &self.labels
+    }
+}
+
+impl<T: Copy> Distribution<T> for WeightedLabels<T> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> T {
+        self.labels[self.index.sample(rng)]
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
+    use alloc::vec;
 
     #[cfg(feature = "serde1")]
     #[test]
@@ -260,15 +481,15 @@ mod test {
     fn test_accepting_nan(){
         assert_eq!(
             WeightedIndex::new(&[core::f32::NAN, 0.5]).unwrap_err(),
-            WeightedError::InvalidWeight,
+            WeightedError::InvalidWeight { index: 0 },
         );
         assert_eq!(
             WeightedIndex::new(&[core::f32::NAN]).unwrap_err(),
-            WeightedError::InvalidWeight,
+            WeightedError::InvalidWeight { index: 0 },
         );
         assert_eq!(
             WeightedIndex::new(&[0.5, core::f32::NAN]).unwrap_err(),
-            WeightedError::InvalidWeight,
+            WeightedError::InvalidWeight { index: 1 },
         );
 
         assert_eq!(
@@ -276,7 +497,7 @@ mod test {
                 .unwrap()
                 .update_weights(&[(0, &core::f32::NAN)])
                 .unwrap_err(),
-            WeightedError::InvalidWeight,
+            WeightedError::InvalidWeight { index: 0 },
         )
     }
 
@@ -345,15 +566,15 @@ mod test {
         );
         assert_eq!(
             WeightedIndex::new(&[10, 20, -1, 30]).unwrap_err(),
-            WeightedError::InvalidWeight
+            WeightedError::InvalidWeight { index: 2 }
         );
         assert_eq!(
             WeightedIndex::new(&[-10, 20, 1, 30]).unwrap_err(),
-            WeightedError::InvalidWeight
+            WeightedError::InvalidWeight { index: 0 }
         );
         assert_eq!(
             WeightedIndex::new(&[-10]).unwrap_err(),
-            WeightedError::InvalidWeight
+            WeightedError::InvalidWeight { index: 0 }
         );
     }
 
@@ -386,6 +607,61 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_update_weights_sampling() {
+        let weights = [1.0f64, 4.0, 0.0, 2.5, 7.0, 3.0];
+        let mut distr = WeightedIndex::new(weights).unwrap();
+        distr.update_weights(&[(0, &0.0), (2, &6.0), (5, &0.5)]).unwrap();
+        let expected = WeightedIndex::new([0.0f64, 4.0, 6.0, 2.5, 7.0, 0.5]).unwrap();
+
+        let mut r1 = crate::test::rng(294);
+        let mut r2 = crate::test::rng(294);
+        for _ in 0..1000 {
+            assert_eq!(distr.sample(&mut r1), expected.sample(&mut r2));
+        }
+
+        // Invalid updates leave the distribution unchanged
+        let before = distr.clone();
+        assert_eq!(distr.update_weights(&[(6, &1.0)]), Err(WeightedError::TooMany));
+        assert_eq!(distr.update_weights(&[(1, &-1.0)]), Err(WeightedError::InvalidWeight { index: 1 }));
+        assert_eq!(distr.update_weights(&[(2, &1.0), (1, &1.0)]), Err(WeightedError::InvalidWeight { index: 1 }));
+        assert_eq!(distr, before);
+    }
+
+    #[test]
+    fn test_from_iter_len() {
+        let weights = [2u32, 0, 5, 1, 9, 3];
+        let from_slice = WeightedIndex::new(weights.iter()).unwrap();
+        // A single-pass iterator with an inexact size hint
+        let lazy = (0..12).filter(|i| i % 2 == 0).map(|i| weights[i / 2]);
+        let from_iter = WeightedIndex::from_iter_len(lazy, weights.len()).unwrap();
+        assert_eq!(from_iter, from_slice);
+        assert_eq!(from_iter.cumulative_weights.capacity(), weights.len() - 1);
+        let lazy = (0..12).filter(|i| i % 2 == 0).map(|i| weights[i / 2]);
+        assert_eq!(WeightedIndex::new(lazy).unwrap(), from_slice);
+
+        let mut r1 = crate::test::rng(310);
+        let mut r2 = crate::test::rng(310);
+        for _ in 0..1000 {
+            assert_eq!(from_iter.sample(&mut r1), from_slice.sample(&mut r2));
+        }
+
+        // `len` is only a capacity
+        assert_eq!(WeightedIndex::from_iter_len(weights.iter(), 0).unwrap(), from_slice);
+        assert_eq!(WeightedIndex::from_iter_len(weights.iter(), 100).unwrap(), from_slice);
+
+        let empty: [f64; 0] = [];
+        assert_eq!(WeightedIndex::from_iter_len(empty, 3).unwrap_err(), WeightedError::NoItem);
+        assert_eq!(
+            WeightedIndex::from_iter_len([1.0, -1.0].iter(), 2).unwrap_err(),
+            WeightedError::InvalidWeight { index: 1 }
+        );
+        assert_eq!(
+            WeightedIndex::from_iter_len([0u8, 0].iter(), 2).unwrap_err(),
+            WeightedError::AllWeightsZero
+        );
+    }
+
     #[test]
     fn value_stability() {
         fn test_samples<X: SampleUniform + PartialOrd, I>(
@@ -416,10 +692,147 @@ mod test {
         ]);
     }
 
+    #[test]
+    fn test_weighted_index_extended() {
+        let mut r = crate::test::rng(702);
+
+        // Weights spanning 1e-100 to 1e100
+        let weights: Vec<f64> = (-100..=100).map(|e| 10f64.powi(e)).rev().collect();
+        let distr = WeightedIndex::new_extended(weights.iter()).unwrap();
+        assert!((distr.total_weight - weights.iter().sum::<f64>()).abs() <= 1e87);
+
+        // The tiny weight, last in the input, is smallest after sorting and
+        // is thus selected by the smallest possible sample ...
+        let mut zero = crate::rngs::mock::StepRng::new(0, 0);
+        assert_eq!(distr.sample(&mut zero), 200);
+        // ... which `WeightedIndex` cannot do: its boundary is lost to rounding.
+        let plain = WeightedIndex::new(weights.iter()).unwrap();
+        assert_eq!(plain.sample(&mut zero), 0);
+        // The boundary of the tiny weight is represented exactly.
+        assert_eq!(distr.cumulative_weights[0], weights[200]);
+
+        // Every item has a non-empty range of samples
+        for k in 1..distr.cumulative_weights.len() {
+            assert!(distr.cumulative_weights[k] > distr.cumulative_weights[k - 1]);
+        }
+
+        // Sample frequencies of the largest items
+        let mut chosen = [0u32; 3];
+        for _ in 0..10000 {
+            let i = distr.sample(&mut r);
+            if i < 3 {
+                chosen[i] += 1;
+            }
+        }
+        let expected = [0.9 * 10000.0, 0.09 * 10000.0, 0.009 * 10000.0];
+        for (c, e) in chosen.iter().zip(expected.iter()) {
+            assert!((*c as f64 - e).abs() < 0.2 * e);
+        }
+
+        // A sample near the median of the full precision sampler
+        let mut mid = crate::rngs::mock::StepRng::new(1 << 63, 0);
+        assert_eq!(sample_full_precision(&mut mid), 0.75);
+    }
+
+    #[test]
+    fn test_weighted_index_extended_tiny() {
+        // The cumulative weights are summed smallest first, so each item's
+        // share of the range matches its weight to within rounding
+        let weights: Vec<f64> = (0..=300).map(|e| 2f64.powi(-e)).collect();
+        let distr = WeightedIndex::new_extended(&weights).unwrap();
+        let mut previous = 0.0;
+        for (&i, &cumulative) in distr.order.iter().zip(&distr.cumulative_weights) {
+            let width = cumulative - previous;
+            assert!((width - weights[i]).abs() <= 1e-15 * weights[i], "item {}", i);
+            previous = cumulative;
+        }
+
+        // A tiny weight, among larger ones, is drawn at the expected rate
+        let mut r = crate::test::rng(278);
+        let distr = WeightedIndex::new_extended([1.0, 1.0, 2e-4, 1.0, 1.0]).unwrap();
+        let n = 1_000_000;
+        let count = (0..n).filter(|_| distr.sample(&mut r) == 2).count();
+        // Expect 50 with a standard deviation of about 7
+        assert!((30..=70).contains(&count), "{}", count);
+    }
+
+    #[test]
+    fn test_weighted_labels() {
+        let mut r = crate::test::rng(290);
+        let dist = WeightedLabels::new(vec![(-5i32, 1.0), (0, 0.0), (3, 3.0)]).unwrap();
+        assert_eq!(dist.labels(), &[-5, 0, 3]);
+
+        let mut counts = [0u32; 2];
+        for _ in 0..8000 {
+            match dist.sample(&mut r) {
+                -5 => counts[0] += 1,
+                3 => counts[1] += 1,
+                x => panic!("unexpected label {}", x),
+            }
+        }
+        assert!(counts[0] > 1800 && counts[0] < 2200);
+        assert_eq!(counts[0] + counts[1], 8000);
+
+        assert_eq!(WeightedLabels::<char>::new(vec![]).unwrap_err(), WeightedError::NoItem);
+        assert_eq!(
+            WeightedLabels::new(vec![('a', 1.0), ('b', -1.0)]).unwrap_err(),
+            WeightedError::InvalidWeight { index: 1 }
+        );
+    }
+
+    #[test]
+    fn test_weighted_index_extended_errors() {
+        let empty: [f64; 0] = [];
+        assert_eq!(WeightedIndex::new_extended(empty).unwrap_err(), WeightedError::NoItem);
+        assert_eq!(
+            WeightedIndex::new_extended([0.0, 0.0]).unwrap_err(),
+            WeightedError::AllWeightsZero
+        );
+        for w in [-1.0, f64::NAN, f64::INFINITY] {
+            assert_eq!(
+                WeightedIndex::new_extended([1.0, w]).unwrap_err(),
+                WeightedError::InvalidWeight { index: 1 }
+            );
+        }
+        assert_eq!(
+            WeightedIndex::new_extended([f64::MAX, f64::MAX]).unwrap_err(),
+            WeightedError::Overflow
+        );
+
+        let mut r = crate::test::rng(703);
+        let distr = WeightedIndex::new_extended([0.0, 3.0, 0.0]).unwrap();
+        for _ in 0..10 {
+            assert_eq!(distr.sample(&mut r), 1);
+        }
+    }
+
     #[test]
     fn weighted_index_distributions_can_be_compared() {
         assert_eq!(WeightedIndex::new(&[1, 2]), WeightedIndex::new(&[1, 2]));
     }
+
+    #[test]
+    fn test_error_index() {
+        let err = WeightedIndex::new([1.0, 2.0, 0.0, -4.0, 5.0, -6.0]).unwrap_err();
+        assert_eq!(err, WeightedError::InvalidWeight { index: 3 });
+        assert_eq!(
+            alloc::format!("{}", err),
+            "The weight at index 3 is invalid in distribution"
+        );
+        assert_eq!(
+            WeightedIndex::new_extended([1.0, 2.0, 0.0, -4.0]).unwrap_err(),
+            WeightedError::InvalidWeight { index: 3 }
+        );
+
+        assert_eq!(
+            WeightedIndex::new([1.0, f64::INFINITY]).unwrap_err(),
+            WeightedError::Overflow
+        );
+        assert_eq!(
+            WeightedIndex::new([f64::MAX, f64::MAX]).unwrap_err(),
+            WeightedError::Overflow
+        );
+    }
 }
 
 /// Error type returned from `WeightedIndex::new`.
@@ -429,15 +842,21 @@ pub enum WeightedError {
     /// The provided weight collection contains no items.
     NoItem,
 
-    /// A weight is either less than zero, greater than the supported maximum,
-    /// NaN, or otherwise invalid.
-    InvalidWeight,
+    /// The weight at `index` is either less than zero, greater than the
+    /// supported maximum, NaN, or otherwise invalid.
+    InvalidWeight {
+        /// The position of the weight in the collection
+        index: usize,
+    },
 
     /// All items in the provided weight collection are zero.
     AllWeightsZero,
 
     /// Too many weights are provided (length greater than `u32::MAX`)
     TooMany,
+
+    /// The sum of the weights is too large, e.g. infinite.
+    Overflow,
 }
 
 #[cfg(feature = "std")]
@@ -447,9 +866,12 @@ impl fmt::Display for WeightedError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         f.write_str(match *self {
             WeightedError::NoItem => "No weights provided in distribution",
-            WeightedError::InvalidWeight => "A weight is invalid in distribution",
+            WeightedError::InvalidWeight { index } => {
+                return write!(f, "The weight at index {} is invalid in distribution", index);
+            }
             WeightedError::AllWeightsZero => "All weights are zero in distribution",
             WeightedError::TooMany => "Too many weights (hit u32::MAX) in distribution",
+            WeightedError::Overflow => "The sum of weights overflows in distribution",
         })
     }
 }
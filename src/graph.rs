@@ -0,0 +1,164 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Random graphs
+//!
+//! Graphs are represented as lists of undirected edges `(a, b)` between
+//! nodes numbered `0..n`.
+
+use crate::seq::SliceRandom;
+use crate::Rng;
+use alloc::vec::Vec;
+use core::fmt;
+
+/// Maximum number of attempts to generate a simple graph
+const MAX_SIMPLE_ATTEMPTS: usize = 1000;
+
+/// Error type returned from [`configuration_model`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GraphError {
+    /// The sum of degrees is odd, thus half-edges cannot be paired.
+    OddDegreeSum,
+    /// No simple graph was found within a bounded number of attempts. The
+    /// degree sequence may not be graphical, or simple graphs may be rare.
+    NoSimpleGraph,
+}
+
+impl fmt::Display for GraphError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            GraphError::OddDegreeSum => "sum of degrees is odd",
+            GraphError::NoSimpleGraph => "failed to generate a simple graph",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl ::std::error::Error for GraphError {}
+
+/// Sample a random graph with the given degree sequence using the
+/// configuration model
+///
+/// Node `i` has `degrees[i]` half-edges ("stubs"); all stubs are paired
+/// uniformly at random to form edges, such that every node has exactly the
+/// requested degree (a self-loop counts twice towards its node's degree).
+///
+/// If `simple` is false, the result may contain self-loops `(i, i)` and
+/// multiple edges between the same pair of nodes. If `simple` is true, graphs
+/// containing either are rejected and sampling is repeated; the result is
+/// then uniformly distributed over simple graphs with the given degrees.
+/// Since rejection may be slow (or impossible, if no such graph exists), this
+/// fails with [`GraphError::NoSimpleGraph`] after a bounded number of
+/// attempts.
+///
+/// Edges are returned in arbitrary order; each edge `(a, b)` has `a <= b`.
+///
+/// # Example
+///
+/// ```
+/// let degrees = [2, 2, 2, 2];
+/// let edges = rand::graph::configuration_model(&mut rand::thread_rng(), &degrees, true).unwrap();
+/// assert_eq!(edges.len(), 4);
+/// ```
+pub fn configuration_model<R: Rng + ?Sized>(
+    rng: &mut R, degrees: &[usize], simple: bool,
+) -> Result<Vec<(usize, usize)>, GraphError> {
+    let total: usize = degrees.iter().sum();
+    if total % 2 != 0 {
+        return Err(GraphError::OddDegreeSum);
+    }
+
+    let mut stubs = Vec::with_capacity(total);
+    for (node, &d) in degrees.iter().enumerate() {
+        stubs.extend(core::iter::repeat(node).take(d));
+    }
+
+    let mut edges = Vec::with_capacity(total / 2);
+    let attempts = if simple { MAX_SIMPLE_ATTEMPTS } else { 1 };
+    for _ in 0..attempts {
+        stubs.shuffle(rng);
+        edges.clear();
+        edges.extend(stubs.chunks_exact(2).map(|pair| {
+            let (a, b) = (pair[0], pair[1]);
+            if a <= b { (a, b) } else { (b, a) }
+        }));
+        if !simple || is_simple(&mut edges) {
+            return Ok(edges);
+        }
+    }
+    Err(GraphError::NoSimpleGraph)
+}
+
+// Check for self-loops and multi-edges. Sorts `edges`.
+fn is_simple(edges: &mut [(usize, usize)]) -> bool {
+    edges.sort_unstable();
+    edges.iter().all(|&(a, b)| a != b) && edges.windows(2).all(|w| w[0] != w[1])
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::vec;
+
+    fn degrees_of(n: usize, edges: &[(usize, usize)]) -> Vec<usize> {
+        let mut degrees = vec![0; n];
+        for &(a, b) in edges {
+            degrees[a] += 1;
+            degrees[b] += 1;
+        }
+        degrees
+    }
+
+    #[test]
+    fn test_configuration_model_degrees() {
+        let mut rng = crate::test::rng(285);
+        for &(degrees, simple) in &[
+            (&[3, 1, 4, 1, 5, 2, 6, 0, 2][..], false),
+            (&[2, 1, 2, 1, 3, 2, 3, 0, 2][..], true),
+        ] {
+            for _ in 0..20 {
+                let edges = configuration_model(&mut rng, degrees, simple).unwrap();
+                assert_eq!(edges.len(), degrees.iter().sum::<usize>() / 2);
+                assert_eq!(degrees_of(degrees.len(), &edges), degrees);
+                for &(a, b) in &edges {
+                    assert!(a <= b);
+                }
+                if simple {
+                    let mut edges = edges;
+                    assert!(is_simple(&mut edges));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_configuration_model_multi() {
+        // Two nodes of degree 2: either a double edge or two self-loops
+        let mut rng = crate::test::rng(286);
+        let mut loops = 0;
+        for _ in 0..300 {
+            let mut edges = configuration_model(&mut rng, &[2, 2], false).unwrap();
+            edges.sort_unstable();
+            if edges == [(0, 0), (1, 1)] {
+                loops += 1;
+            } else {
+                assert_eq!(edges, [(0, 1), (0, 1)]);
+            }
+        }
+        // Probability of self-loops is 1/3
+        assert!(loops > 70 && loops < 130);
+    }
+
+    #[test]
+    fn test_configuration_model_errors() {
+        let mut rng = crate::test::rng(287);
+        assert_eq!(configuration_model(&mut rng, &[1, 2], false), Err(GraphError::OddDegreeSum));
+        assert_eq!(configuration_model(&mut rng, &[2, 2], true), Err(GraphError::NoSimpleGraph));
+        assert_eq!(configuration_model(&mut rng, &[], true), Ok(Vec::new()));
+    }
+}
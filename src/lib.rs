@@ -95,10 +95,21 @@ pub use rand_core::{CryptoRng, Error, RngCore, SeedableRng};
 
 // Public modules
 pub mod distributions;
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+pub mod graph;
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+pub mod maze;
+pub mod nt;
 pub mod prelude;
 mod rng;
 pub mod rngs;
 pub mod seq;
+pub mod test_support;
+#[cfg(feature = "test_vectors")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "test_vectors")))]
+pub mod test_vectors;
 
 // Public exports
 #[cfg(all(feature = "std", feature = "std_rng"))]
@@ -0,0 +1,160 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Random mazes
+//!
+//! Mazes are grids of cells indexed as `maze[y][x]`, where `true` is an open
+//! cell and `false` is a wall. Movement is between horizontally or vertically
+//! adjacent open cells.
+
+use crate::Rng;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Generate a random perfect maze of `width` by `height` cells
+///
+/// Cells at even coordinates `(x, y)` are "rooms"; the cells between two
+/// adjacent rooms are opened or left as walls, and all cells at two odd
+/// coordinates are walls. The maze is *perfect*: there is exactly one path
+/// between any two rooms, including the corners `(0, 0)` and
+/// `(width - 1, height - 1)`.
+///
+/// The maze is a spanning tree of the rooms, generated by a randomized
+/// depth-first search, which tends to produce long, winding corridors. Time
+/// and space are `O(width * height)`.
+///
+/// # Panics
+///
+/// Panics if `width` or `height` is even.
+///
+/// # Example
+///
+/// ```
+/// let maze = rand::maze::generate(&mut rand::thread_rng(), 21, 11);
+/// for row in &maze {
+///     let line: String = row.iter().map(|&open| if open { ' ' } else { '#' }).collect();
+///     println!("{}", line);
+/// }
+/// ```
+pub fn generate<R: Rng + ?Sized>(rng: &mut R, width: usize, height: usize) -> Vec<Vec<bool>> {
+    assert!(
+        width % 2 == 1 && height % 2 == 1,
+        "maze::generate: width and height must be odd"
+    );
+    let mut maze = vec![vec![false; width]; height];
+    maze[0][0] = true;
+
+    // Rooms are open exactly when visited
+    let mut stack = vec![(0, 0)];
+    while let Some(&(x, y)) = stack.last() {
+        let mut neighbours = [(0, 0); 4];
+        let mut n = 0;
+        if x >= 2 && !maze[y][x - 2] {
+            neighbours[n] = (x - 2, y);
+            n += 1;
+        }
+        if x + 2 < width && !maze[y][x + 2] {
+            neighbours[n] = (x + 2, y);
+            n += 1;
+        }
+        if y >= 2 && !maze[y - 2][x] {
+            neighbours[n] = (x, y - 2);
+            n += 1;
+        }
+        if y + 2 < height && !maze[y + 2][x] {
+            neighbours[n] = (x, y + 2);
+            n += 1;
+        }
+
+        if n == 0 {
+            stack.pop();
+            continue;
+        }
+        let (nx, ny) = neighbours[rng.gen_range(0..n)];
+        maze[(y + ny) / 2][(x + nx) / 2] = true;
+        maze[ny][nx] = true;
+        stack.push((nx, ny));
+    }
+    maze
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Number of open cells reachable from `(0, 0)`, and whether the opposite
+    /// corner is among them
+    fn reachable(maze: &[Vec<bool>]) -> (usize, bool) {
+        let (width, height) = (maze[0].len(), maze.len());
+        let mut seen = vec![vec![false; width]; height];
+        seen[0][0] = true;
+        let mut stack = vec![(0, 0)];
+        let mut count = 0;
+        while let Some((x, y)) = stack.pop() {
+            count += 1;
+            let mut visit = |x: usize, y: usize| {
+                if maze[y][x] && !seen[y][x] {
+                    seen[y][x] = true;
+                    stack.push((x, y));
+                }
+            };
+            if x > 0 {
+                visit(x - 1, y);
+            }
+            if x + 1 < width {
+                visit(x + 1, y);
+            }
+            if y > 0 {
+                visit(x, y - 1);
+            }
+            if y + 1 < height {
+                visit(x, y + 1);
+            }
+        }
+        (count, seen[height - 1][width - 1])
+    }
+
+    #[test]
+    fn test_maze_perfect() {
+        let mut rng = crate::test::rng(299);
+        for &(width, height) in &[(1, 1), (1, 9), (7, 1), (21, 11), (15, 15)] {
+            let maze = generate(&mut rng, width, height);
+            assert_eq!(maze.len(), height);
+            assert!(maze.iter().all(|row| row.len() == width));
+
+            // A spanning tree over all rooms has `rooms - 1` passages
+            let rooms = ((width + 1) / 2) * ((height + 1) / 2);
+            let open = maze.iter().flatten().filter(|&&c| c).count();
+            assert_eq!(open, 2 * rooms - 1);
+            assert_eq!(reachable(&maze), (open, true));
+
+            for (y, row) in maze.iter().enumerate() {
+                for (x, &cell) in row.iter().enumerate() {
+                    if x % 2 == 0 && y % 2 == 0 {
+                        assert!(cell);
+                    } else if x % 2 == 1 && y % 2 == 1 {
+                        assert!(!cell);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_maze_varies() {
+        let mut rng = crate::test::rng(300);
+        let first = generate(&mut rng, 9, 9);
+        assert!((0..10).any(|_| generate(&mut rng, 9, 9) != first));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_maze_even_width() {
+        generate(&mut crate::test::rng(301), 8, 9);
+    }
+}
@@ -0,0 +1,170 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Random numbers for number theory
+//!
+//! These functions are intended for testing (e.g. of modular arithmetic), not
+//! for cryptography.
+
+use crate::Rng;
+
+/// The number of Miller–Rabin rounds used by [`random_prime`]
+///
+/// Each round lets a composite number pass with probability at most `1/4`,
+/// so a composite is returned with probability at most `4^-MILLER_RABIN_ROUNDS`.
+pub const MILLER_RABIN_ROUNDS: u32 = 24;
+
+/// Sample a random probable prime of exactly `bits` bits
+///
+/// Returns a number in `[2^(bits - 1), 2^bits)` which passes
+/// [`MILLER_RABIN_ROUNDS`] rounds of the Miller–Rabin test with random bases.
+/// Odd candidates are sampled uniformly until one passes, so every prime in
+/// the range is equally likely. For `bits == 2`, this is either 2 or 3.
+///
+/// The result is probabilistic: a composite number is returned with
+/// probability at most 2<sup>-48</sup> (far less in practice). The expected
+/// number of candidates is about `0.35 * bits`.
+///
+/// # Panics
+///
+/// Panics if `bits < 2` or `bits > 63`.
+///
+/// # Example
+///
+/// ```
+/// let p = rand::nt::random_prime(&mut rand::thread_rng(), 32);
+/// assert!(p >= 1 << 31 && p < 1 << 32);
+/// assert!(p % 2 == 1);
+/// ```
+pub fn random_prime<R: Rng + ?Sized>(rng: &mut R, bits: u32) -> u64 {
+    assert!(
+        (2..=63).contains(&bits),
+        "random_prime: bits must be in the range 2..=63"
+    );
+    if bits == 2 {
+        // The only even prime; all other candidates are odd
+        return if rng.gen() { 2 } else { 3 };
+    }
+    let (low, high) = (1u64 << (bits - 1), 1u64 << bits);
+    loop {
+        // `high` is even, so setting the low bit stays in range
+        let candidate = rng.gen_range(low..high) | 1;
+        if is_probable_prime(rng, candidate) {
+            return candidate;
+        }
+    }
+}
+
+/// Miller–Rabin test of odd `n >= 3` with random bases
+fn is_probable_prime<R: Rng + ?Sized>(rng: &mut R, n: u64) -> bool {
+    if n == 3 {
+        return true;
+    }
+    if SMALL_PRIMES.iter().any(|&p| n % p == 0) {
+        return SMALL_PRIMES.contains(&n);
+    }
+    (0..MILLER_RABIN_ROUNDS).all(|_| passes_round(n, rng.gen_range(2..n - 1)))
+}
+
+/// Primes used for trial division, to cheaply reject most candidates
+const SMALL_PRIMES: [u64; 11] = [3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+/// One round of Miller–Rabin: is odd `n` a strong probable prime to `base`?
+fn passes_round(n: u64, base: u64) -> bool {
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+    let mut x = pow_mod(base, d, n);
+    if x == 1 || x == n - 1 {
+        return true;
+    }
+    for _ in 1..s {
+        x = mul_mod(x, x, n);
+        if x == n - 1 {
+            return true;
+        }
+    }
+    false
+}
+
+fn mul_mod(a: u64, b: u64, m: u64) -> u64 {
+    (u128::from(a) * u128::from(b) % u128::from(m)) as u64
+}
+
+fn pow_mod(mut base: u64, mut exp: u64, m: u64) -> u64 {
+    let mut result = 1;
+    base %= m;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul_mod(result, base, m);
+        }
+        base = mul_mod(base, base, m);
+        exp >>= 1;
+    }
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Deterministic primality test: Miller–Rabin with the first 12 primes as
+    /// bases is exact for all `n < 3.3 * 10^24`
+    fn is_prime(n: u64) -> bool {
+        const BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+        if n < 2 {
+            return false;
+        }
+        if let Some(&p) = BASES.iter().find(|&&p| n % p == 0) {
+            return n == p;
+        }
+        BASES.iter().all(|&b| passes_round(n, b))
+    }
+
+    #[test]
+    fn test_is_prime() {
+        let trial = |n: u64| n >= 2 && (2..n).take_while(|d| d * d <= n).all(|d| n % d != 0);
+        for n in 0..5000 {
+            assert_eq!(is_prime(n), trial(n), "{}", n);
+        }
+        // Largest prime below 2^63, and strong pseudoprimes to several bases
+        assert!(is_prime(9223372036854775783));
+        assert!(!is_prime(3215031751));
+        assert!(!is_prime(3825123056546413051));
+    }
+
+    #[test]
+    fn test_random_prime() {
+        let mut rng = crate::test::rng(304);
+        for bits in 2..=63 {
+            for _ in 0..5 {
+                let p = random_prime(&mut rng, bits);
+                assert!(p >> (bits - 1) == 1, "{} has not {} bits", p, bits);
+                assert!(is_prime(p), "{} is not prime", p);
+            }
+        }
+        // Both 2 and 3 have two bits
+        let twos = (0..100).filter(|_| random_prime(&mut rng, 2) == 2).count();
+        assert!(twos > 30 && twos < 70, "{}", twos);
+        assert!([5, 7].contains(&random_prime(&mut rng, 3)));
+    }
+
+    #[test]
+    fn test_probable_prime_rejects_pseudoprimes() {
+        let mut rng = crate::test::rng(305);
+        // Carmichael numbers and strong pseudoprimes to base 2
+        for &n in &[561, 41041, 2047, 3215031751, 3825123056546413051] {
+            assert!(!is_probable_prime(&mut rng, n), "{}", n);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_random_prime_too_many_bits() {
+        random_prime(&mut crate::test::rng(306), 64);
+    }
+}
@@ -12,6 +12,8 @@
 use rand_core::{Error, RngCore};
 use crate::distributions::uniform::{SampleRange, SampleUniform};
 use crate::distributions::{self, Distribution, Standard};
+use crate::rngs::splitmix::SplitMix64;
+use core::convert::TryFrom;
 use core::num::Wrapping;
 use core::{mem, slice};
 
@@ -93,6 +95,44 @@ pub trait Rng: RngCore {
         Standard.sample(self)
     }
 
+    /// Return a random value supporting the [`Standard`] distribution,
+    /// forwarding errors of the RNG.
+    ///
+    /// This is like [`gen`], except that all random data is drawn via
+    /// [`RngCore::try_fill_bytes`]: if that fails, the (first) error is
+    /// returned instead of panicking. This is useful with generators which
+    /// may fail, such as [`OsRng`] or a [`ReseedingRng`] with a fallible
+    /// reseeder.
+    ///
+    /// For the generators of this crate, the value is the same as that
+    /// returned by [`gen`] given the same RNG state.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rand::Error;
+    /// use rand::{thread_rng, Rng};
+    ///
+    /// # fn try_inner() -> Result<(), Error> {
+    /// let x: (u32, f64) = thread_rng().try_gen()?;
+    /// println!("{:?}", x);
+    /// # Ok(())
+    /// # }
+    ///
+    /// # try_inner().unwrap()
+    /// ```
+    ///
+    /// [`Standard`]: distributions::Standard
+    /// [`gen`]: Rng::gen
+    /// [`OsRng`]: crate::rngs::OsRng
+    /// [`ReseedingRng`]: crate::rngs::adapter::ReseedingRng
+    fn try_gen<T>(&mut self) -> Result<T, Error>
+    where Standard: Distribution<T> {
+        let mut rng = TryRng::new(self);
+        let value = Standard.sample(&mut rng);
+        rng.finish().map(|()| value)
+    }
+
     /// Generate a random value in the given range.
     ///
     /// This function is optimised for the case that only a single sample is
@@ -133,27 +173,62 @@ pub trait Rng: RngCore {
         range.sample_single(self).unwrap()
     }
 
+    /// Generate an array of `N` independent values in the given range.
+    ///
+    /// This is equivalent to sampling `N` times from
+    /// [`Uniform::try_from(range)`](distributions::uniform::Uniform), hence
+    /// the sampler is set up only once. For `N > 1` this is usually faster than
+    /// `N` calls to [`Rng::gen_range`], but produces different values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is empty, or, for floating-point types, if it is
+    /// not finite.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::{thread_rng, Rng};
+    ///
+    /// let mut rng = thread_rng();
+    /// let [x, y, z]: [u32; 3] = rng.gen_range_array(0..64);
+    /// println!("{} {} {}", x, y, z);
+    /// ```
+    fn gen_range_array<T, R, const N: usize>(&mut self, range: R) -> [T; N]
+    where
+        T: SampleUniform,
+        R: SampleRange<T>,
+        distributions::Uniform<T>: TryFrom<R, Error = distributions::uniform::Error>,
+    {
+        assert!(!range.is_empty(), "cannot sample empty range");
+        let uniform = distributions::Uniform::try_from(range).unwrap();
+        [(); N].map(|()| uniform.sample(self))
+    }
+
     /// Generate values via an iterator
     ///
-    /// This is a just a wrapper over [`Rng::sample_iter`] using
+    /// This is a just a wrapper over [`Distribution::sample_iter`] using
     /// [`distributions::Standard`].
     ///
-    /// Note: this method consumes its argument. Use
-    /// `(&mut rng).gen_iter()` to avoid consuming the RNG.
+    /// The iterator borrows the RNG mutably for its lifetime, so the RNG may
+    /// be used again once the iterator is dropped. Use
+    /// [`Rng::sample_iter`] to move the RNG into the iterator instead.
     ///
     /// # Example
     ///
     /// ```
     /// use rand::{rngs::mock::StepRng, Rng};
     ///
-    /// let rng = StepRng::new(1, 1);
-    /// let v: Vec<i32> = rng.gen_iter().take(5).collect();
+    /// let mut rng = StepRng::new(1, 1);
+    /// let v: Vec<u32> = rng.gen_iter::<u32>().take(5).collect();
     /// assert_eq!(&v, &[1, 2, 3, 4, 5]);
+    ///
+    /// let evens: Vec<u32> = rng.gen_iter().filter(|x| x % 2 == 0).take(2).collect();
+    /// assert_eq!(&evens, &[6, 8]);
     /// ```
     #[inline]
-    fn gen_iter<T>(self) -> distributions::DistIter<Standard, Self, T>
+    fn gen_iter<T>(&mut self) -> distributions::DistIter<Standard, &mut Self, T>
     where
-        Self: Sized,
         Standard: Distribution<T>,
     {
         Standard.sample_iter(self)
@@ -218,6 +293,70 @@ pub trait Rng: RngCore {
         distr.sample_iter(self)
     }
 
+    /// Generate a `String` of `len` chars sampled from `distr`
+    ///
+    /// Note that `len` counts chars, not bytes. For random alphanumeric
+    /// strings, use [`DistString::sample_string`] with
+    /// [`distributions::Alphanumeric`], which samples ASCII bytes rather than
+    /// chars.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::distributions::{DistString, Alphanumeric, Uniform};
+    /// use rand::Rng;
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let greek = Uniform::new_inclusive('α', 'ω').unwrap();
+    /// let word = rng.sample_string(8, greek);
+    /// assert_eq!(word.chars().count(), 8);
+    ///
+    /// let token = Alphanumeric.sample_string(&mut rng, 16);
+    /// assert_eq!(token.len(), 16);
+    /// ```
+    ///
+    /// [`DistString::sample_string`]: distributions::DistString::sample_string
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+    fn sample_string<D: Distribution<char>>(&mut self, len: usize, distr: D) -> alloc::string::String {
+        let mut s = alloc::string::String::with_capacity(len);
+        s.extend((0..len).map(|_| distr.sample(self)));
+        s
+    }
+
+    /// Generate a uniformly random permutation of `0..n`
+    ///
+    /// Unlike shuffling in place, this keeps the permutation, e.g. to apply it
+    /// to several slices. Element `i` of the result is the index of the
+    /// original item placed at position `i`. The result is the same as that
+    /// of shuffling `(0..n).collect::<Vec<_>>()` with [`SliceRandom::shuffle`]
+    /// (Fisher-Yates). For a portable permutation determined by a seed alone,
+    /// see [`index::permutation_seeded`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::Rng;
+    ///
+    /// let names = ["Alice", "Bob", "Carol"];
+    /// let ages = [31, 27, 45];
+    /// let perm = rand::thread_rng().permutation(names.len());
+    /// let names: Vec<_> = perm.iter().map(|&i| names[i]).collect();
+    /// let ages: Vec<_> = perm.iter().map(|&i| ages[i]).collect();
+    /// # assert_eq!(names.len(), ages.len());
+    /// ```
+    ///
+    /// [`SliceRandom::shuffle`]: crate::seq::SliceRandom::shuffle
+    /// [`index::permutation_seeded`]: crate::seq::index::permutation_seeded
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+    fn permutation(&mut self, n: usize) -> alloc::vec::Vec<usize> {
+        use crate::seq::SliceRandom;
+        let mut perm: alloc::vec::Vec<usize> = (0..n).collect();
+        perm.shuffle(self);
+        perm
+    }
+
     /// Fill any type implementing [`Fill`] with random data
     ///
     /// The distribution is expected to be uniform with portable results, but
@@ -268,6 +407,106 @@ pub trait Rng: RngCore {
         dest.try_fill(self)
     }
 
+    /// Fill an uninitialized byte buffer with random data, returning it as
+    /// an initialized slice
+    ///
+    /// This avoids zeroing a buffer before [`fill_bytes`], e.g. for large
+    /// buffers from [`Vec::spare_capacity_mut`]. The bytes are generated with
+    /// [`fill_bytes`] in chunks via a small stack buffer (since `fill_bytes`
+    /// may not write to uninitialized memory); for all RNGs in this crate, the
+    /// output equals that of a single `fill_bytes` call.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core::mem::MaybeUninit;
+    /// use rand::{thread_rng, Rng};
+    ///
+    /// let mut buf = [MaybeUninit::<u8>::uninit(); 1000];
+    /// let bytes: &mut [u8] = thread_rng().fill_uninit(&mut buf);
+    /// assert_eq!(bytes.len(), 1000);
+    /// ```
+    ///
+    /// [`fill_bytes`]: RngCore::fill_bytes
+    /// [`Vec::spare_capacity_mut`]: https://doc.rust-lang.org/std/vec/struct.Vec.html#method.spare_capacity_mut
+    fn fill_uninit<'a>(&mut self, dest: &'a mut [mem::MaybeUninit<u8>]) -> &'a mut [u8] {
+        // A multiple of 8, such that word-based RNGs do not discard output
+        // between chunks
+        let mut buf = [0u8; 256];
+        for chunk in dest.chunks_mut(buf.len()) {
+            let buf = &mut buf[..chunk.len()];
+            self.fill_bytes(buf);
+            for (d, &b) in chunk.iter_mut().zip(buf.iter()) {
+                *d = mem::MaybeUninit::new(b);
+            }
+        }
+        // SAFETY: every byte of `dest` was written above, and `MaybeUninit<u8>`
+        // has the same layout as `u8`.
+        unsafe { &mut *(dest as *mut [mem::MaybeUninit<u8>] as *mut [u8]) }
+    }
+
+    /// Allocate a `Vec` of `n` random bytes
+    ///
+    /// The bytes are generated with [`fill_uninit`] directly into the new
+    /// allocation, without zeroing it first.
+    ///
+    /// This is identical to [`try_gen_bytes`] except that it panics on error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::{thread_rng, Rng};
+    ///
+    /// let key = thread_rng().gen_bytes(32);
+    /// assert_eq!(key.len(), 32);
+    /// ```
+    ///
+    /// [`fill_uninit`]: Rng::fill_uninit
+    /// [`try_gen_bytes`]: Rng::try_gen_bytes
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+    fn gen_bytes(&mut self, n: usize) -> alloc::vec::Vec<u8> {
+        let mut bytes = alloc::vec::Vec::with_capacity(n);
+        // SAFETY: the capacity is at least `n`, and uninitialized memory is
+        // valid for `MaybeUninit<u8>`.
+        let spare = unsafe {
+            slice::from_raw_parts_mut(bytes.as_mut_ptr() as *mut mem::MaybeUninit<u8>, n)
+        };
+        self.fill_uninit(spare);
+        // SAFETY: `fill_uninit` initialized the first `n` bytes.
+        unsafe { bytes.set_len(n) };
+        bytes
+    }
+
+    /// Allocate a `Vec` of `n` random bytes, forwarding errors
+    ///
+    /// This uses [`try_fill_bytes`], which may not write to uninitialized
+    /// memory, so the allocation is zeroed first.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rand::Error;
+    /// use rand::{thread_rng, Rng};
+    ///
+    /// # fn try_inner() -> Result<(), Error> {
+    /// let key = thread_rng().try_gen_bytes(32)?;
+    /// assert_eq!(key.len(), 32);
+    /// # Ok(())
+    /// # }
+    ///
+    /// # try_inner().unwrap()
+    /// ```
+    ///
+    /// [`try_fill_bytes`]: RngCore::try_fill_bytes
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+    fn try_gen_bytes(&mut self, n: usize) -> Result<alloc::vec::Vec<u8>, Error> {
+        let mut bytes = alloc::vec![0u8; n];
+        self.try_fill_bytes(&mut bytes)?;
+        Ok(bytes)
+    }
+
     /// Return a bool with a probability `p` of being true.
     ///
     /// See also the [`Bernoulli`] distribution, which may be faster if
@@ -299,6 +538,12 @@ pub trait Rng: RngCore {
     /// is guaranteed to be `true`. If `numerator == 0`, then the returned
     /// value is guaranteed to be `false`.
     ///
+    /// The comparison uses integer arithmetic only: one `u64` is sampled and
+    /// compared to `numerator / denominator` scaled to 2<sup>64</sup> and
+    /// rounded down, so the probability is exact to within 2<sup>-64</sup>.
+    /// `gen_bool(1.0 / 3.0)` is less precise, since `1.0 / 3.0` is rounded
+    /// to an `f64`.
+    ///
     /// See also the [`Bernoulli`] distribution, which may be faster if
     /// sampling from the same `numerator` and `denominator` repeatedly.
     ///
@@ -321,10 +566,143 @@ pub trait Rng: RngCore {
         let d = distributions::Bernoulli::from_ratio(numerator, denominator).unwrap();
         self.sample(d)
     }
+
+    /// Return a bool with a probability of `numerator/denominator` of being
+    /// true, for denominators too large for [`Rng::gen_ratio`].
+    ///
+    /// The guarantees are those of [`Rng::gen_ratio`]: the probability is
+    /// exact to within 2<sup>-64</sup>, and the result is always `true` if
+    /// `numerator == denominator` and always `false` if `numerator == 0`.
+    /// This suits fine-grained odds such as a drop rate of 1 in 1,000,000.
+    ///
+    /// # Panics
+    ///
+    /// If `denominator == 0` or `numerator > denominator` (in all builds;
+    /// the arguments are not clamped).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::{thread_rng, Rng};
+    ///
+    /// let mut rng = thread_rng();
+    /// let one_in_ten_billion = rng.gen_ratio_u64(1, 10_000_000_000);
+    /// # let _ = one_in_ten_billion;
+    /// ```
+    #[doc(alias = "chance")]
+    #[inline]
+    fn gen_ratio_u64(&mut self, numerator: u64, denominator: u64) -> bool {
+        let d = distributions::Bernoulli::from_ratio_u64(numerator, denominator).unwrap();
+        self.sample(d)
+    }
+
+    /// Sample a value from `dist` which is not contained in `blocklist`.
+    ///
+    /// Values are sampled until one is not found in `blocklist`, up to
+    /// `max_tries` times; `None` is returned if all attempts hit the
+    /// blocklist. This is useful e.g. to generate IDs avoiding collisions with
+    /// existing ones.
+    ///
+    /// Each attempt succeeds with probability `p`, the probability mass of
+    /// `dist` outside of the blocklist, thus `1 / p` attempts are needed on
+    /// average. When the blocklist covers most of the support of `dist`, `p`
+    /// is small and this method will likely fail (or require a large
+    /// `max_tries`); in that case, consider sampling from the complement of
+    /// the blocklist directly, e.g. with [`SliceRandom::choose`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::HashSet;
+    /// use rand::{thread_rng, Rng};
+    /// use rand::distributions::Uniform;
+    ///
+    /// let used: HashSet<u32> = [1, 2, 3].iter().cloned().collect();
+    /// let dist = Uniform::new(0, 1000).unwrap();
+    /// let id = thread_rng().gen_not_in(&dist, &used, 100).unwrap();
+    /// assert!(!used.contains(&id));
+    /// ```
+    ///
+    /// [`SliceRandom::choose`]: crate::seq::SliceRandom::choose
+    #[cfg(feature = "std")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
+    fn gen_not_in<T, D, S>(
+        &mut self, dist: &D, blocklist: &std::collections::HashSet<T, S>, max_tries: usize,
+    ) -> Option<T>
+    where
+        D: Distribution<T> + ?Sized,
+        T: Eq + core::hash::Hash,
+        S: core::hash::BuildHasher,
+    {
+        for _ in 0..max_tries {
+            let value = dist.sample(self);
+            if !blocklist.contains(&value) {
+                return Some(value);
+            }
+        }
+        None
+    }
 }
 
 impl<R: RngCore + ?Sized> Rng for R {}
 
+/// Draws all output of the wrapped RNG via `try_fill_bytes`, recording the
+/// first error instead of panicking; `finish` reports it.
+///
+/// Values returned after an error are meaningless, but are not constant, such
+/// that rejection sampling still terminates.
+struct TryRng<'a, R: ?Sized> {
+    rng: &'a mut R,
+    error: Option<Error>,
+    fallback: SplitMix64,
+}
+
+impl<'a, R: RngCore + ?Sized> TryRng<'a, R> {
+    fn new(rng: &'a mut R) -> Self {
+        TryRng {
+            rng,
+            error: None,
+            fallback: SplitMix64::new(0),
+        }
+    }
+
+    fn finish(self) -> Result<(), Error> {
+        match self.error {
+            Some(err) => Err(err),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<'a, R: RngCore + ?Sized> RngCore for TryRng<'a, R> {
+    fn next_u32(&mut self) -> u32 {
+        let mut buf = [0; 4];
+        self.fill_bytes(&mut buf);
+        u32::from_le_bytes(buf)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut buf = [0; 8];
+        self.fill_bytes(&mut buf);
+        u64::from_le_bytes(buf)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        if self.error.is_none() {
+            match self.rng.try_fill_bytes(dest) {
+                Ok(()) => return,
+                Err(err) => self.error = Some(err),
+            }
+        }
+        self.fallback.fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
 /// Types which may be filled with random data
 ///
 /// This trait allows arrays to be efficiently filled with random data.
@@ -428,6 +806,28 @@ mod test {
     use crate::rngs::mock::StepRng;
     #[cfg(feature = "alloc")] use alloc::boxed::Box;
 
+    /// An RNG whose `try_fill_bytes` always fails with the custom error code
+    /// 7; like `OsRng`, the infallible methods panic on error.
+    struct FailingRng;
+
+    impl RngCore for FailingRng {
+        fn next_u32(&mut self) -> u32 {
+            rand_core::impls::next_u32_via_fill(self)
+        }
+        fn next_u64(&mut self) -> u64 {
+            rand_core::impls::next_u64_via_fill(self)
+        }
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            if let Err(e) = self.try_fill_bytes(dest) {
+                panic!("Error: {}", e);
+            }
+        }
+        fn try_fill_bytes(&mut self, _: &mut [u8]) -> Result<(), Error> {
+            let code = core::num::NonZeroU32::new(Error::CUSTOM_START + 7).unwrap();
+            Err(Error::from(code))
+        }
+    }
+
     #[test]
     fn test_fill_bytes_default() {
         let mut r = StepRng::new(0x11_22_33_44_55_66_77_88, 0);
@@ -486,6 +886,76 @@ mod test {
         rng.fill(&mut array[..]);
     }
 
+    #[test]
+    fn test_try_gen() {
+        let code = |err: Error| err.code().unwrap().get() - Error::CUSTOM_START;
+        let mut failing = FailingRng;
+        assert_eq!(failing.try_gen::<u32>().map_err(code), Err(7));
+        assert_eq!(failing.try_gen::<(u8, f64, char)>().map_err(code), Err(7));
+        assert_eq!(failing.try_gen::<[bool; 3]>().map_err(code), Err(7));
+        assert_eq!(failing.try_fill(&mut [0u64; 3]).map_err(code), Err(7));
+
+        // Values match those of `gen` for the generators of this crate
+        type Values = (u32, u64, u128, f32, f64, char, [bool; 4], (u8, i16));
+        fn check<R: RngCore + Clone>(mut a: R) {
+            let mut b = a.clone();
+            for _ in 0..100 {
+                let x: Values = a.try_gen().unwrap();
+                assert_eq!(x, b.gen());
+            }
+            assert_eq!(a.next_u64(), b.next_u64());
+        }
+        check(rand_pcg::Pcg32::new(301, 11));
+        #[cfg(feature = "std_rng")]
+        check(<crate::rngs::StdRng as rand_core::SeedableRng>::seed_from_u64(301));
+        #[cfg(feature = "small_rng")]
+        check(<crate::rngs::SmallRng as rand_core::SeedableRng>::seed_from_u64(301));
+
+        let mut a = StepRng::new(3, 0x1_0000_0001);
+        let mut b = a.clone();
+        assert_eq!(a.try_gen::<(u32, u64, u128)>().unwrap(), b.gen());
+        let (mut x, mut y) = ([0f64; 5], [0f64; 5]);
+        a.try_fill(&mut x).unwrap();
+        y.iter_mut().for_each(|y| *y = b.gen());
+        assert_eq!(x, y);
+    }
+
+    #[test]
+    fn test_fill_uninit() {
+        for &n in &[0, 1, 7, 256, 257, 1000] {
+            let mut r1 = rng(302);
+            let mut r2 = rng(302);
+            let mut buf = [mem::MaybeUninit::<u8>::uninit(); 1000];
+            let bytes = r1.fill_uninit(&mut buf[..n]);
+            assert_eq!(bytes.len(), n);
+
+            let mut expected = [0u8; 1000];
+            r2.fill_bytes(&mut expected[..n]);
+            assert_eq!(bytes, &expected[..n]);
+            assert_eq!(r1.next_u64(), r2.next_u64());
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_gen_bytes() {
+        let mut r = rand_pcg::Pcg32::new(303, 11);
+        for &n in &[0, 1, 7, 256, 257, 1000] {
+            let mut expected = alloc::vec![0u8; n];
+            r.clone().fill_bytes(&mut expected);
+            let bytes = r.gen_bytes(n);
+            assert_eq!(bytes.len(), n);
+            assert_eq!(bytes, expected);
+        }
+        assert_ne!(r.gen_bytes(32), r.gen_bytes(32));
+        assert_ne!(r.try_gen_bytes(32).unwrap(), r.try_gen_bytes(32).unwrap());
+        assert_eq!(r.try_gen_bytes(0).unwrap().len(), 0);
+        assert_eq!(r.try_gen_bytes(100).unwrap().len(), 100);
+
+        let err = FailingRng.try_gen_bytes(10).unwrap_err();
+        assert_eq!(err.code().unwrap().get(), Error::CUSTOM_START + 7);
+    }
+
     #[test]
     fn test_gen_range_int() {
         let mut r = rng(101);
@@ -507,6 +977,42 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_gen_range_array() {
+        use crate::distributions::uniform::{SampleUniform, UniformSampler};
+
+        let mut r = rng(103);
+        for _ in 0..100 {
+            let a: [i16; 8] = r.gen_range_array(-300..17);
+            assert!(a.iter().all(|x| (-300..17).contains(x)));
+            let a: [f64; 3] = r.gen_range_array(-1.5..=2.0);
+            assert!(a.iter().all(|x| (-1.5..=2.0).contains(x)));
+        }
+        let a: [u8; 0] = r.gen_range_array(0..1);
+        assert_eq!(a, []);
+
+        // Matches sequential samples from a single sampler
+        let mut r1 = rng(104);
+        let mut r2 = rng(104);
+        let a: [u64; 16] = r1.gen_range_array(10..1_000_000_007);
+        let sampler = <u64 as SampleUniform>::Sampler::new(10, 1_000_000_007).unwrap();
+        for x in a.iter() {
+            assert_eq!(*x, sampler.sample(&mut r2));
+        }
+        let a: [f32; 5] = r1.gen_range_array(0.5..=0.75);
+        let sampler = <f32 as SampleUniform>::Sampler::new_inclusive(0.5, 0.75).unwrap();
+        for x in a.iter() {
+            assert_eq!(*x, sampler.sample(&mut r2));
+        }
+        assert_eq!(r1.next_u64(), r2.next_u64());
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot sample empty range")]
+    fn test_gen_range_array_empty() {
+        let _: [u32; 2] = rng(105).gen_range_array(5..5);
+    }
+
     #[test]
     fn test_gen_range_float() {
         let mut r = rng(101);
@@ -572,6 +1078,82 @@ mod test {
         let _c: u8 = Standard.sample(&mut r);
     }
 
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_gen_not_in() {
+        use crate::distributions::Uniform;
+        use std::collections::HashSet;
+
+        let mut r = rng(112);
+        let dist = Uniform::new(0u8, 8).unwrap();
+
+        // All but one value blocked
+        let blocklist: HashSet<u8> = (0..8).filter(|&x| x != 5).collect();
+        for _ in 0..10 {
+            assert_eq!(r.gen_not_in(&dist, &blocklist, 1000), Some(5));
+        }
+
+        // All values blocked
+        let blocklist: HashSet<u8> = (0..8).collect();
+        assert_eq!(r.gen_not_in(&dist, &blocklist, 100), None);
+        assert_eq!(r.gen_not_in(&dist, &HashSet::new(), 0), None);
+
+        let empty = HashSet::new();
+        let x = r.gen_not_in(&dist, &empty, 1).unwrap();
+        assert!(x < 8);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_sample_string() {
+        use crate::distributions::Uniform;
+        let mut r = rng(315);
+        let greek = Uniform::new_inclusive('α', 'ω').unwrap();
+        for &len in &[0, 1, 7, 100] {
+            let s = r.sample_string(len, greek);
+            assert_eq!(s.chars().count(), len);
+            assert!(s.chars().all(|c| ('α'..='ω').contains(&c)), "{}", s);
+        }
+        let s = r.sample_string(50, crate::distributions::Standard);
+        assert_eq!(s.chars().count(), 50);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_permutation() {
+        use crate::seq::SliceRandom;
+        let mut r = rng(320);
+        for &n in &[0, 1, 2, 10, 1000] {
+            let mut perm = r.permutation(n);
+            perm.sort_unstable();
+            assert!(perm.iter().copied().eq(0..n));
+        }
+
+        // Same as shuffling in place
+        let mut r2 = rng(320);
+        let mut v: alloc::vec::Vec<usize> = (0..20).collect();
+        v.shuffle(&mut r2);
+        assert_eq!(rng(320).permutation(20), v);
+
+        // All 24 permutations of 0..4 are about equally likely
+        let mut counts = [0u32; 24];
+        let n = 24_000;
+        for _ in 0..n {
+            let p = r.permutation(4);
+            // Lehmer code of the permutation
+            let mut code = 0;
+            for i in 0..4 {
+                let smaller = p[i + 1..].iter().filter(|&&x| x < p[i]).count();
+                code = code * (4 - i) + smaller;
+            }
+            counts[code] += 1;
+        }
+        for &c in counts.iter() {
+            // Expected 1000, standard deviation ~31
+            assert!((850..1150).contains(&c), "{:?}", counts);
+        }
+    }
+
     #[test]
     #[cfg_attr(miri, ignore)] // Miri is too slow
     fn test_gen_ratio_average() {
@@ -590,4 +1172,48 @@ mod test {
         let expected = (NUM * N) / DENOM; // exact integer
         assert!(((sum - expected) as i32).abs() < 500);
     }
+
+    #[test]
+    fn test_gen_ratio_exact_threshold() {
+        use crate::rngs::mock::StepRng;
+        // `gen_ratio(1, 3)` is true for the `floor(2^64 / 3) = u64::MAX / 3`
+        // smallest outputs, the closest possible to 1/3 of all outputs
+        let threshold = u64::MAX / 3;
+        assert!(StepRng::new(threshold - 1, 0).gen_ratio(1, 3));
+        assert!(!StepRng::new(threshold, 0).gen_ratio(1, 3));
+        assert!(!StepRng::new(threshold, 0).gen_ratio_u64(1 << 40, 3 << 40));
+
+        // `gen_bool(1.0 / 3.0)` is false for some outputs below the threshold
+        let below = (threshold - 1024..threshold)
+            .filter(|&v| !StepRng::new(v, 0).gen_bool(1.0 / 3.0))
+            .count();
+        assert!(below > 0);
+
+        let mut rng = rng(308);
+        assert!(rng.gen_ratio_u64(u64::MAX, u64::MAX));
+        assert!(!rng.gen_ratio_u64(0, u64::MAX));
+        let n = 100_000;
+        let sum = (0..n).filter(|_| rng.gen_ratio_u64(3_000_000_000_000, 10_000_000_000_000)).count();
+        assert!((sum as i64 - 30_000).abs() < 500, "{}", sum);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn test_gen_ratio_u64_one_in_a_million() {
+        use crate::rngs::mock::StepRng;
+        // True for exactly `floor(2^64 / 10^6)` of all outputs
+        let threshold = ((1u128 << 64) / 1_000_000) as u64;
+        assert!(StepRng::new(threshold - 1, 0).gen_ratio_u64(1, 1_000_000));
+        assert!(!StepRng::new(threshold, 0).gen_ratio_u64(1, 1_000_000));
+        // Odds not representable as `f64`
+        let threshold = ((999_999u128 << 64) / 1_000_003) as u64;
+        assert!(StepRng::new(threshold - 1, 0).gen_ratio_u64(999_999, 1_000_003));
+        assert!(!StepRng::new(threshold, 0).gen_ratio_u64(999_999, 1_000_003));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_gen_ratio_u64_invalid() {
+        rng(309).gen_ratio_u64(2, 1);
+    }
 }
@@ -0,0 +1,142 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Fork protection for [`ReseedingRng`](super::ReseedingRng).
+//!
+//! With the `fork_simulation` feature, [`simulate_fork`] allows testing the
+//! reseeding of generators after a fork on all platforms.
+
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+// Fork protection
+//
+// We implement fork protection on Unix using `pthread_atfork`.
+// When the process is forked, we increment `RESEEDING_RNG_FORK_COUNTER`.
+// Every `ReseedingRng` stores the last known value of the static in
+// `fork_counter`. If the cached `fork_counter` is less than
+// `RESEEDING_RNG_FORK_COUNTER`, it is time to reseed this RNG.
+//
+// If reseeding fails, we don't deal with this by setting a delay, but just
+// don't update `fork_counter`, so a reseed is attempted as soon as
+// possible.
+//
+// On other platforms the counter only changes through `simulate_fork`.
+
+static RESEEDING_RNG_FORK_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+pub(crate) fn get_fork_counter() -> usize {
+    RESEEDING_RNG_FORK_COUNTER.load(Ordering::Relaxed)
+}
+
+// Elsewhere only used by `simulate_fork`
+#[cfg_attr(not(all(unix, not(target_os = "emscripten"))), allow(dead_code))]
+extern "C" fn fork_handler() {
+    // Note: fetch_add is defined to wrap on overflow
+    // (which is what we want).
+    RESEEDING_RNG_FORK_COUNTER.fetch_add(1, Ordering::Relaxed);
+}
+
+#[cfg(all(unix, not(target_os = "emscripten")))]
+pub(crate) fn register_fork_handler() {
+    use std::sync::Once;
+
+    static REGISTER: Once = Once::new();
+    REGISTER.call_once(|| {
+        // Bump the counter before and after forking (see #1169):
+        let ret = unsafe { libc::pthread_atfork(
+            Some(fork_handler),
+            Some(fork_handler),
+            Some(fork_handler),
+        ) };
+        if ret != 0 {
+            panic!("libc::pthread_atfork failed with code {}", ret);
+        }
+    });
+}
+
+#[cfg(not(all(unix, not(target_os = "emscripten"))))]
+pub(crate) fn register_fork_handler() {}
+
+/// Act as if the process had been forked.
+///
+/// This bumps the fork counter as the handlers registered with
+/// `pthread_atfork` do when forking on Unix: every [`ReseedingRng`] in the
+/// process, including [`ThreadRng`], reseeds before generating its next
+/// block, i.e. after at most one block of buffered values. It works on all
+/// platforms, thus allows testing code relying on fork protection without
+/// forking.
+///
+/// # Example
+///
+/// ```
+/// use rand::rngs::adapter::fork;
+/// use rand::Rng;
+///
+/// let mut rng = rand::thread_rng();
+/// let before: u64 = rng.gen();
+/// fork::simulate_fork();
+/// // `rng` now reseeds before generating its next block
+/// let after: u64 = rng.gen();
+/// # let _ = (before, after);
+/// ```
+///
+/// [`ReseedingRng`]: super::ReseedingRng
+/// [`ThreadRng`]: crate::rngs::ThreadRng
+#[cfg(any(test, feature = "fork_simulation"))]
+pub fn simulate_fork() {
+    fork_handler();
+    fork_handler();
+}
+
+// Simulated forks reseed every `ReseedingRng` in the process, including those
+// of concurrently running tests. Tests which simulate forks hold this lock for
+// writing, tests which depend on when their generators reseed for reading.
+#[cfg(test)]
+fn test_lock() -> &'static std::sync::RwLock<()> {
+    use core::sync::atomic::AtomicPtr;
+    use std::sync::{Once, RwLock};
+
+    static LOCK: AtomicPtr<RwLock<()>> = AtomicPtr::new(core::ptr::null_mut());
+    static INIT: Once = Once::new();
+    INIT.call_once(|| {
+        let lock = std::boxed::Box::new(RwLock::new(()));
+        LOCK.store(std::boxed::Box::into_raw(lock), Ordering::Release);
+    });
+    // The lock is initialised above and never freed
+    unsafe { &*LOCK.load(Ordering::Acquire) }
+}
+
+/// Prevent other tests from simulating forks while the guard is held
+#[cfg(test)]
+pub(crate) fn lock_no_forks() -> std::sync::RwLockReadGuard<'static, ()> {
+    // A failing test poisons the lock, but leaves nothing to clean up
+    test_lock().read().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Allow simulating forks without disturbing other tests while the guard is held
+#[cfg(test)]
+pub(crate) fn lock_forks() -> ForkGuard {
+    let lock = test_lock().write().unwrap_or_else(|e| e.into_inner());
+    let counter = get_fork_counter();
+    ForkGuard { _lock: lock, counter }
+}
+
+/// Restores the fork counter when dropped, such that generators created by
+/// later tests do not reseed on first use
+#[cfg(test)]
+pub(crate) struct ForkGuard {
+    _lock: std::sync::RwLockWriteGuard<'static, ()>,
+    counter: usize,
+}
+
+#[cfg(test)]
+impl Drop for ForkGuard {
+    fn drop(&mut self) {
+        RESEEDING_RNG_FORK_COUNTER.store(self.counter, Ordering::Relaxed);
+    }
+}
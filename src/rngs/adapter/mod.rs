@@ -8,9 +8,18 @@
 
 //! Wrappers / adapters forming RNGs
 
+#[cfg(feature = "fork_simulation")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "fork_simulation")))]
+pub mod fork;
+#[cfg(not(feature = "fork_simulation"))]
+mod fork;
+mod quota;
 mod read;
 mod reseeding;
+mod shared;
 
+pub use self::quota::{QuotaPolicy, QuotaRng};
 #[allow(deprecated)]
 pub use self::read::{ReadError, ReadRng};
-pub use self::reseeding::ReseedingRng;
+pub use self::reseeding::{ReseedingRng, ReseedingState};
+pub use self::shared::SharedRng;
@@ -0,0 +1,238 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A wrapper limiting the output of an RNG.
+
+use rand_core::{impls, Error, RngCore};
+
+/// What the infallible methods of [`QuotaRng`] do once its quota is exhausted
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QuotaPolicy {
+    /// Panic if a request exceeds the remaining quota.
+    Panic,
+    /// Fill the remaining quota from the inner RNG and all further bytes with
+    /// zeros.
+    Clamp,
+}
+
+/// A wrapper around an RNG which produces at most a fixed number of bytes.
+///
+/// This bounds the randomness consumed, e.g. per test case of a fuzzing
+/// harness. Each request counts the bytes it returns against the quota:
+/// 4 bytes for `next_u32`, 8 for `next_u64` and the length of the buffer for
+/// `fill_bytes` and `try_fill_bytes`.
+///
+/// A request exceeding the remaining quota is handled as follows:
+///
+/// -   [`try_fill_bytes`] returns an error without consuming any quota.
+/// -   The other methods of [`RngCore`] follow the [`QuotaPolicy`]: either
+///     panic, or use up the remaining quota and fill the rest of the
+///     output with zeros. In the latter case, a request straddling the quota
+///     returns the leading bytes from the inner RNG.
+///
+/// [`reset()`] restores the full quota.
+///
+/// `QuotaRng` does not implement [`CryptoRng`](rand_core::CryptoRng), even if
+/// the inner RNG does, since it may return zeros.
+///
+/// # Example
+///
+/// ```
+/// use rand::rngs::adapter::{QuotaPolicy, QuotaRng};
+/// use rand::rngs::StdRng;
+/// use rand::{RngCore, SeedableRng};
+///
+/// let mut rng = QuotaRng::new(StdRng::seed_from_u64(0), 16, QuotaPolicy::Panic);
+/// let mut buf = [0u8; 12];
+/// assert!(rng.try_fill_bytes(&mut buf).is_ok());
+/// assert_eq!(rng.remaining(), 4);
+/// assert!(rng.try_fill_bytes(&mut buf).is_err());
+/// rng.reset();
+/// assert_eq!(rng.remaining(), 16);
+/// ```
+///
+/// [`try_fill_bytes`]: RngCore::try_fill_bytes
+/// [`reset()`]: QuotaRng::reset
+#[derive(Debug, Clone)]
+pub struct QuotaRng<R> {
+    inner: R,
+    quota: u64,
+    remaining: u64,
+    policy: QuotaPolicy,
+}
+
+impl<R: RngCore> QuotaRng<R> {
+    /// Wrap `inner`, allowing it to produce `quota` bytes.
+    pub fn new(inner: R, quota: u64, policy: QuotaPolicy) -> Self {
+        QuotaRng {
+            inner,
+            quota,
+            remaining: quota,
+            policy,
+        }
+    }
+
+    /// The number of bytes which may still be produced
+    pub fn remaining(&self) -> u64 {
+        self.remaining
+    }
+
+    /// The total number of bytes which may be produced after a reset
+    pub fn quota(&self) -> u64 {
+        self.quota
+    }
+
+    /// Restore the full quota.
+    ///
+    /// The state of the inner RNG is not affected.
+    pub fn reset(&mut self) {
+        self.remaining = self.quota;
+    }
+
+    /// Unwrap the inner RNG.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// Take `n` bytes from the quota, returning `false` if they exceed it
+    #[inline]
+    fn take(&mut self, n: u64) -> bool {
+        if n > self.remaining {
+            return false;
+        }
+        self.remaining -= n;
+        true
+    }
+
+    #[cold]
+    fn exhausted(&mut self, dest: &mut [u8]) {
+        match self.policy {
+            QuotaPolicy::Panic => panic!(
+                "QuotaRng: request for {} bytes exceeds the remaining quota of {} bytes",
+                dest.len(),
+                self.remaining
+            ),
+            QuotaPolicy::Clamp => {
+                // `remaining < dest.len()`, hence fits in `usize`
+                let (head, tail) = dest.split_at_mut(self.remaining as usize);
+                self.inner.fill_bytes(head);
+                tail.iter_mut().for_each(|x| *x = 0);
+                self.remaining = 0;
+            }
+        }
+    }
+}
+
+impl<R: RngCore> RngCore for QuotaRng<R> {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        if self.take(4) {
+            self.inner.next_u32()
+        } else {
+            impls::next_u32_via_fill(self)
+        }
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        if self.take(8) {
+            self.inner.next_u64()
+        } else {
+            impls::next_u64_via_fill(self)
+        }
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        if self.take(dest.len() as u64) {
+            self.inner.fill_bytes(dest)
+        } else {
+            self.exhausted(dest)
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        if dest.len() as u64 > self.remaining {
+            return Err(Error::new("QuotaRng: quota exhausted"));
+        }
+        self.inner.try_fill_bytes(dest)?;
+        self.remaining -= dest.len() as u64;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::rngs::mock::StepRng;
+
+    #[test]
+    fn test_quota_counts_bytes() {
+        let mut rng = QuotaRng::new(StepRng::new(1, 1), 32, QuotaPolicy::Panic);
+        assert_eq!(rng.next_u32(), 1);
+        assert_eq!(rng.next_u64(), 2);
+        let mut buf = [0u8; 20];
+        rng.fill_bytes(&mut buf);
+        assert_eq!(rng.remaining(), 0);
+        assert!(rng.try_fill_bytes(&mut []).is_ok());
+        assert!(rng.try_fill_bytes(&mut [0u8; 1]).is_err());
+
+        rng.reset();
+        assert_eq!(rng.remaining(), rng.quota());
+        // The inner RNG continues where it stopped
+        assert_eq!(rng.into_inner().next_u64(), 6);
+    }
+
+    #[test]
+    fn test_quota_try_fill_straddling() {
+        let mut rng = QuotaRng::new(StepRng::new(0x0807_0605_0403_0201, 0), 10, QuotaPolicy::Panic);
+        let mut buf = [0u8; 6];
+        assert!(rng.try_fill_bytes(&mut buf).is_ok());
+        assert_eq!(rng.remaining(), 4);
+
+        // A request straddling the quota fails without consuming it ...
+        let mut buf = [0xffu8; 5];
+        assert!(rng.try_fill_bytes(&mut buf).is_err());
+        assert_eq!(buf, [0xff; 5]);
+        assert_eq!(rng.remaining(), 4);
+        // ... while one ending exactly at the quota succeeds
+        let mut buf = [0u8; 4];
+        assert!(rng.try_fill_bytes(&mut buf).is_ok());
+        assert_eq!(buf, [1, 2, 3, 4]);
+        assert_eq!(rng.remaining(), 0);
+    }
+
+    #[test]
+    fn test_quota_clamp_straddling() {
+        let mut rng = QuotaRng::new(StepRng::new(0x0807_0605_0403_0201, 0), 13, QuotaPolicy::Clamp);
+        let mut buf = [0xffu8; 8];
+        rng.fill_bytes(&mut buf);
+        assert_eq!(rng.remaining(), 5);
+
+        let mut buf = [0xffu8; 8];
+        rng.fill_bytes(&mut buf);
+        assert_eq!(buf, [1, 2, 3, 4, 5, 0, 0, 0]);
+        assert_eq!(rng.remaining(), 0);
+
+        assert_eq!(rng.next_u32(), 0);
+        assert_eq!(rng.next_u64(), 0);
+
+        rng.reset();
+        assert_eq!(rng.next_u64(), 0x0807_0605_0403_0201);
+        // Words straddling the quota keep their low bytes
+        assert_eq!(rng.next_u32(), 0x0403_0201);
+        assert_eq!(rng.next_u32(), 0x01);
+    }
+
+    #[test]
+    #[should_panic(expected = "exceeds the remaining quota")]
+    fn test_quota_panic_straddling() {
+        let mut rng = QuotaRng::new(StepRng::new(0, 0), 12, QuotaPolicy::Panic);
+        rng.next_u64();
+        rng.next_u64();
+    }
+}
@@ -10,10 +10,20 @@
 //! A wrapper around another PRNG that reseeds it after it
 //! generates a certain number of random bytes.
 
+use alloc::boxed::Box;
+use alloc::vec::Vec;
 use core::mem::size_of_val;
 
-use rand_core::block::{BlockRng, BlockRngCore, CryptoBlockRng};
+use rand_core::block::{BlockRng, BlockRngCore, CryptoBlockRng, SeekableRng};
+use rand_core::impls::fill_via_u32_chunks;
 use rand_core::{CryptoRng, Error, RngCore, SeedableRng};
+use core::fmt;
+use std::time::{Duration, Instant};
+
+use super::fork;
+
+#[cfg(feature = "serde1")]
+use serde::{Serialize, Deserialize};
 
 /// A wrapper around any PRNG that implements [`BlockRngCore`], that adds the
 /// ability to reseed it.
@@ -21,12 +31,18 @@ use rand_core::{CryptoRng, Error, RngCore, SeedableRng};
 /// `ReseedingRng` reseeds the underlying PRNG in the following cases:
 ///
 /// - On a manual call to [`reseed()`].
-/// - After `clone()`, the clone will be reseeded on first use.
+/// - After `clone()`, the clone will be reseeded on first use (but not after
+///   [`clone_no_reseed()`]).
 /// - When a process is forked on UNIX, the RNGs in both the parent and child
 ///   processes will be reseeded just before the next call to
 ///   [`BlockRngCore::generate`], i.e. "soon". For ChaCha and Hc128 this is a
 ///   maximum of fifteen `u32` values before reseeding.
-/// - After the PRNG has generated a configurable number of random bytes.
+/// - After the PRNG has generated a configurable number of random bytes
+///   (optionally rate-limited: see [`ReseedingRng::with_min_interval`]).
+/// - Optionally, after a time interval: see
+///   [`ReseedingRng::with_jittered_interval`].
+/// - Optionally, when an external signal is raised: see
+///   [`ReseedingRng::with_should_reseed`].
 ///
 /// # When should reseeding after a fixed number of generated bytes be used?
 ///
@@ -57,6 +73,11 @@ use rand_core::{CryptoRng, Error, RngCore, SeedableRng};
 /// If handling the source error fails `ReseedingRng` will continue generating
 /// data from the wrapped PRNG without reseeding.
 ///
+/// After a failure, reseeding is retried once `threshold` more bytes have
+/// been generated (or, with time-based reseeding, one interval later).
+/// [`consecutive_reseed_failures()`] reports the current failure count, e.g.
+/// to surface degraded entropy health.
+///
 /// Manually calling [`reseed()`] will not have this retry or delay logic, but
 /// reports the error.
 ///
@@ -81,6 +102,8 @@ use rand_core::{CryptoRng, Error, RngCore, SeedableRng};
 /// [`BlockRngCore`]: rand_core::block::BlockRngCore
 /// [`ReseedingRng::new`]: ReseedingRng::new
 /// [`reseed()`]: ReseedingRng::reseed
+/// [`consecutive_reseed_failures()`]: ReseedingRng::consecutive_reseed_failures
+/// [`clone_no_reseed()`]: ReseedingRng::clone_no_reseed
 #[derive(Debug)]
 pub struct ReseedingRng<R, Rsdr>(BlockRng<ReseedingCore<R, Rsdr>>)
 where
@@ -102,10 +125,375 @@ where
         ReseedingRng(BlockRng::new(ReseedingCore::new(rng, threshold, reseeder)))
     }
 
+    /// Create a new `ReseedingRng`, immediately reseeding the PRNG.
+    ///
+    /// This is like [`ReseedingRng::new`], except that the state of `rng` is
+    /// replaced by one from `reseeder` before any output is generated, so the
+    /// output never depends on how `rng` was seeded. Failure to reseed is
+    /// reported, not retried.
+    ///
+    /// The RNG is considered reseeded for the current fork generation: it will
+    /// next reseed after `threshold` bytes or after a subsequent fork, not on
+    /// first use.
+    pub fn new_reseeded(rng: R, threshold: u64, reseeder: Rsdr) -> Result<Self, Error> {
+        let mut core = ReseedingCore::new(rng, threshold, reseeder);
+        core.fork_counter = fork::get_fork_counter();
+        core.reseed()?;
+        Ok(ReseedingRng(BlockRng::new(core)))
+    }
+
     /// Reseed the internal PRNG.
     pub fn reseed(&mut self) -> Result<(), Error> {
         self.0.core.reseed()
     }
+
+    /// Set the number of generated bytes after which to reseed the PRNG.
+    ///
+    /// Bytes generated since the last reseed count towards the new threshold;
+    /// if they already exceed it, the PRNG is reseeded when generating the
+    /// next block. Set `threshold` to zero to never reseed based on the number
+    /// of generated values.
+    pub fn set_threshold(&mut self, threshold: u64) {
+        self.0.core.set_threshold(threshold)
+    }
+
+    /// The number of generated bytes after which the PRNG is reseeded, or
+    /// zero if it is never reseeded based on the number of generated values.
+    pub fn threshold(&self) -> u64 {
+        match self.0.core.threshold {
+            i64::MAX => 0,
+            threshold => threshold as u64,
+        }
+    }
+
+    /// The number of reseed attempts which have failed since the last
+    /// successful reseed (including manual calls to [`reseed()`]).
+    ///
+    /// [`reseed()`]: ReseedingRng::reseed
+    pub fn consecutive_reseed_failures(&self) -> u32 {
+        self.0.core.consecutive_failures
+    }
+
+    /// The number of words of the current block which have been consumed.
+    ///
+    /// This is a diagnostic, e.g. for correlating reseeds with block
+    /// boundaries: the PRNG is only reseeded when generating a new block.
+    /// The result is at most the block length, which it equals when the
+    /// buffer is exhausted (including before the first block is generated).
+    pub fn buffer_position(&self) -> usize {
+        self.0.index()
+    }
+
+    /// Create a new `ReseedingRng` which reseeds when `should_reseed` returns
+    /// `true`.
+    ///
+    /// This generalises fork detection to external signals, e.g. a daemon
+    /// reporting that the system entropy pool has been refreshed. The
+    /// predicate is called exactly once each time a new block is generated;
+    /// if it returns `true`, the PRNG is reseeded before generating the
+    /// block. The predicate must manage its own state, e.g. return `true`
+    /// only once per signal (edge-detection), otherwise the PRNG is reseeded
+    /// on every block.
+    ///
+    /// Reseeding on fork is unaffected. Reseeding after a number of generated
+    /// bytes is disabled, but may be enabled with
+    /// [`set_threshold()`](ReseedingRng::set_threshold).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::rngs::adapter::ReseedingRng;
+    /// use rand::rngs::OsRng;
+    /// use rand::{Rng, SeedableRng};
+    /// use rand_chacha::ChaCha20Core;
+    /// use std::sync::atomic::{AtomicBool, Ordering};
+    ///
+    /// static POOL_REFRESHED: AtomicBool = AtomicBool::new(false);
+    ///
+    /// fn pool_refreshed() -> bool {
+    ///     POOL_REFRESHED.swap(false, Ordering::Relaxed)
+    /// }
+    ///
+    /// let prng = ChaCha20Core::from_entropy();
+    /// let mut rng = ReseedingRng::with_should_reseed(prng, OsRng, pool_refreshed);
+    /// // Elsewhere, on a notification from the daemon:
+    /// POOL_REFRESHED.store(true, Ordering::Relaxed);
+    /// println!("{}", rng.gen::<u64>());
+    /// ```
+    pub fn with_should_reseed(rng: R, reseeder: Rsdr, should_reseed: fn() -> bool) -> Self {
+        let mut core = ReseedingCore::new(rng, 0, reseeder);
+        core.extras_mut().should_reseed = Some(should_reseed);
+        ReseedingRng(BlockRng::new(core))
+    }
+
+    /// Create a new `ReseedingRng` which generates up to `blocks` blocks of
+    /// the PRNG at a time.
+    ///
+    /// `threshold` has the same meaning as for [`ReseedingRng::new`]. The
+    /// additional blocks are buffered and used before generating more, which
+    /// amortizes the cost of calling into the PRNG over several blocks.
+    /// Whether this improves throughput depends on the PRNG: the ChaCha cores
+    /// already generate four ChaCha blocks per call, and the
+    /// `gen_u32_reseeding_*` benchmarks show no improvement for these.
+    ///
+    /// The output is identical to that of [`ReseedingRng::new`]: blocks are
+    /// never generated beyond the point where the byte threshold causes a
+    /// reseed, and the conditions for reseeding (including a fork) are still
+    /// checked for every block. When a reseed happens, buffered blocks are
+    /// discarded.
+    ///
+    /// With `blocks == 1` this is equivalent to [`ReseedingRng::new`]. Other
+    /// values make every block take the slower path which also handles the
+    /// optional reseeding policies.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `blocks` is zero.
+    pub fn with_buffer_blocks(rng: R, threshold: u64, reseeder: Rsdr, blocks: usize) -> Self {
+        assert!(blocks > 0, "ReseedingRng: blocks must be at least 1");
+        let mut core = ReseedingCore::new(rng, threshold, reseeder);
+        if blocks > 1 {
+            core.extras_mut().prefetch = Some(Prefetch::new(blocks));
+        }
+        ReseedingRng(BlockRng::new(core))
+    }
+
+    /// Create a new `ReseedingRng` which does not reseed due to the byte
+    /// threshold more often than once per `min_interval`.
+    ///
+    /// `threshold` has the same meaning as for [`ReseedingRng::new`], except
+    /// that when it is reached less than `min_interval` after the last
+    /// reseed, the PRNG is not reseeded but continues generating for another
+    /// `threshold` bytes. This limits the load on the reseeder (e.g.
+    /// [`OsRng`]) when drawing heavily with a small threshold.
+    ///
+    /// Other reseeds are not limited: after a fork, after `clone()` and on
+    /// manual calls to [`reseed()`](ReseedingRng::reseed).
+    ///
+    /// [`OsRng`]: crate::rngs::OsRng
+    pub fn with_min_interval(rng: R, threshold: u64, min_interval: Duration, reseeder: Rsdr) -> Self {
+        Self::with_min_interval_and_clock(rng, threshold, min_interval, reseeder, Instant::now)
+    }
+
+    fn with_min_interval_and_clock(
+        rng: R, threshold: u64, min_interval: Duration, reseeder: Rsdr, clock: fn() -> Instant,
+    ) -> Self {
+        let mut core = ReseedingCore::new(rng, threshold, reseeder);
+        core.extras_mut().min_interval = Some(MinInterval {
+            clock,
+            interval: min_interval,
+            last_reseed: None,
+        });
+        ReseedingRng(BlockRng::new(core))
+    }
+}
+
+impl<R, Rsdr> ReseedingRng<R, Rsdr>
+where
+    R: BlockRngCore<Item = u32> + SeedableRng,
+    Rsdr: RngCore,
+{
+    /// Create a new `ReseedingRng` which also reseeds after a randomised time
+    /// interval.
+    ///
+    /// `threshold` has the same meaning as for [`ReseedingRng::new`]. In
+    /// addition, the PRNG is reseeded when generating a block after a
+    /// deadline has passed. After each reseed (and on construction), the next
+    /// deadline is set to `interval * (1 + jitter)` from now, where `jitter`
+    /// is drawn uniformly from `[-jitter_fraction, jitter_fraction)` using
+    /// the PRNG itself. This spreads the reseeds of many processes started
+    /// at the same time, instead of them all reading entropy at once.
+    ///
+    /// As for the byte threshold, the deadline is only checked when a new
+    /// block is generated, so an idle generator is reseeded on its next use.
+    /// A deadline too far in the future to be represented (e.g. for an
+    /// `interval` of [`Duration::MAX`]) is never reached.
+    ///
+    /// The jitter is taken from an extra block of the PRNG which is then
+    /// discarded, so the output does not reveal the next deadline. This costs
+    /// one block per reseed, which is cheap compared to the reseed itself.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `jitter_fraction` is not in the range `[0, 1]`.
+    pub fn with_jittered_interval(
+        rng: R, threshold: u64, interval: Duration, jitter_fraction: f64, reseeder: Rsdr,
+    ) -> Self {
+        Self::with_interval_and_clock(rng, threshold, interval, jitter_fraction, reseeder, Instant::now)
+    }
+
+    fn with_interval_and_clock(
+        rng: R, threshold: u64, interval: Duration, jitter_fraction: f64, reseeder: Rsdr,
+        clock: fn() -> Instant,
+    ) -> Self {
+        assert!(
+            (0.0..=1.0).contains(&jitter_fraction),
+            "ReseedingRng: jitter_fraction must be in the range [0, 1]"
+        );
+        let mut core = ReseedingCore::new(rng, threshold, reseeder);
+        let now = clock();
+        let mut timer = Timer {
+            clock,
+            interval,
+            jitter_fraction,
+            draw: draw_u32::<R>,
+            deadline: None,
+        };
+        timer.deadline = timer.next_deadline(now, draw_u32(&mut core.inner));
+        core.extras_mut().timer = Some(timer);
+        ReseedingRng(BlockRng::new(core))
+    }
+}
+
+impl<R, Rsdr> ReseedingRng<R, Rsdr>
+where
+    R: BlockRngCore + SeedableRng + Clone,
+    R::Results: Clone,
+    Rsdr: RngCore,
+{
+    /// Capture the complete state of this generator, except the reseeder.
+    ///
+    /// This includes the state of the wrapped PRNG, the position within the
+    /// current block of results and the reseeding bookkeeping, such that
+    /// [`restore()`] reproduces the exact continuation of the output stream.
+    ///
+    /// Note that the snapshot contains the secret state of the PRNG.
+    ///
+    /// [`restore()`]: ReseedingRng::restore
+    pub fn snapshot(&self) -> ReseedingState<R> {
+        let core = &self.0.core;
+        ReseedingState {
+            inner: core.inner.clone(),
+            results: self.0.results().clone(),
+            index: self.0.index(),
+            prefetched: core.prefetched().to_vec(),
+            threshold: core.threshold,
+            bytes_until_reseed: core.bytes_until_reseed,
+            fork_counter: core.fork_counter,
+            consecutive_failures: core.consecutive_failures,
+        }
+    }
+
+    /// Restore a state captured with [`snapshot()`].
+    ///
+    /// The reseeder is not affected; output after the next reseed depends on
+    /// its state. If a fork happened since the snapshot was taken, the
+    /// restored generator will reseed "soon", as usual. The deadline of
+    /// time-based reseeding is not part of the state and is not affected.
+    ///
+    /// [`snapshot()`]: ReseedingRng::snapshot
+    pub fn restore(&mut self, state: ReseedingState<R>) {
+        let core = &mut self.0.core;
+        core.inner = state.inner;
+        core.threshold = state.threshold;
+        core.bytes_until_reseed = state.bytes_until_reseed;
+        core.fork_counter = state.fork_counter;
+        core.consecutive_failures = state.consecutive_failures;
+        match core.extras.as_deref_mut().and_then(|e| e.prefetch.as_mut()) {
+            Some(prefetch) => prefetch.set_buffered(state.prefetched),
+            None if !state.prefetched.is_empty() => {
+                let mut prefetch = Prefetch::new(1);
+                prefetch.set_buffered(state.prefetched);
+                core.extras_mut().prefetch = Some(prefetch);
+            }
+            None => {}
+        }
+        self.0.set_results(state.results, state.index);
+    }
+}
+
+impl<R, Rsdr> ReseedingRng<R, Rsdr>
+where
+    R: BlockRngCore + SeedableRng + SeekableRng,
+    Rsdr: RngCore,
+{
+    /// Set the stream number of the wrapped PRNG.
+    ///
+    /// Like the `set_stream` method of e.g. `ChaCha20Rng`, this keeps the
+    /// position: output continues at the same word of the new stream.
+    ///
+    /// Note that a reseed replaces the state of the wrapped PRNG, including
+    /// its stream and position: the output after a subsequent reseed (e.g.
+    /// due to the threshold or a fork) is unrelated to the stream set here.
+    pub fn set_stream(&mut self, stream: u64) {
+        let len = self.0.results().as_ref().len();
+        let unused = len - self.0.index().min(len);
+        let core = &mut self.0.core;
+        let ahead = (core.prefetched().len() * len + unused) as u128;
+        let pos = core.inner.word_pos().wrapping_sub(ahead);
+        core.inner.set_stream(stream);
+        self.set_word_pos(pos);
+    }
+
+    /// Seek the wrapped PRNG to the word at `pos` of the current stream.
+    ///
+    /// The block containing this word is generated immediately (counting
+    /// towards the threshold, but never reseeding), such that the next word
+    /// of output is the one at `pos`.
+    ///
+    /// Note that a reseed replaces the state of the wrapped PRNG, including
+    /// its stream and position: the output after a subsequent reseed (e.g.
+    /// due to the threshold or a fork) is unrelated to the position set here.
+    pub fn set_word_pos(&mut self, pos: u128) {
+        let core = &mut self.0.core;
+        if let Some(prefetch) = core.extras.as_deref_mut().and_then(|e| e.prefetch.as_mut()) {
+            prefetch.clear();
+        }
+        let index = core.inner.set_word_pos(pos);
+        let mut results = R::Results::default();
+        core.inner.generate(&mut results);
+        core.bytes_until_reseed -= size_of_val(results.as_ref()) as i64;
+        self.0.set_results(results, index);
+    }
+}
+
+/// The state of a [`ReseedingRng`], excluding its reseeder
+///
+/// Obtained from [`ReseedingRng::snapshot`] and consumed by
+/// [`ReseedingRng::restore`]. Serializable with the `serde1` feature (if the
+/// PRNG and its results are).
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde1",
+    serde(
+        bound = "for<'x> R: Serialize + Deserialize<'x>, for<'x> R::Results: Serialize + Deserialize<'x>"
+    )
+)]
+pub struct ReseedingState<R: BlockRngCore> {
+    inner: R,
+    results: R::Results,
+    index: usize,
+    #[cfg_attr(feature = "serde1", serde(default))]
+    prefetched: Vec<R::Results>,
+    threshold: i64,
+    bytes_until_reseed: i64,
+    fork_counter: usize,
+    consecutive_failures: u32,
+}
+
+/// Debug implementation does not leak internal state
+impl<R: BlockRngCore> fmt::Debug for ReseedingState<R> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "ReseedingState {{ .. }}")
+    }
+}
+
+impl<R> ReseedingRng<R, Box<dyn RngCore + Send>>
+where R: BlockRngCore + SeedableRng
+{
+    /// Create a new `ReseedingRng` with a boxed reseeder.
+    ///
+    /// This allows the reseeder to be chosen at run-time (e.g. [`OsRng`] in
+    /// production and a seeded PRNG in tests) without making its type part of
+    /// the `ReseedingRng` type.
+    ///
+    /// `threshold` has the same meaning as for [`ReseedingRng::new`].
+    ///
+    /// [`OsRng`]: crate::rngs::OsRng
+    pub fn new_boxed(rng: R, threshold: u64, reseeder: Box<dyn RngCore + Send>) -> Self {
+        ReseedingRng::new(rng, threshold, reseeder)
+    }
 }
 
 // TODO: this should be implemented for any type where the inner type
@@ -124,12 +512,59 @@ where
         self.0.next_u64()
     }
 
+    // Whole blocks are generated straight into `dest`, bypassing the results
+    // buffer of `BlockRng`; only the head (the rest of the buffered block)
+    // and the tail (less than a block) use `BlockRng::fill_bytes`. The output
+    // is identical: the same blocks are generated by `ReseedingCore` in the
+    // same order, with the same reseed checks at each block boundary.
     fn fill_bytes(&mut self, dest: &mut [u8]) {
-        self.0.fill_bytes(dest)
+        let block_words = self.0.results().as_ref().len();
+        let block_bytes = block_words * 4;
+        let buffered = (block_words - self.0.index().min(block_words)) * 4;
+        let (head, rest) = dest.split_at_mut(buffered.min(dest.len()));
+        self.0.fill_bytes(head);
+        if rest.is_empty() {
+            return;
+        }
+
+        let mut chunks = rest.chunks_exact_mut(block_bytes);
+        let mut results = R::Results::default();
+        for chunk in &mut chunks {
+            self.0.core.generate(&mut results);
+            fill_via_u32_chunks(results.as_mut(), chunk);
+        }
+        self.0.fill_bytes(chunks.into_remainder());
     }
 
     fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
-        self.0.try_fill_bytes(dest)
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl<R, Rsdr> ReseedingRng<R, Rsdr>
+where
+    R: BlockRngCore + SeedableRng + Clone,
+    R::Results: Clone,
+    Rsdr: RngCore + Clone,
+{
+    /// Clone this generator *without* reseeding the clone.
+    ///
+    /// Unlike [`clone()`](Clone::clone), which makes the clone reseed before
+    /// its first use, this copies the wrapped PRNG, its buffered results and
+    /// the reseeding bookkeeping as they are.
+    ///
+    /// # Warning
+    ///
+    /// The original and the copy produce **identical** output streams (until
+    /// either reseeds). This is not suitable for security-sensitive use; it
+    /// is intended for reproducing output, e.g. in tests. Use `clone()` to
+    /// obtain an independent generator.
+    pub fn clone_no_reseed(&self) -> Self {
+        let mut rng = self.0.clone();
+        rng.core.bytes_until_reseed = self.0.core.bytes_until_reseed;
+        rng.core.extras = self.0.core.extras.clone();
+        ReseedingRng(rng)
     }
 }
 
@@ -152,13 +587,229 @@ where
 {
 }
 
-#[derive(Debug)]
-struct ReseedingCore<R, Rsdr> {
+struct ReseedingCore<R: BlockRngCore, Rsdr> {
     inner: R,
     reseeder: Rsdr,
     threshold: i64,
     bytes_until_reseed: i64,
     fork_counter: usize,
+    consecutive_failures: u32,
+    /// Optional policies and buffering; when absent (the common case), the
+    /// default path checks nothing else per block
+    extras: Option<Box<Extras<R>>>,
+}
+
+// Not derived, which would require `R::Results: Debug`
+impl<R: BlockRngCore + fmt::Debug, Rsdr: fmt::Debug> fmt::Debug for ReseedingCore<R, Rsdr> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct("ReseedingCore")
+            .field("inner", &self.inner)
+            .field("reseeder", &self.reseeder)
+            .field("threshold", &self.threshold)
+            .field("bytes_until_reseed", &self.bytes_until_reseed)
+            .field("fork_counter", &self.fork_counter)
+            .field("consecutive_failures", &self.consecutive_failures)
+            .field("extras", &self.extras)
+            .finish()
+    }
+}
+
+/// The optional features of `ReseedingCore`
+struct Extras<R: BlockRngCore> {
+    timer: Option<Timer<R>>,
+    should_reseed: Option<fn() -> bool>,
+    min_interval: Option<MinInterval>,
+    prefetch: Option<Prefetch<R::Results>>,
+}
+
+impl<R: BlockRngCore> Extras<R> {
+    fn new() -> Self {
+        Extras {
+            timer: None,
+            should_reseed: None,
+            min_interval: None,
+            prefetch: None,
+        }
+    }
+
+    /// A copy for a clone of the generator, which reseeds on first use
+    fn clone_reseeding(&self) -> Self {
+        Extras {
+            timer: self.timer.clone(),
+            should_reseed: self.should_reseed,
+            min_interval: self.min_interval.as_ref().map(|m| MinInterval {
+                last_reseed: None,
+                ..m.clone()
+            }),
+            prefetch: self.prefetch.as_ref().map(|p| Prefetch::new(p.depth)),
+        }
+    }
+
+    /// Whether the deadline of time-based reseeding has passed
+    fn is_due(&self) -> bool {
+        match self.timer {
+            Some(Timer { clock, deadline: Some(deadline), .. }) => clock() >= deadline,
+            _ => false,
+        }
+    }
+
+    /// Whether the last reseed was less than the minimum interval ago
+    fn is_too_soon(&self) -> bool {
+        match self.min_interval {
+            Some(MinInterval { clock, interval, last_reseed: Some(last) }) => {
+                // An end beyond what `Instant` can represent is never reached
+                last.checked_add(interval).map_or(true, |end| clock() < end)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl<R: BlockRngCore> Clone for Extras<R>
+where R::Results: Clone
+{
+    fn clone(&self) -> Self {
+        Extras {
+            timer: self.timer.clone(),
+            should_reseed: self.should_reseed,
+            min_interval: self.min_interval.clone(),
+            prefetch: self.prefetch.clone(),
+        }
+    }
+}
+
+impl<R: BlockRngCore + fmt::Debug> fmt::Debug for Extras<R> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct("Extras")
+            .field("timer", &self.timer)
+            .field("should_reseed", &self.should_reseed)
+            .field("min_interval", &self.min_interval)
+            .field("prefetch", &self.prefetch)
+            .finish()
+    }
+}
+
+/// Blocks generated ahead of use, see [`ReseedingRng::with_buffer_blocks`]
+#[derive(Clone)]
+struct Prefetch<T> {
+    /// Blocks generated at a time (at least 1)
+    depth: usize,
+    /// Buffered blocks are `blocks[next..end]`
+    blocks: Vec<T>,
+    next: usize,
+    end: usize,
+}
+
+impl<T> Prefetch<T> {
+    fn new(depth: usize) -> Self {
+        Prefetch {
+            depth,
+            blocks: Vec::new(),
+            next: 0,
+            end: 0,
+        }
+    }
+
+    fn buffered(&self) -> &[T] {
+        &self.blocks[self.next..self.end]
+    }
+
+    fn set_buffered(&mut self, blocks: Vec<T>) {
+        self.end = blocks.len();
+        self.next = 0;
+        self.blocks = blocks;
+    }
+
+    fn clear(&mut self) {
+        self.next = 0;
+        self.end = 0;
+    }
+
+    /// Take the next block of `rng`, either a buffered one or a new one.
+    ///
+    /// In the latter case, further blocks are generated ahead of use, but
+    /// none past the next reseed due to the byte threshold.
+    fn generate<R>(&mut self, rng: &mut R, results: &mut T, bytes_until_reseed: i64)
+    where R: BlockRngCore<Results = T>, T: AsRef<[R::Item]> + Default {
+        if self.next < self.end {
+            // Swapping avoids a copy; the used block is overwritten later
+            core::mem::swap(results, &mut self.blocks[self.next]);
+            self.next += 1;
+            return;
+        }
+        rng.generate(results);
+
+        let num_bytes = size_of_val(results.as_ref()) as u64;
+        let until_reseed = bytes_until_reseed.max(0) as u64;
+        let until_reseed = (until_reseed + num_bytes - 1) / num_bytes;
+        let n = (self.depth - 1).min(until_reseed.min(usize::MAX as u64) as usize);
+        if self.blocks.len() < n {
+            self.blocks.resize_with(n, Default::default);
+        }
+        for block in &mut self.blocks[..n] {
+            rng.generate(block);
+        }
+        self.next = 0;
+        self.end = n;
+    }
+}
+
+/// Debug implementation does not leak internal state
+impl<T> fmt::Debug for Prefetch<T> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct("Prefetch")
+            .field("depth", &self.depth)
+            .field("available", &(self.end - self.next))
+            .finish()
+    }
+}
+
+/// Time-based reseeding: the PRNG is reseeded once `deadline` has passed.
+#[derive(Debug)]
+struct Timer<R> {
+    clock: fn() -> Instant,
+    interval: Duration,
+    jitter_fraction: f64,
+    /// Draws the jitter from the PRNG
+    draw: fn(&mut R) -> u32,
+    /// `None` if the deadline is not representable, i.e. never reached
+    deadline: Option<Instant>,
+}
+
+impl<R> Timer<R> {
+    /// The deadline for a reseed at `now`, with jitter drawn from `x`
+    fn next_deadline(&self, now: Instant, x: u32) -> Option<Instant> {
+        let u = f64::from(x) / (1u64 << 32) as f64;
+        let jitter = self.jitter_fraction * (2.0 * u - 1.0);
+        // As `Duration::mul_f64`, but without panicking on overflow
+        let secs = self.interval.as_secs_f64() * (1.0 + jitter);
+        if secs >= u64::MAX as f64 {
+            return None;
+        }
+        now.checked_add(Duration::from_secs_f64(secs))
+    }
+}
+
+impl<R> Clone for Timer<R> {
+    fn clone(&self) -> Self {
+        Timer { ..*self }
+    }
+}
+
+/// A lower bound on the time between reseeds due to the byte threshold
+#[derive(Clone, Debug)]
+struct MinInterval {
+    clock: fn() -> Instant,
+    interval: Duration,
+    /// The time of the last successful reseed, if any
+    last_reseed: Option<Instant>,
+}
+
+/// Draw a `u32` from a fresh block of `rng`, discarding the rest of the block
+fn draw_u32<R: BlockRngCore<Item = u32>>(rng: &mut R) -> u32 {
+    let mut results = R::Results::default();
+    rng.generate(&mut results);
+    results.as_ref()[0]
 }
 
 impl<R, Rsdr> BlockRngCore for ReseedingCore<R, Rsdr>
@@ -171,7 +822,10 @@ where
 
     fn generate(&mut self, results: &mut Self::Results) {
         let global_fork_counter = fork::get_fork_counter();
-        if self.bytes_until_reseed <= 0 || self.is_forked(global_fork_counter) {
+        if self.bytes_until_reseed <= 0
+            || self.is_forked(global_fork_counter)
+            || self.extras.is_some()
+        {
             // We get better performance by not calling only `reseed` here
             // and continuing with the rest of the function, but by directly
             // returning from a non-inlined function.
@@ -190,36 +844,108 @@ where
 {
     /// Create a new `ReseedingCore`.
     fn new(rng: R, threshold: u64, reseeder: Rsdr) -> Self {
-        use ::core::i64::MAX;
         fork::register_fork_handler();
+        let threshold = Self::clamp_threshold(threshold);
+
+        ReseedingCore {
+            inner: rng,
+            reseeder,
+            threshold,
+            bytes_until_reseed: threshold,
+            fork_counter: 0,
+            consecutive_failures: 0,
+            extras: None,
+        }
+    }
+
+    fn extras_mut(&mut self) -> &mut Extras<R> {
+        self.extras.get_or_insert_with(|| Box::new(Extras::new()))
+    }
+
+    /// The blocks generated ahead of use
+    fn prefetched(&self) -> &[R::Results] {
+        match self.extras.as_deref().and_then(|e| e.prefetch.as_ref()) {
+            Some(prefetch) => prefetch.buffered(),
+            None => &[],
+        }
+    }
+
+    /// Take the next block of the PRNG, either a buffered one or a new one.
+    #[inline]
+    fn generate_next(&mut self, results: &mut <Self as BlockRngCore>::Results) {
+        match self.extras.as_deref_mut().and_then(|e| e.prefetch.as_mut()) {
+            Some(prefetch) => prefetch.generate(&mut self.inner, results, self.bytes_until_reseed),
+            None => self.inner.generate(results),
+        }
+    }
+
+    /// Whether to reseed before generating the next block, given the
+    /// optional policies (without any, this is the case when called)
+    fn is_reseed_due(&mut self, global_fork_counter: usize) -> bool {
+        let forked = self.is_forked(global_fork_counter);
+        let extras = match self.extras.as_deref() {
+            Some(extras) => extras,
+            None => return true,
+        };
+        // Always consult the predicate, such that it sees every block
+        let signalled = extras.should_reseed.map_or(false, |f| f());
+        if self.bytes_until_reseed <= 0 && extras.is_too_soon() {
+            // Postpone the reseed by another `threshold` bytes
+            self.bytes_until_reseed = self.threshold;
+        }
+        // The timer is checked last, to avoid reading the clock if possible
+        self.bytes_until_reseed <= 0 || forked || signalled || extras.is_due()
+    }
+
+    fn clamp_threshold(threshold: u64) -> i64 {
+        use ::core::i64::MAX;
 
         // Because generating more values than `i64::MAX` takes centuries on
         // current hardware, we just clamp to that value.
         // Also we set a threshold of 0, which indicates no limit, to that
         // value.
-        let threshold = if threshold == 0 {
+        if threshold == 0 {
             MAX
         } else if threshold <= MAX as u64 {
             threshold as i64
         } else {
             MAX
-        };
-
-        ReseedingCore {
-            inner: rng,
-            reseeder,
-            threshold,
-            bytes_until_reseed: threshold,
-            fork_counter: 0,
         }
     }
 
+    fn set_threshold(&mut self, threshold: u64) {
+        let threshold = Self::clamp_threshold(threshold);
+        let generated = self.threshold.saturating_sub(self.bytes_until_reseed);
+        self.bytes_until_reseed = threshold.saturating_sub(generated);
+        self.threshold = threshold;
+    }
+
     /// Reseed the internal PRNG.
     fn reseed(&mut self) -> Result<(), Error> {
-        R::from_rng(&mut self.reseeder).map(|result| {
-            self.bytes_until_reseed = self.threshold;
-            self.inner = result
-        })
+        match R::from_rng(&mut self.reseeder) {
+            Ok(result) => {
+                self.bytes_until_reseed = self.threshold;
+                self.inner = result;
+                self.consecutive_failures = 0;
+                if let Some(extras) = self.extras.as_deref_mut() {
+                    if let Some(prefetch) = extras.prefetch.as_mut() {
+                        prefetch.clear();
+                    }
+                    if let Some(min_interval) = extras.min_interval.as_mut() {
+                        min_interval.last_reseed = Some((min_interval.clock)());
+                    }
+                    if let Some(timer) = extras.timer.as_mut() {
+                        let x = (timer.draw)(&mut self.inner);
+                        timer.deadline = timer.next_deadline((timer.clock)(), x);
+                    }
+                }
+                Ok(())
+            }
+            Err(e) => {
+                self.consecutive_failures = self.consecutive_failures.saturating_add(1);
+                Err(e)
+            }
+        }
     }
 
     fn is_forked(&self, global_fork_counter: usize) -> bool {
@@ -240,23 +966,46 @@ where
     fn reseed_and_generate(
         &mut self, results: &mut <Self as BlockRngCore>::Results, global_fork_counter: usize,
     ) {
-        #![allow(clippy::if_same_then_else)] // false positive
-        if self.is_forked(global_fork_counter) {
-            info!("Fork detected, reseeding RNG");
-        } else {
-            trace!("Reseeding RNG (periodic reseed)");
+        if !self.is_reseed_due(global_fork_counter) {
+            let num_bytes = size_of_val(results.as_ref());
+            self.bytes_until_reseed -= num_bytes as i64;
+            return self.generate_next(results);
+        }
+
+        #[cfg(feature = "log")]
+        {
+            if self.is_forked(global_fork_counter) {
+                info!("Fork detected, reseeding RNG");
+            } else {
+                trace!("Reseeding RNG (periodic reseed)");
+            }
         }
 
         let num_bytes = size_of_val(results.as_ref());
 
-        if let Err(e) = self.reseed() {
-            warn!("Reseeding RNG failed: {}", e);
-            let _ = e;
-        }
         self.fork_counter = global_fork_counter;
-
+        let result = self.reseed();
         self.bytes_until_reseed = self.threshold - num_bytes as i64;
-        self.inner.generate(results);
+        if result.is_err() {
+            if let Some(timer) = self.extras.as_deref_mut().and_then(|e| e.timer.as_mut()) {
+                // Retry after one interval rather than on every block
+                timer.deadline = (timer.clock)().checked_add(timer.interval);
+            }
+        }
+
+        #[cfg(feature = "log")]
+        {
+            if let Err(e) = result {
+                if self.consecutive_failures > 1 {
+                    warn!("Reseeding RNG failed {} times in a row: {}", self.consecutive_failures, e);
+                } else {
+                    warn!("Reseeding RNG failed: {}", e);
+                }
+            }
+        }
+
+        // Buffered blocks remain after a failed reseed
+        self.generate_next(results);
     }
 }
 
@@ -272,6 +1021,8 @@ where
             threshold: self.threshold,
             bytes_until_reseed: 0, // reseed clone on first use
             fork_counter: self.fork_counter,
+            consecutive_failures: self.consecutive_failures,
+            extras: self.extras.as_ref().map(|e| Box::new(e.clone_reseeding())),
         }
     }
 }
@@ -284,70 +1035,18 @@ where
 }
 
 
-#[cfg(all(unix, not(target_os = "emscripten")))]
-mod fork {
-    use core::sync::atomic::{AtomicUsize, Ordering};
-    use std::sync::Once;
-
-    // Fork protection
-    //
-    // We implement fork protection on Unix using `pthread_atfork`.
-    // When the process is forked, we increment `RESEEDING_RNG_FORK_COUNTER`.
-    // Every `ReseedingRng` stores the last known value of the static in
-    // `fork_counter`. If the cached `fork_counter` is less than
-    // `RESEEDING_RNG_FORK_COUNTER`, it is time to reseed this RNG.
-    //
-    // If reseeding fails, we don't deal with this by setting a delay, but just
-    // don't update `fork_counter`, so a reseed is attempted as soon as
-    // possible.
-
-    static RESEEDING_RNG_FORK_COUNTER: AtomicUsize = AtomicUsize::new(0);
-
-    pub fn get_fork_counter() -> usize {
-        RESEEDING_RNG_FORK_COUNTER.load(Ordering::Relaxed)
-    }
-
-    extern "C" fn fork_handler() {
-        // Note: fetch_add is defined to wrap on overflow
-        // (which is what we want).
-        RESEEDING_RNG_FORK_COUNTER.fetch_add(1, Ordering::Relaxed);
-    }
-
-    pub fn register_fork_handler() {
-        static REGISTER: Once = Once::new();
-        REGISTER.call_once(|| {
-            // Bump the counter before and after forking (see #1169):
-            let ret = unsafe { libc::pthread_atfork(
-                Some(fork_handler),
-                Some(fork_handler),
-                Some(fork_handler),
-            ) };
-            if ret != 0 {
-                panic!("libc::pthread_atfork failed with code {}", ret);
-            }
-        });
-    }
-}
-
-#[cfg(not(all(unix, not(target_os = "emscripten"))))]
-mod fork {
-    pub fn get_fork_counter() -> usize {
-        0
-    }
-    pub fn register_fork_handler() {}
-}
-
-
 #[cfg(feature = "std_rng")]
 #[cfg(test)]
 mod test {
     use super::ReseedingRng;
     use crate::rngs::mock::StepRng;
     use crate::rngs::std::Core;
-    use crate::{Rng, SeedableRng};
+    use crate::{Rng, RngCore, SeedableRng};
+    use alloc::boxed::Box;
 
     #[test]
     fn test_reseeding() {
+        let _forks = super::fork::lock_no_forks();
         let mut zero = StepRng::new(0, 0);
         let rng = Core::from_rng(&mut zero).unwrap();
         let thresh = 1; // reseed every time the buffer is exhausted
@@ -366,10 +1065,98 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_simulate_fork() {
+        let _forks = super::fork::lock_forks();
+        let rng = Core::from_rng(&mut StepRng::new(7, 0)).unwrap();
+        let mut forked = ReseedingRng::new(rng.clone(), 0, StepRng::new(1, 1));
+        let mut reference = ReseedingRng::new(rng, 0, StepRng::new(1, 1));
+        assert_eq!(forked.next_u32(), reference.next_u32());
+        let expected: [u32; 64] = reference.gen();
+
+        super::fork::simulate_fork();
+        // The rest of the buffered block (of 64 values) is unaffected; the
+        // next block is generated after reseeding
+        let output: [u32; 64] = forked.gen();
+        assert_eq!(output[..63], expected[..63]);
+        let after: [u32; 16] = forked.gen();
+        assert!(after.iter().zip(&output[63..]).all(|(a, b)| a != b));
+        // Reseeded once, drawing a 32-byte seed
+        assert_eq!(forked.0.core.reseeder.next_u64(), 1 + 4);
+    }
+
+    #[test]
+    fn test_fill_bytes_large() {
+        let _forks = super::fork::lock_no_forks();
+        let rng = Core::from_rng(&mut StepRng::new(7, 0)).unwrap();
+        // The reseeder yields a different seed on each reseed
+        let reseeder = StepRng::new(1, 1);
+        let mut whole = ReseedingRng::new(rng.clone(), 1000, reseeder.clone());
+        let mut pieces = ReseedingRng::new(rng, 1000, reseeder);
+
+        // One large fill, spanning many blocks and reseeds, matches the
+        // same output drawn in pieces of various sizes (multiples of 4 bytes,
+        // since `fill_bytes` discards the rest of a partially used word)
+        let mut a = alloc::vec![0u8; 20_000];
+        whole.fill_bytes(&mut a);
+        let mut b = alloc::vec![0u8; 20_000];
+        let mut pos = 0;
+        for &len in [4, 256, 8, 5000, 252, 4096, 260].iter().cycle() {
+            let end = (pos + len).min(b.len());
+            if len == 4 {
+                b[pos..end].copy_from_slice(&pieces.next_u32().to_le_bytes());
+            } else {
+                pieces.fill_bytes(&mut b[pos..end]);
+            }
+            pos = end;
+            if pos == b.len() {
+                break;
+            }
+        }
+        assert_eq!(a, b);
+        assert_eq!(whole.next_u64(), pieces.next_u64());
+    }
+
+    #[test]
+    fn test_fill_bytes_fast_path() {
+        let _forks = super::fork::lock_forks();
+        let rng = Core::from_rng(&mut StepRng::new(7, 0)).unwrap();
+        for &blocks in [1, 3].iter() {
+            let new = || {
+                ReseedingRng::with_buffer_blocks(rng.clone(), 1000, StepRng::new(1, 1), blocks)
+            };
+            let (mut fast, mut slow) = (new(), new());
+            // Various start offsets within a block (in words) and lengths
+            // around the block size of 256 bytes
+            for &(skip, len) in [(0, 0), (0, 256), (1, 256), (63, 257), (5, 3), (0, 10_000),
+                (17, 1023), (64, 512), (2, 4096)].iter()
+            {
+                for _ in 0..skip {
+                    assert_eq!(fast.next_u32(), slow.next_u32());
+                }
+                if len == 4096 {
+                    // Fork detection at a block boundary
+                    super::fork::simulate_fork();
+                }
+                let mut a = alloc::vec![0u8; len];
+                let mut b = alloc::vec![0u8; len];
+                fast.fill_bytes(&mut a);
+                slow.0.fill_bytes(&mut b);
+                assert_eq!(a, b);
+                assert_eq!(fast.0.index(), slow.0.index());
+                assert_eq!(fast.0.core.bytes_until_reseed, slow.0.core.bytes_until_reseed);
+            }
+            assert_eq!(fast.0.core.reseeder.next_u64(), slow.0.core.reseeder.next_u64());
+            assert_eq!(fast.next_u64(), slow.next_u64());
+        }
+    }
+
     #[test]
     fn test_clone_reseeding() {
         #![allow(clippy::redundant_clone)]
 
+        let _forks = super::fork::lock_no_forks();
+
         let mut zero = StepRng::new(0, 0);
         let rng = Core::from_rng(&mut zero).unwrap();
         let mut rng1 = ReseedingRng::new(rng, 32 * 4, zero);
@@ -382,4 +1169,610 @@ mod test {
         let mut rng2 = rng1.clone();
         assert_eq!(first, rng2.gen::<u32>());
     }
+
+    #[test]
+    fn test_clone_no_reseed() {
+        let _forks = super::fork::lock_no_forks();
+        let rng = Core::from_rng(&mut StepRng::new(7, 0)).unwrap();
+        let mut rng1 = ReseedingRng::new(rng, 1 << 20, StepRng::new(1, 1));
+        // Start within a block
+        for _ in 0..10 {
+            let _ = rng1.gen::<u32>();
+        }
+
+        let mut copy = rng1.clone_no_reseed();
+        let mut clone = rng1.clone();
+        let expected: [u32; 100] = rng1.gen();
+        let output: [u32; 100] = copy.gen();
+        assert_eq!(output[..], expected[..]);
+        let output: [u32; 100] = clone.gen();
+        assert!(output.iter().zip(expected.iter()).all(|(a, b)| a != b));
+    }
+
+    #[test]
+    fn test_seek() {
+        use rand_chacha::{ChaCha20Core, ChaCha20Rng};
+
+        let _forks = super::fork::lock_no_forks();
+
+        let seed = [5; 32];
+        let mut reference = ChaCha20Rng::from_seed(seed);
+        for &blocks in [1, 3].iter() {
+            let core = ChaCha20Core::from_seed(seed);
+            let mut rng = ReseedingRng::with_buffer_blocks(core, 0, StepRng::new(1, 1), blocks);
+            for &pos in [0, 1, 63, 64, 1000, 12345678901234567].iter() {
+                rng.set_word_pos(pos);
+                reference.set_word_pos(pos);
+                let expected: [u32; 100] = reference.gen();
+                let output: [u32; 100] = rng.gen();
+                assert_eq!(output[..], expected[..]);
+            }
+
+            // The position is kept, both within a block and at a boundary
+            rng.set_word_pos(70);
+            reference.set_word_pos(70);
+            for &(words, stream) in [(5, 7), (9, 1 << 60), (64, 0)].iter() {
+                for _ in 0..words {
+                    assert_eq!(rng.next_u32(), reference.next_u32());
+                }
+                rng.set_stream(stream);
+                reference.set_stream(stream);
+                let expected: [u32; 100] = reference.gen();
+                let output: [u32; 100] = rng.gen();
+                assert_eq!(output[..], expected[..]);
+            }
+        }
+
+        // A reseed replaces the stream and position (after the buffered
+        // block of 64 words)
+        let core = ChaCha20Core::from_seed(seed);
+        let mut rng = ReseedingRng::new(core, 0, StepRng::new(1, 1));
+        rng.set_stream(3);
+        rng.set_word_pos(16);
+        rng.reseed().unwrap();
+        reference.set_stream(3);
+        reference.set_word_pos(16);
+        let expected: [u32; 64] = reference.gen();
+        let output: [u32; 64] = rng.gen();
+        assert_eq!(output[..], expected[..]);
+        let expected: [u32; 100] = reference.gen();
+        let output: [u32; 100] = rng.gen();
+        assert!(output.iter().zip(expected.iter()).all(|(a, b)| a != b));
+    }
+
+    #[test]
+    fn test_buffer_blocks() {
+        let _forks = super::fork::lock_forks();
+        let rng = Core::from_rng(&mut StepRng::new(7, 0)).unwrap();
+        // Thresholds of 3 blocks (of 256 bytes) and a non-multiple thereof
+        for &threshold in [3 * 256, 1000, 0].iter() {
+            for &blocks in [1, 2, 3, 8].iter() {
+                let mut expected = ReseedingRng::new(rng.clone(), threshold, StepRng::new(1, 1));
+                let mut buffered =
+                    ReseedingRng::with_buffer_blocks(rng.clone(), threshold, StepRng::new(1, 1), blocks);
+                for i in 0..40 {
+                    if i == 25 {
+                        // Buffered blocks are discarded on fork
+                        super::fork::simulate_fork();
+                    }
+                    let a: [u32; 100] = expected.gen();
+                    let b: [u32; 100] = buffered.gen();
+                    assert_eq!(a[..], b[..]);
+                }
+            }
+        }
+
+        // A single block needs no extra bookkeeping
+        let single = ReseedingRng::with_buffer_blocks(rng.clone(), 1000, StepRng::new(1, 1), 1);
+        assert!(single.0.core.extras.is_none());
+
+        // Blocks are not generated past a reseed
+        let mut buffered = ReseedingRng::with_buffer_blocks(rng.clone(), 1000, StepRng::new(1, 1), 8);
+        buffered.next_u32();
+        assert_eq!(buffered.0.core.prefetched().len(), 3);
+
+        // Buffered blocks are part of a snapshot
+        let mut other = ReseedingRng::new(rng, 1000, StepRng::new(1, 1));
+        other.restore(buffered.snapshot());
+        let expected: [u32; 32] = buffered.gen();
+        assert_eq!(other.gen::<[u32; 32]>(), expected);
+        for _ in 0..200 {
+            assert_eq!(other.next_u32(), buffered.next_u32());
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_buffer_blocks_zero() {
+        let rng = Core::from_rng(&mut StepRng::new(7, 0)).unwrap();
+        ReseedingRng::with_buffer_blocks(rng, 0, StepRng::new(1, 1), 0);
+    }
+
+    #[test]
+    fn test_new_boxed() {
+        let _forks = super::fork::lock_no_forks();
+        let mut zero = StepRng::new(0, 0);
+        let rng = Core::from_rng(&mut zero).unwrap();
+        let reseeder: Box<dyn RngCore + Send> = Box::new(zero.clone());
+        let mut boxed = ReseedingRng::new_boxed(rng.clone(), 32 * 4, reseeder);
+        let mut plain = ReseedingRng::new(rng, 32 * 4, zero);
+
+        for _ in 0..100 {
+            assert_eq!(boxed.gen::<u32>(), plain.gen::<u32>());
+        }
+    }
+
+    #[test]
+    fn test_new_reseeded() {
+        let _forks = super::fork::lock_no_forks();
+        let mut zero = StepRng::new(0, 0);
+        let reseeded_core = Core::from_rng(&mut zero).unwrap();
+        let mut expected = ReseedingRng::new(reseeded_core, 0, zero.clone());
+
+        // The initial state is discarded in favour of one from the reseeder
+        let rng = Core::from_rng(StepRng::new(1, 1)).unwrap();
+        let mut reseeding = ReseedingRng::new_reseeded(rng, 0, zero).unwrap();
+        for _ in 0..100 {
+            assert_eq!(reseeding.gen::<u32>(), expected.gen::<u32>());
+        }
+    }
+
+    #[test]
+    fn test_reseed_failure_counter() {
+        use core::cell::Cell;
+        use rand_core::{impls, Error};
+        use std::time::{Duration, Instant};
+
+        let _forks = super::fork::lock_no_forks();
+
+        // Fails to fill bytes the given number of times, then yields zeros
+        struct FailingRng(u32);
+        impl RngCore for FailingRng {
+            fn next_u32(&mut self) -> u32 {
+                impls::next_u32_via_fill(self)
+            }
+            fn next_u64(&mut self) -> u64 {
+                impls::next_u64_via_fill(self)
+            }
+            fn fill_bytes(&mut self, dest: &mut [u8]) {
+                self.try_fill_bytes(dest).unwrap()
+            }
+            fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+                if self.0 > 0 {
+                    self.0 -= 1;
+                    return Err(Error::new("reseeder failure"));
+                }
+                dest.iter_mut().for_each(|x| *x = 0);
+                Ok(())
+            }
+        }
+
+        // Generate one block of 64 `u32` values (256 bytes)
+        fn next_block<R: RngCore>(rng: &mut R) {
+            for _ in 0..64 {
+                rng.next_u32();
+            }
+        }
+
+        let mut zero = StepRng::new(0, 0);
+        let rng = Core::from_rng(&mut zero).unwrap();
+        let mut reseeding = ReseedingRng::new(rng, 4 * 256, FailingRng(20));
+        assert_eq!(reseeding.consecutive_reseed_failures(), 0);
+
+        for _ in 0..4 {
+            next_block(&mut reseeding);
+        }
+        assert_eq!(reseeding.consecutive_reseed_failures(), 0);
+
+        // Failures are retried once per `threshold` bytes
+        for i in 1..=3 {
+            for _ in 0..4 {
+                next_block(&mut reseeding);
+                assert_eq!(reseeding.consecutive_reseed_failures(), i);
+            }
+        }
+
+        // A manual reseed also counts; success resets the count
+        for i in 4..=20 {
+            assert!(reseeding.reseed().is_err());
+            assert_eq!(reseeding.consecutive_reseed_failures(), i);
+        }
+        assert!(reseeding.reseed().is_ok());
+        assert_eq!(reseeding.consecutive_reseed_failures(), 0);
+
+        // With time-based reseeding, failures are retried once per interval
+        std::thread_local! {
+            static NOW: Cell<Instant> = Cell::new(Instant::now());
+        }
+        fn mock_clock() -> Instant {
+            NOW.with(Cell::get)
+        }
+        fn advance(by: Duration) {
+            NOW.with(|now| now.set(now.get() + by));
+        }
+        let interval = Duration::from_secs(100);
+        let rng = Core::from_rng(&mut zero).unwrap();
+        let mut timed =
+            ReseedingRng::with_interval_and_clock(rng, 0, interval, 0.0, FailingRng(2), mock_clock);
+        for i in 1..=2 {
+            advance(interval);
+            for _ in 0..4 {
+                next_block(&mut timed);
+                assert_eq!(timed.consecutive_reseed_failures(), i);
+            }
+        }
+        advance(interval);
+        next_block(&mut timed);
+        assert_eq!(timed.consecutive_reseed_failures(), 0);
+
+        // Retrying with an interval past the representable deadline does not panic
+        let rng = Core::from_rng(&mut zero).unwrap();
+        let mut timed = ReseedingRng::with_jittered_interval(rng, 256, Duration::MAX, 0.5, FailingRng(20));
+        for _ in 0..12 {
+            next_block(&mut timed);
+        }
+        assert_eq!(timed.consecutive_reseed_failures(), 11);
+        assert!(timed.0.core.extras.as_ref().unwrap().timer.as_ref().unwrap().deadline.is_none());
+    }
+
+    #[test]
+    fn test_jittered_interval() {
+        use core::cell::Cell;
+        use std::time::{Duration, Instant};
+
+        let _forks = super::fork::lock_no_forks();
+
+        std::thread_local! {
+            static START: Instant = Instant::now();
+            static ELAPSED: Cell<Duration> = Cell::new(Duration::ZERO);
+        }
+        fn mock_clock() -> Instant {
+            START.with(|start| *start + ELAPSED.with(Cell::get))
+        }
+        fn advance(by: Duration) {
+            ELAPSED.with(|elapsed| elapsed.set(elapsed.get() + by));
+        }
+
+        let interval = Duration::from_secs(100);
+        let rng = Core::from_rng(&mut StepRng::new(0, 0)).unwrap();
+        let mut reseeding = ReseedingRng::with_interval_and_clock(
+            rng,
+            0,
+            interval,
+            0.25,
+            StepRng::new(1, 1),
+            mock_clock,
+        );
+        let deadline =
+            |r: &ReseedingRng<Core, StepRng>| r.0.core.extras.as_ref().unwrap().timer.as_ref().unwrap().deadline.unwrap();
+
+        // Successive intervals vary within `interval * [0.75, 1.25]`
+        let mut intervals = std::vec::Vec::new();
+        for _ in 0..100 {
+            let next = deadline(&reseeding) - mock_clock();
+            assert!(next >= interval.mul_f64(0.75) && next <= interval.mul_f64(1.25), "{:?}", next);
+            intervals.push(next);
+
+            // No reseed before the deadline ...
+            advance(next - Duration::from_millis(1));
+            reseeding.0.generate_and_set(0);
+            assert_eq!(deadline(&reseeding) - mock_clock(), Duration::from_millis(1));
+            // ... but once it has passed
+            advance(Duration::from_millis(1));
+            reseeding.0.generate_and_set(0);
+        }
+        intervals.sort();
+        intervals.dedup();
+        assert!(intervals.len() > 90);
+        let spread = intervals[intervals.len() - 1] - intervals[0];
+        assert!(spread > interval / 3, "{:?}", spread);
+
+        // Without jitter, the interval is fixed
+        let rng = Core::from_rng(&mut StepRng::new(0, 0)).unwrap();
+        let mut fixed =
+            ReseedingRng::with_interval_and_clock(rng, 0, interval, 0.0, StepRng::new(1, 1), mock_clock);
+        for _ in 0..10 {
+            assert_eq!(deadline(&fixed) - mock_clock(), interval);
+            advance(interval);
+            fixed.0.generate_and_set(0);
+        }
+    }
+
+    #[test]
+    fn test_min_interval() {
+        use core::cell::Cell;
+        use std::time::{Duration, Instant};
+
+        let _forks = super::fork::lock_forks();
+
+        std::thread_local! {
+            static START: Instant = Instant::now();
+            static ELAPSED: Cell<Duration> = Cell::new(Duration::ZERO);
+            static CALLS: Cell<usize> = Cell::new(0);
+        }
+        fn mock_clock() -> Instant {
+            CALLS.with(|calls| calls.set(calls.get() + 1));
+            START.with(|start| *start + ELAPSED.with(Cell::get))
+        }
+        fn advance(by: Duration) {
+            ELAPSED.with(|elapsed| elapsed.set(elapsed.get() + by));
+        }
+        // Each reseed draws a 32-byte seed, i.e. 4 values of the reseeder
+        let reseeds = |r: &ReseedingRng<Core, StepRng>| (r.0.core.reseeder.clone().next_u64() - 1) / 4;
+
+        let min_interval = Duration::from_secs(10);
+        let rng = Core::from_rng(&mut StepRng::new(0, 0)).unwrap();
+        // Reseed after every block (of 256 bytes), but at most every 10s
+        let mut reseeding = ReseedingRng::with_min_interval_and_clock(
+            rng,
+            256,
+            min_interval,
+            StepRng::new(1, 1),
+            mock_clock,
+        );
+        reseeding.0.generate_and_set(0);
+        assert_eq!(reseeds(&reseeding), 0);
+        reseeding.0.generate_and_set(0);
+        assert_eq!(reseeds(&reseeding), 1);
+
+        for _ in 0..3 {
+            // Further reseeds due to the threshold are suppressed ...
+            for _ in 0..100 {
+                advance(Duration::from_millis(99));
+                reseeding.0.generate_and_set(0);
+            }
+            let n = reseeds(&reseeding);
+            // ... until the minimum interval has passed
+            advance(Duration::from_millis(100));
+            reseeding.0.generate_and_set(0);
+            assert_eq!(reseeds(&reseeding), n + 1);
+        }
+        assert_eq!(reseeds(&reseeding), 4);
+
+        // Other reseeds are not limited
+        super::fork::simulate_fork();
+        reseeding.0.generate_and_set(0);
+        assert_eq!(reseeds(&reseeding), 5);
+        reseeding.reseed().unwrap();
+        assert_eq!(reseeds(&reseeding), 6);
+        let mut clone = reseeding.clone();
+        clone.0.generate_and_set(0);
+        assert_eq!(reseeds(&clone), 7);
+        reseeding.0.generate_and_set(0);
+        assert_eq!(reseeds(&reseeding), 6);
+
+        // An unrepresentable minimum interval suppresses all later reseeds
+        let rng = Core::from_rng(&mut StepRng::new(0, 0)).unwrap();
+        let mut forever = ReseedingRng::with_min_interval_and_clock(
+            rng,
+            256,
+            Duration::MAX,
+            StepRng::new(1, 1),
+            mock_clock,
+        );
+        for _ in 0..10 {
+            forever.0.generate_and_set(0);
+        }
+        assert_eq!(reseeds(&forever), 1);
+
+        // The clock is only read once the threshold is reached
+        let rng = Core::from_rng(&mut StepRng::new(0, 0)).unwrap();
+        let mut lazy = ReseedingRng::with_min_interval_and_clock(
+            rng,
+            256 * 10,
+            min_interval,
+            StepRng::new(1, 1),
+            mock_clock,
+        );
+        // Reseeds due to the earlier fork
+        lazy.0.generate_and_set(0);
+        CALLS.with(|calls| calls.set(0));
+        for _ in 0..9 {
+            lazy.0.generate_and_set(0);
+        }
+        assert_eq!(CALLS.with(Cell::get), 0);
+        lazy.0.generate_and_set(0);
+        assert_ne!(CALLS.with(Cell::get), 0);
+    }
+
+    #[test]
+    fn test_jittered_interval_max() {
+        use std::time::Duration;
+
+        let _forks = super::fork::lock_no_forks();
+
+        // An interval too long for the deadline to be represented never expires
+        for &jitter_fraction in &[0.0, 0.5, 1.0] {
+            let rng = Core::from_rng(&mut StepRng::new(0, 0)).unwrap();
+            let mut reseeding = ReseedingRng::with_jittered_interval(
+                rng,
+                256,
+                Duration::MAX,
+                jitter_fraction,
+                StepRng::new(1, 1),
+            );
+            for _ in 0..10 {
+                reseeding.0.generate_and_set(0);
+            }
+            assert!(reseeding.0.core.extras.as_ref().unwrap().timer.as_ref().unwrap().deadline.is_none());
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_jittered_interval_invalid() {
+        let rng = Core::from_rng(&mut StepRng::new(0, 0)).unwrap();
+        let interval = std::time::Duration::from_secs(1);
+        ReseedingRng::with_jittered_interval(rng, 0, interval, 1.5, StepRng::new(0, 0));
+    }
+
+    #[test]
+    fn test_buffer_position() {
+        let _forks = super::fork::lock_no_forks();
+        let rng = Core::from_rng(&mut StepRng::new(0, 0)).unwrap();
+        let mut reseeding = ReseedingRng::new(rng, 2 * 256, StepRng::new(0, 0));
+        // Nothing generated yet
+        assert_eq!(reseeding.buffer_position(), 64);
+        reseeding.next_u32();
+        assert_eq!(reseeding.buffer_position(), 1);
+        reseeding.next_u64();
+        assert_eq!(reseeding.buffer_position(), 3);
+        for _ in 3..64 {
+            reseeding.next_u32();
+        }
+        assert_eq!(reseeding.buffer_position(), 64);
+
+        // A reseed happens when generating the block after the threshold
+        reseeding.next_u32();
+        assert_eq!(reseeding.buffer_position(), 1);
+        assert_eq!(reseeding.0.core.bytes_until_reseed, 0);
+        for _ in 1..64 {
+            reseeding.next_u32();
+        }
+        reseeding.next_u32();
+        assert_eq!(reseeding.buffer_position(), 1);
+        assert_eq!(reseeding.0.core.bytes_until_reseed, 2 * 256 - 256);
+    }
+
+    #[test]
+    fn test_should_reseed() {
+        use core::cell::Cell;
+
+        let _forks = super::fork::lock_no_forks();
+
+        std::thread_local! {
+            static SIGNAL: Cell<bool> = Cell::new(false);
+            static CALLS: Cell<u32> = Cell::new(0);
+        }
+        fn signalled() -> bool {
+            CALLS.with(|calls| calls.set(calls.get() + 1));
+            SIGNAL.with(|signal| signal.replace(false))
+        }
+
+        let rng = Core::from_rng(&mut StepRng::new(0, 0)).unwrap();
+        // Each reseed yields a different seed
+        let mut reseeding = ReseedingRng::with_should_reseed(rng, StepRng::new(1, 1), signalled);
+        assert_eq!(reseeding.threshold(), 0);
+
+        let mut block = [0u32; 64];
+        reseeding.fill(&mut block[..]);
+        assert_eq!(CALLS.with(Cell::get), 1);
+        let reference = Core::from_rng(&mut StepRng::new(0, 0)).unwrap();
+        let mut reference = ReseedingRng::new(reference, 0, StepRng::new(0, 0));
+        let mut expected = [0u32; 64];
+        reference.fill(&mut expected[..]);
+        // No reseed without a signal
+        assert_eq!(block, expected);
+
+        // The signal triggers one reseed when generating the next block
+        SIGNAL.with(|signal| signal.set(true));
+        reseeding.fill(&mut block[..]);
+        assert_eq!(CALLS.with(Cell::get), 2);
+        assert!(!SIGNAL.with(Cell::get));
+        // The reseeder was used once, yielding four words for a 32-byte seed
+        let seed = reseeding.0.core.reseeder.next_u64();
+        assert_eq!(seed, 1 + 4);
+
+        reseeding.fill(&mut block[..]);
+        assert_eq!(CALLS.with(Cell::get), 3);
+        assert_eq!(reseeding.0.core.reseeder.next_u64(), seed + 1);
+    }
+
+    #[test]
+    fn test_set_threshold() {
+        let _forks = super::fork::lock_no_forks();
+        let mut zero = StepRng::new(0, 0);
+        let rng = Core::from_rng(&mut zero).unwrap();
+        let mut reseeding = ReseedingRng::new(rng, 0, zero);
+        assert_eq!(reseeding.threshold(), 0);
+        reseeding.set_threshold(1024);
+        assert_eq!(reseeding.threshold(), 1024);
+        assert_eq!(reseeding.0.core.bytes_until_reseed, 1024);
+
+        // Generate two blocks (512 bytes), then lower the threshold
+        for _ in 0..2 * 64 {
+            reseeding.next_u32();
+        }
+        assert_eq!(reseeding.0.core.bytes_until_reseed, 512);
+        reseeding.set_threshold(256);
+        assert_eq!(reseeding.0.core.bytes_until_reseed, -256);
+        reseeding.set_threshold(2048);
+        assert_eq!(reseeding.0.core.bytes_until_reseed, 1536);
+
+        reseeding.set_threshold(0);
+        assert_eq!(reseeding.threshold(), 0);
+        assert_eq!(reseeding.0.core.bytes_until_reseed, i64::MAX - 512);
+        reseeding.set_threshold(u64::MAX);
+        assert_eq!(reseeding.threshold(), 0);
+    }
+
+    #[test]
+    fn test_snapshot_restore() {
+        let _forks = super::fork::lock_no_forks();
+        let mut zero = StepRng::new(0, 0);
+        let rng = Core::from_rng(&mut zero).unwrap();
+        let mut reseeding = ReseedingRng::new(rng, 4 * 256, StepRng::new(5, 0));
+
+        // Snapshot mid-block, shortly before the first reseed. The reseeder is
+        // constant, thus the continuation does not depend on its state.
+        for _ in 0..(3 * 64 + 17) {
+            reseeding.next_u32();
+        }
+        let state = reseeding.snapshot();
+        let mut expected = [0u32; 200];
+        reseeding.fill(&mut expected[..]);
+
+        reseeding.restore(state.clone());
+        let mut buf = [0u32; 200];
+        reseeding.fill(&mut buf[..]);
+        assert_eq!(buf, expected);
+
+        // Restore into another instance with a different state
+        let mut other = ReseedingRng::new(Core::from_rng(&mut zero).unwrap(), 0, StepRng::new(5, 0));
+        other.gen::<u64>();
+        other.restore(state);
+        let mut buf = [0u32; 200];
+        other.fill(&mut buf[..]);
+        assert_eq!(buf, expected);
+        assert_eq!(std::format!("{:?}", other.snapshot()), "ReseedingState { .. }");
+    }
+
+    #[cfg(feature = "serde1")]
+    #[test]
+    fn test_snapshot_serde() {
+        use rand_core::block::BlockRngCore;
+        use serde::{Serialize, Deserialize};
+
+        let _forks = super::fork::lock_no_forks();
+
+        #[derive(Clone, Serialize, Deserialize)]
+        struct Counter(u32);
+        impl BlockRngCore for Counter {
+            type Item = u32;
+            type Results = [u32; 16];
+            fn generate(&mut self, results: &mut Self::Results) {
+                for r in results {
+                    self.0 = self.0.wrapping_mul(2891336453).wrapping_add(1);
+                    *r = self.0;
+                }
+            }
+        }
+        impl SeedableRng for Counter {
+            type Seed = [u8; 4];
+            fn from_seed(seed: Self::Seed) -> Self {
+                Counter(u32::from_le_bytes(seed))
+            }
+        }
+
+        let mut reseeding = ReseedingRng::new(Counter(1), 100, StepRng::new(1, 1));
+        for _ in 0..21 {
+            reseeding.next_u32();
+        }
+        let encoded = bincode::serialize(&reseeding.snapshot()).unwrap();
+        let expected: [u32; 50] = reseeding.gen();
+
+        let mut reseeding = ReseedingRng::new(Counter(7), 100, StepRng::new(1, 1));
+        reseeding.restore(bincode::deserialize(&encoded).unwrap());
+        assert_eq!(reseeding.gen::<[u32; 50]>(), expected);
+    }
 }
@@ -0,0 +1,159 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A generator shared between threads.
+
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use rand_core::{CryptoRng, Error, RngCore};
+
+/// A handle to a generator shared between threads.
+///
+/// The generator is protected by a [`Mutex`]; each method of [`RngCore`]
+/// locks it for the duration of the call. Cloning a `SharedRng` yields
+/// another handle to the *same* generator, which may be sent to another
+/// thread, such that all handles draw from a single stream.
+///
+/// Every call takes the lock, thus this is slower than a thread-local
+/// generator such as [`ThreadRng`], especially under contention from many
+/// threads, and the interleaving of output between threads depends on
+/// scheduling. Prefer [`ThreadRng`] unless a single generator must be shared,
+/// and prefer [`Rng::fill`] or [`RngCore::fill_bytes`] over many small calls
+/// to reduce locking.
+///
+/// If a thread panics while drawing from the generator, other handles remain
+/// usable (the lock is not considered poisoned).
+///
+/// # Example
+///
+/// ```
+/// use rand::rngs::adapter::SharedRng;
+/// use rand::rngs::StdRng;
+/// use rand::{Rng, SeedableRng};
+///
+/// let rng = SharedRng::new(StdRng::seed_from_u64(7));
+/// let workers: Vec<_> = (0..4)
+///     .map(|_| {
+///         let mut rng = rng.clone();
+///         std::thread::spawn(move || rng.gen::<u32>())
+///     })
+///     .collect();
+/// for worker in workers {
+///     println!("{}", worker.join().unwrap());
+/// }
+/// ```
+///
+/// [`ThreadRng`]: crate::rngs::ThreadRng
+/// [`Rng::fill`]: crate::Rng::fill
+#[derive(Debug)]
+pub struct SharedRng<R> {
+    inner: Arc<Mutex<R>>,
+}
+
+impl<R: RngCore> SharedRng<R> {
+    /// Wrap `rng`, to be shared by clones of the result.
+    pub fn new(rng: R) -> Self {
+        SharedRng {
+            inner: Arc::new(Mutex::new(rng)),
+        }
+    }
+
+    #[inline]
+    fn lock(&self) -> MutexGuard<'_, R> {
+        self.inner.lock().unwrap_or_else(|err| err.into_inner())
+    }
+}
+
+impl<R> Clone for SharedRng<R> {
+    /// Another handle to the same generator
+    fn clone(&self) -> Self {
+        SharedRng {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<R: RngCore> RngCore for SharedRng<R> {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        self.lock().next_u32()
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.lock().next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.lock().fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.lock().try_fill_bytes(dest)
+    }
+}
+
+impl<R: CryptoRng> CryptoRng for SharedRng<R> {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::rngs::mock::StepRng;
+    use std::vec::Vec;
+
+    #[test]
+    fn test_shared_rng_threads() {
+        const THREADS: u64 = 4;
+        const DRAWS: u64 = 1000;
+
+        // Each draw yields the next integer, so lost or repeated draws show
+        let rng = SharedRng::new(StepRng::new(0, 1));
+        let workers: Vec<_> = (0..THREADS)
+            .map(|_| {
+                let mut rng = rng.clone();
+                std::thread::spawn(move || (0..DRAWS).map(|_| rng.next_u64()).collect::<Vec<_>>())
+            })
+            .collect();
+        let mut values: Vec<u64> = workers
+            .into_iter()
+            .flat_map(|worker| worker.join().unwrap())
+            .collect();
+
+        values.sort_unstable();
+        assert!(values.iter().copied().eq(0..THREADS * DRAWS));
+        let mut rng = rng;
+        assert_eq!(rng.next_u64(), THREADS * DRAWS);
+    }
+
+    #[test]
+    fn test_shared_rng_poison() {
+        struct PanicRng;
+        impl RngCore for PanicRng {
+            fn next_u32(&mut self) -> u32 {
+                panic!("next_u32")
+            }
+            fn next_u64(&mut self) -> u64 {
+                7
+            }
+            fn fill_bytes(&mut self, _: &mut [u8]) {}
+            fn try_fill_bytes(&mut self, _: &mut [u8]) -> Result<(), Error> {
+                Ok(())
+            }
+        }
+
+        let rng = SharedRng::new(PanicRng);
+        let mut other = rng.clone();
+        let result = std::thread::spawn(move || {
+            let mut rng = rng;
+            rng.next_u32()
+        })
+        .join();
+        assert!(result.is_err());
+        assert_eq!(other.next_u64(), 7);
+    }
+}
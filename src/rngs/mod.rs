@@ -65,6 +65,9 @@
 //! -   [`SmallRng`] is an **insecure** PRNG designed to be fast, simple, require
 //!     little memory, and have good output quality.
 //!
+//! Additionally, [`TeeRng`] wraps another generator to record its output and
+//! replay it, e.g. to use common random numbers in paired experiments.
+//!
 //! The algorithms selected for [`StdRng`] and [`SmallRng`] may change in any
 //! release and may be platform-dependent, therefore they should be considered
 //! **not reproducible**.
@@ -102,18 +105,27 @@
 pub mod mock; // Public so we don't export `StepRng` directly, making it a bit
               // more clear it is intended for testing.
 
-#[cfg(all(feature = "small_rng", target_pointer_width = "64"))]
-mod xoshiro256plusplus;
-#[cfg(all(feature = "small_rng", not(target_pointer_width = "64")))]
-mod xoshiro128plusplus;
+#[cfg(feature = "small_rng")] mod xoshiro256plusplus;
+#[cfg(feature = "small_rng")] mod xoshiro128plusplus;
 #[cfg(feature = "small_rng")] mod small;
+#[cfg(feature = "alloc")] mod registry;
+pub(crate) mod splitmix;
+#[cfg(feature = "alloc")] mod tee;
 
 #[cfg(feature = "std_rng")] mod std;
 #[cfg(all(feature = "std", feature = "std_rng"))] pub(crate) mod thread;
 
-#[cfg(feature = "small_rng")] pub use self::small::SmallRng;
+#[cfg(feature = "small_rng")]
+pub use self::small::{Algorithm as SmallRngAlgorithm, PortableSmallRng, SmallRng};
 #[cfg(feature = "std_rng")] pub use self::std::StdRng;
-#[cfg(all(feature = "std", feature = "std_rng"))] pub use self::thread::ThreadRng;
+#[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+#[cfg(feature = "alloc")] pub use self::registry::{by_name, UnknownRngError};
+#[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+#[cfg(feature = "alloc")] pub use self::tee::TeeRng;
+#[cfg(all(feature = "std", feature = "std_rng"))]
+pub use self::thread::{set_thread_rng_reseed_threshold, thread_rng_reseed_threshold, ThreadRng};
+#[cfg(feature = "thread_rng_override")]
+pub use self::thread::{override_thread_rng, ThreadRngOverrideGuard};
 
 #[cfg_attr(doc_cfg, doc(cfg(feature = "getrandom")))]
 #[cfg(feature = "getrandom")] pub use rand_core::OsRng;
@@ -0,0 +1,173 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Selection of a bundled generator by name.
+
+use alloc::boxed::Box;
+use alloc::string::String;
+use core::fmt;
+use rand_core::RngCore;
+
+type Constructor = fn(&[u8]) -> Box<dyn RngCore + Send>;
+
+const GENERATORS: &[(&str, Constructor)] = &[
+    #[cfg(feature = "std_rng")]
+    ("chacha8", new_boxed::<rand_chacha::ChaCha8Rng>),
+    #[cfg(feature = "std_rng")]
+    ("chacha12", new_boxed::<rand_chacha::ChaCha12Rng>),
+    #[cfg(feature = "std_rng")]
+    ("chacha20", new_boxed::<rand_chacha::ChaCha20Rng>),
+    #[cfg(feature = "small_rng")]
+    ("xoshiro128plusplus", new_boxed::<super::xoshiro128plusplus::Xoshiro128PlusPlus>),
+    #[cfg(feature = "small_rng")]
+    ("xoshiro256plusplus", new_boxed::<super::xoshiro256plusplus::Xoshiro256PlusPlus>),
+];
+
+#[cfg(any(feature = "std_rng", feature = "small_rng"))]
+fn new_boxed<R>(seed: &[u8]) -> Box<dyn RngCore + Send>
+where
+    R: RngCore + rand_core::SeedableRng + Send + 'static,
+{
+    let mut full = R::Seed::default();
+    let buf = full.as_mut();
+    let n = seed.len().min(buf.len());
+    buf[..n].copy_from_slice(&seed[..n]);
+    Box::new(R::from_seed(full))
+}
+
+/// Construct a bundled generator, selected by name.
+///
+/// This supports choosing the algorithm at runtime, e.g. from a configuration
+/// file. The name is compared ignoring ASCII case; the following are
+/// supported:
+///
+/// | Name                 | Generator            | Seed length | Feature     |
+/// |----------------------|----------------------|-------------|-------------|
+/// | `chacha8`            | `ChaCha8Rng`         | 32 bytes    | `std_rng`   |
+/// | `chacha12`           | `ChaCha12Rng`        | 32 bytes    | `std_rng`   |
+/// | `chacha20`           | `ChaCha20Rng`        | 32 bytes    | `std_rng`   |
+/// | `xoshiro128plusplus` | `Xoshiro128PlusPlus` | 16 bytes    | `small_rng` |
+/// | `xoshiro256plusplus` | `Xoshiro256PlusPlus` | 32 bytes    | `small_rng` |
+///
+/// The ChaCha generators are those of the [rand_chacha] crate.
+///
+/// The generator is seeded with [`SeedableRng::from_seed`](rand_core::SeedableRng::from_seed), using `seed` as
+/// its seed: a shorter `seed` is padded with zeros, a longer one is
+/// truncated. For reproducible output, the same name and seed thus always
+/// yield the same generator. To seed from a value of another length without
+/// discarding any of it, derive the seed first, e.g. with a hash function.
+///
+/// # Example
+///
+/// ```
+/// use rand::rngs::by_name;
+///
+/// let mut rng = by_name("ChaCha20", &[7; 32]).unwrap();
+/// println!("{}", rng.next_u64());
+///
+/// match by_name("mt19937", &[]) {
+///     Ok(_) => unreachable!(),
+///     Err(err) => println!("{}", err), // lists the supported names
+/// }
+/// ```
+///
+/// [rand_chacha]: https://crates.io/crates/rand_chacha
+pub fn by_name(name: &str, seed: &[u8]) -> Result<Box<dyn RngCore + Send>, UnknownRngError> {
+    GENERATORS
+        .iter()
+        .find(|(known, _)| known.eq_ignore_ascii_case(name))
+        .map(|(_, new)| new(seed))
+        .ok_or_else(|| UnknownRngError {
+            name: String::from(name),
+        })
+}
+
+/// Error type returned from [`by_name`] for an unknown name.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnknownRngError {
+    name: String,
+}
+
+impl UnknownRngError {
+    /// The name which was not recognised
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl fmt::Display for UnknownRngError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "unknown RNG `{}`; supported names:", self.name)?;
+        if GENERATORS.is_empty() {
+            return f.write_str(" none (enable the `std_rng` or `small_rng` feature)");
+        }
+        for (i, (name, _)) in GENERATORS.iter().enumerate() {
+            let sep = if i == 0 { " " } else { ", " };
+            write!(f, "{}{}", sep, name)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UnknownRngError {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_unknown_name() {
+        let err = by_name("pcg64", &[1, 2, 3]).err().unwrap();
+        assert_eq!(err.name(), "pcg64");
+        let msg = alloc::format!("{}", err);
+        assert!(msg.starts_with("unknown RNG `pcg64`; supported names:"));
+        for (name, _) in GENERATORS {
+            assert!(msg.contains(name), "{}", msg);
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "std_rng", feature = "small_rng"))]
+    fn test_by_name() {
+        use rand_core::SeedableRng;
+
+        assert_eq!(
+            alloc::format!("{}", by_name("", &[]).err().unwrap()),
+            "unknown RNG ``; supported names: chacha8, chacha12, chacha20, \
+             xoshiro128plusplus, xoshiro256plusplus"
+        );
+
+        // Case is ignored
+        let mut a = by_name("ChaCha12", &[3; 32]).unwrap();
+        let mut b = crate::rngs::StdRng::from_seed([3; 32]);
+        assert_eq!(a.next_u64(), b.next_u64());
+
+        // The seed is padded with zeros or truncated
+        let mut seed = [0u8; 32];
+        seed[..3].copy_from_slice(&[1, 2, 3]);
+        let mut a = by_name("chacha20", &[1, 2, 3]).unwrap();
+        let mut b = rand_chacha::ChaCha20Rng::from_seed(seed);
+        assert_eq!(a.next_u64(), b.next_u64());
+        let mut a = by_name("chacha8", &[5; 40]).unwrap();
+        let mut b = rand_chacha::ChaCha8Rng::from_seed([5; 32]);
+        assert_eq!(a.next_u64(), b.next_u64());
+
+        let mut a = by_name("xoshiro256plusplus", &[9; 32]).unwrap();
+        let mut b = super::super::xoshiro256plusplus::Xoshiro256PlusPlus::from_seed([9; 32]);
+        assert_eq!(a.next_u64(), b.next_u64());
+        let mut a = by_name("XOSHIRO128PLUSPLUS", &[9; 16]).unwrap();
+        let mut b = super::super::xoshiro128plusplus::Xoshiro128PlusPlus::from_seed([9; 16]);
+        assert_eq!(a.next_u64(), b.next_u64());
+
+        // Distinct generators differ on the same seed
+        let mut a = by_name("chacha20", &[4; 32]).unwrap();
+        let mut b = by_name("chacha12", &[4; 32]).unwrap();
+        assert_ne!(a.next_u64(), b.next_u64());
+    }
+}
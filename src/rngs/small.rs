@@ -9,11 +9,33 @@
 //! A small fast RNG
 
 use rand_core::{Error, RngCore, SeedableRng};
+use super::xoshiro128plusplus::Xoshiro128PlusPlus;
+use super::xoshiro256plusplus::Xoshiro256PlusPlus;
 
 #[cfg(target_pointer_width = "64")]
-type Rng = super::xoshiro256plusplus::Xoshiro256PlusPlus;
+type Rng = Xoshiro256PlusPlus;
 #[cfg(not(target_pointer_width = "64"))]
-type Rng = super::xoshiro128plusplus::Xoshiro128PlusPlus;
+type Rng = Xoshiro128PlusPlus;
+
+#[cfg(target_pointer_width = "64")]
+const DEFAULT: Algorithm = Algorithm::Xoshiro256PlusPlus;
+#[cfg(not(target_pointer_width = "64"))]
+const DEFAULT: Algorithm = Algorithm::Xoshiro128PlusPlus;
+
+/// The algorithms available as backend of [`SmallRng`]
+///
+/// `SmallRng` picks its algorithm based on the target platform.
+/// [`PortableSmallRng`] allows selecting one explicitly, e.g. to get the same
+/// stream on 32-bit and 64-bit platforms.
+#[cfg_attr(doc_cfg, doc(cfg(feature = "small_rng")))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Algorithm {
+    /// `Xoshiro256PlusPlus`, the default on 64-bit platforms
+    Xoshiro256PlusPlus,
+    /// `Xoshiro128PlusPlus`, the default on other platforms
+    Xoshiro128PlusPlus,
+}
 
 /// A small-state, fast non-crypto PRNG
 ///
@@ -40,6 +62,8 @@ type Rng = super::xoshiro128plusplus::Xoshiro128PlusPlus;
 /// its state is much smaller than [`StdRng`]. The current algorithm is
 /// `Xoshiro256PlusPlus` on 64-bit platforms and `Xoshiro128PlusPlus` on 32-bit
 /// platforms. Both are also implemented by the [rand_xoshiro] crate.
+/// [`PortableSmallRng`] may be used to select either algorithm explicitly,
+/// regardless of platform.
 ///
 /// # Examples
 ///
@@ -80,6 +104,13 @@ type Rng = super::xoshiro128plusplus::Xoshiro128PlusPlus;
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct SmallRng(Rng);
 
+impl SmallRng {
+    /// The algorithm used by `SmallRng` on this platform
+    pub fn algorithm(&self) -> Algorithm {
+        DEFAULT
+    }
+}
+
 impl RngCore for SmallRng {
     #[inline(always)]
     fn next_u32(&mut self) -> u32 {
@@ -120,3 +151,121 @@ impl SeedableRng for SmallRng {
         SmallRng(Rng::seed_from_u64(state))
     }
 }
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Inner {
+    Xoshiro256PlusPlus(Xoshiro256PlusPlus),
+    Xoshiro128PlusPlus(Xoshiro128PlusPlus),
+}
+
+macro_rules! forward {
+    ($self:ident, $rng:ident => $e:expr) => {
+        match &mut $self.0 {
+            Inner::Xoshiro256PlusPlus($rng) => $e,
+            Inner::Xoshiro128PlusPlus($rng) => $e,
+        }
+    };
+}
+
+/// A [`SmallRng`] with an explicitly selected algorithm
+///
+/// Unlike [`SmallRng`], whose algorithm depends on the target platform, the
+/// stream of a `PortableSmallRng` for a given algorithm and seed is the same
+/// on all platforms (though it may still change in future library versions).
+/// This is useful e.g. for reproducing randomized tests across 32-bit and
+/// 64-bit platforms. The algorithm is selected at run time, which costs a
+/// branch on each call; prefer [`SmallRng`] where its default is acceptable.
+///
+/// # Example
+///
+/// ```
+/// use rand::Rng;
+/// use rand::rngs::{PortableSmallRng, SmallRngAlgorithm};
+///
+/// let mut rng = PortableSmallRng::from_seed_with(SmallRngAlgorithm::Xoshiro128PlusPlus, 42);
+/// let x: u32 = rng.gen();
+/// ```
+#[cfg_attr(doc_cfg, doc(cfg(feature = "small_rng")))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PortableSmallRng(Inner);
+
+impl PortableSmallRng {
+    /// Create a new `PortableSmallRng` using the given `algorithm`, seeded
+    /// from `state` (as by [`SeedableRng::seed_from_u64`] for that algorithm).
+    ///
+    /// For the default algorithm of the platform, the stream is that of
+    /// [`SmallRng::seed_from_u64`].
+    pub fn from_seed_with(algorithm: Algorithm, state: u64) -> Self {
+        PortableSmallRng(match algorithm {
+            Algorithm::Xoshiro256PlusPlus => {
+                Inner::Xoshiro256PlusPlus(Xoshiro256PlusPlus::seed_from_u64(state))
+            }
+            Algorithm::Xoshiro128PlusPlus => {
+                Inner::Xoshiro128PlusPlus(Xoshiro128PlusPlus::seed_from_u64(state))
+            }
+        })
+    }
+
+    /// The algorithm used by this generator
+    pub fn algorithm(&self) -> Algorithm {
+        match self.0 {
+            Inner::Xoshiro256PlusPlus(_) => Algorithm::Xoshiro256PlusPlus,
+            Inner::Xoshiro128PlusPlus(_) => Algorithm::Xoshiro128PlusPlus,
+        }
+    }
+}
+
+impl RngCore for PortableSmallRng {
+    #[inline(always)]
+    fn next_u32(&mut self) -> u32 {
+        forward!(self, rng => rng.next_u32())
+    }
+
+    #[inline(always)]
+    fn next_u64(&mut self) -> u64 {
+        forward!(self, rng => rng.next_u64())
+    }
+
+    #[inline(always)]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        forward!(self, rng => rng.fill_bytes(dest))
+    }
+
+    #[inline(always)]
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        forward!(self, rng => rng.try_fill_bytes(dest))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_default_algorithm() {
+        let mut a = SmallRng::seed_from_u64(7);
+        let mut b = PortableSmallRng::from_seed_with(DEFAULT, 7);
+        assert_eq!(a.algorithm(), DEFAULT);
+        assert_eq!(b.algorithm(), DEFAULT);
+        for _ in 0..10 {
+            assert_eq!(a.next_u64(), b.next_u64());
+        }
+    }
+
+    #[test]
+    fn test_from_seed_with() {
+        for &alg in &[Algorithm::Xoshiro256PlusPlus, Algorithm::Xoshiro128PlusPlus] {
+            let mut rng = PortableSmallRng::from_seed_with(alg, 7);
+            assert_eq!(rng.algorithm(), alg);
+            let mut buf = [0u8; 16];
+            rng.fill_bytes(&mut buf);
+        }
+
+        let mut a = PortableSmallRng::from_seed_with(Algorithm::Xoshiro256PlusPlus, 7);
+        let mut b = Xoshiro256PlusPlus::seed_from_u64(7);
+        assert_eq!(a.next_u64(), b.next_u64());
+        let mut a = PortableSmallRng::from_seed_with(Algorithm::Xoshiro128PlusPlus, 7);
+        let mut b = Xoshiro128PlusPlus::seed_from_u64(7);
+        assert_eq!(a.next_u32(), b.next_u32());
+    }
+}
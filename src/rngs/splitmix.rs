@@ -0,0 +1,83 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A tiny fixed-algorithm generator for internal, reproducible use
+
+use rand_core::impls::fill_bytes_via_next;
+use rand_core::{Error, RngCore};
+
+/// The SplitMix64 generator.
+///
+/// This is not exposed publicly; it exists so that functionality documented
+/// as reproducible (e.g. seeded permutations) does not depend on the
+/// algorithms behind [`StdRng`] or [`SmallRng`], which may change.
+///
+/// [`StdRng`]: crate::rngs::StdRng
+/// [`SmallRng`]: crate::rngs::SmallRng
+#[derive(Debug, Clone)]
+pub(crate) struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    const PHI: u64 = 0x9e3779b97f4a7c15;
+
+    /// Construct with the given state
+    pub(crate) fn new(state: u64) -> Self {
+        SplitMix64 { state }
+    }
+}
+
+impl RngCore for SplitMix64 {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(Self::PHI);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        fill_bytes_via_next(self, dest);
+    }
+
+    #[inline]
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SplitMix64;
+    use rand_core::RngCore;
+
+    #[test]
+    fn reference() {
+        // Reference values from the C implementation (seed 1234567).
+        let mut rng = SplitMix64::new(1234567);
+        let expected = [
+            6457827717110365317,
+            3203168211198807973,
+            9817491932198370423,
+            4593380528125082431,
+            16408922859458223821,
+        ];
+        for &e in &expected {
+            assert_eq!(rng.next_u64(), e);
+        }
+    }
+}
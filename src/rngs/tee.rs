@@ -0,0 +1,190 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A generator recording and replaying its output.
+
+use alloc::vec::Vec;
+use rand_core::{Error, RngCore};
+
+/// A wrapper recording the output of a generator, such that it can be
+/// replayed.
+///
+/// This supports experiments with *common random numbers*: comparing two
+/// configurations of a simulation with the same random draws, which reduces
+/// the variance of the estimated difference.
+///
+/// The generator is either recording or replaying:
+///
+/// -   When recording (the initial mode, or after [`TeeRng::record`]), output
+///     is drawn from the inner generator and appended to a tape.
+/// -   After [`TeeRng::replay`], output is read from the start of the tape.
+///     Once the tape is exhausted, further output is drawn from the inner
+///     generator (without being recorded).
+///
+/// The tape is a byte stream: all methods of [`RngCore`] consume bytes from
+/// it in little-endian order. Thus the replayed output is identical if the
+/// same methods are called, in the same order, as when recording. Memory use
+/// grows with the recorded output.
+///
+/// # Example
+///
+/// ```
+/// use rand::rngs::{StdRng, TeeRng};
+/// use rand::{Rng, SeedableRng};
+///
+/// let mut rng = TeeRng::new(StdRng::seed_from_u64(1));
+/// let first: Vec<f64> = (0..10).map(|_| rng.gen()).collect();
+/// rng.replay();
+/// let second: Vec<f64> = (0..10).map(|_| rng.gen()).collect();
+/// assert_eq!(first, second);
+/// ```
+#[derive(Debug, Clone)]
+pub struct TeeRng<R> {
+    inner: R,
+    tape: Vec<u8>,
+    /// The read position on the tape, or `None` when recording
+    replay_pos: Option<usize>,
+}
+
+impl<R: RngCore> TeeRng<R> {
+    /// Wrap `inner`, starting to record.
+    pub fn new(inner: R) -> Self {
+        TeeRng {
+            inner,
+            tape: Vec::new(),
+            replay_pos: None,
+        }
+    }
+
+    /// Start recording, discarding the tape.
+    pub fn record(&mut self) {
+        self.tape.clear();
+        self.replay_pos = None;
+    }
+
+    /// Start replaying from the start of the tape.
+    pub fn replay(&mut self) {
+        self.replay_pos = Some(0);
+    }
+
+    /// Returns `true` while replaying.
+    pub fn is_replaying(&self) -> bool {
+        self.replay_pos.is_some()
+    }
+
+    /// The recorded bytes
+    pub fn tape(&self) -> &[u8] {
+        &self.tape
+    }
+
+    /// Unwrap the inner generator, discarding the tape.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R: RngCore> RngCore for TeeRng<R> {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        let mut buf = [0; 4];
+        self.fill_bytes(&mut buf);
+        u32::from_le_bytes(buf)
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        let mut buf = [0; 8];
+        self.fill_bytes(&mut buf);
+        u64::from_le_bytes(buf)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.try_fill_bytes(dest).unwrap_or_else(|err| panic!("TeeRng: inner generator failed: {}", err))
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        match self.replay_pos {
+            None => {
+                self.inner.try_fill_bytes(dest)?;
+                self.tape.extend_from_slice(dest);
+            }
+            Some(pos) => {
+                let n = dest.len().min(self.tape.len() - pos);
+                dest[..n].copy_from_slice(&self.tape[pos..pos + n]);
+                self.replay_pos = Some(pos + n);
+                if n < dest.len() {
+                    self.inner.try_fill_bytes(&mut dest[n..])?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Rng;
+
+    #[test]
+    fn test_tee_rng_replay() {
+        let mut rng = TeeRng::new(crate::test::rng(305));
+        assert!(!rng.is_replaying());
+        let a32 = rng.next_u32();
+        let a64 = rng.next_u64();
+        let mut a_bytes = [0u8; 13];
+        rng.fill_bytes(&mut a_bytes);
+        let a_floats: [f64; 5] = rng.gen();
+        assert_eq!(rng.tape().len(), 4 + 8 + 13 + 40);
+
+        // Replaying twice gives the same output
+        for _ in 0..2 {
+            rng.replay();
+            assert!(rng.is_replaying());
+            assert_eq!(rng.next_u32(), a32);
+            assert_eq!(rng.next_u64(), a64);
+            let mut bytes = [0u8; 13];
+            rng.fill_bytes(&mut bytes);
+            assert_eq!(bytes, a_bytes);
+            assert_eq!(rng.gen::<[f64; 5]>(), a_floats);
+        }
+
+        // The tape is a byte stream
+        rng.replay();
+        let lo = u64::from(rng.next_u32());
+        assert_eq!(lo, u64::from(a32));
+        let (lo, hi) = (rng.next_u32(), rng.next_u32());
+        assert_eq!(u64::from(lo) | u64::from(hi) << 32, a64);
+    }
+
+    #[test]
+    fn test_tee_rng_exhausted_tape() {
+        let mut rng = TeeRng::new(crate::test::rng(306));
+        let mut reference = crate::test::rng(306);
+        let x = rng.next_u64();
+        rng.replay();
+        assert_eq!(rng.next_u64(), x);
+        // Beyond the tape, output continues from the inner generator
+        assert_eq!(reference.next_u64(), x);
+        assert_eq!(rng.next_u64(), reference.next_u64());
+        assert_eq!(rng.tape().len(), 8);
+
+        // A partially recorded draw
+        rng.replay();
+        let mut buf = [0u8; 12];
+        rng.fill_bytes(&mut buf);
+        assert_eq!(buf[..8], x.to_le_bytes());
+
+        rng.record();
+        assert!(!rng.is_replaying());
+        assert!(rng.tape().is_empty());
+        let y = rng.next_u32();
+        rng.replay();
+        assert_eq!(rng.next_u32(), y);
+    }
+}
@@ -9,6 +9,9 @@
 //! Thread-local random number generator
 
 use core::cell::UnsafeCell;
+#[cfg(feature = "thread_rng_override")] use core::cell::RefCell;
+#[cfg(feature = "thread_rng_override")] use core::marker::PhantomData;
+#[cfg(feature = "thread_rng_override")] use std::boxed::Box;
 use std::rc::Rc;
 use std::thread_local;
 use std::fmt;
@@ -90,6 +93,85 @@ thread_local!(
     }
 );
 
+#[cfg(feature = "thread_rng_override")]
+thread_local!(
+    static THREAD_RNG_OVERRIDE: RefCell<Option<Box<dyn RngCore>>> = RefCell::new(None);
+);
+
+/// Apply `f` to the overriding generator of this thread, if any
+#[cfg(feature = "thread_rng_override")]
+#[inline]
+fn with_override<T>(f: impl FnOnce(&mut dyn RngCore) -> T) -> Option<T> {
+    THREAD_RNG_OVERRIDE
+        .try_with(|o| o.borrow_mut().as_mut().map(|rng| f(&mut **rng)))
+        .ok()
+        .flatten()
+}
+
+/// Replace the thread-local generator of the current thread (testing aid)
+///
+/// Until the returned guard is dropped, all output of [`ThreadRng`] on the
+/// calling thread (including existing handles, [`thread_rng`] and
+/// [`random`]) comes from `rng`. This allows tests to make code using
+/// [`thread_rng`] reproducible without passing an RNG through every call site.
+/// Other threads are not affected.
+///
+/// Dropping the guard restores the previous generator. Overrides may be nested,
+/// but guards should be dropped in reverse order of creation.
+///
+/// **This is intended for testing only**: the supplied `rng` need not be
+/// cryptographically secure, contrary to the usual guarantees of
+/// [`ThreadRng`]. `rng` must not itself use [`ThreadRng`] (this panics).
+///
+/// # Example
+///
+/// ```
+/// use rand::RngCore;
+/// use rand::rngs::{mock::StepRng, override_thread_rng};
+///
+/// let guard = override_thread_rng(StepRng::new(7, 0));
+/// assert_eq!(rand::thread_rng().next_u32(), 7);
+/// drop(guard);
+/// ```
+///
+/// [`random`]: crate::random
+#[cfg(feature = "thread_rng_override")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "thread_rng_override")))]
+pub fn override_thread_rng(rng: impl RngCore + 'static) -> ThreadRngOverrideGuard {
+    let previous = THREAD_RNG_OVERRIDE.with(|o| o.borrow_mut().replace(Box::new(rng)));
+    ThreadRngOverrideGuard {
+        previous,
+        _not_send: PhantomData,
+    }
+}
+
+/// Guard returned by [`override_thread_rng`]
+///
+/// Restores the previous thread-local generator when dropped.
+#[cfg(feature = "thread_rng_override")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "thread_rng_override")))]
+#[must_use = "the override ends when the guard is dropped"]
+pub struct ThreadRngOverrideGuard {
+    previous: Option<Box<dyn RngCore>>,
+    // The guard applies to the current thread only
+    _not_send: PhantomData<*const ()>,
+}
+
+#[cfg(feature = "thread_rng_override")]
+impl fmt::Debug for ThreadRngOverrideGuard {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "ThreadRngOverrideGuard {{ .. }}")
+    }
+}
+
+#[cfg(feature = "thread_rng_override")]
+impl Drop for ThreadRngOverrideGuard {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        let _ = THREAD_RNG_OVERRIDE.try_with(|o| *o.borrow_mut() = previous);
+    }
+}
+
 /// Access the thread-local generator
 ///
 /// Returns a reference to the local [`ThreadRng`], initializing the generator
@@ -113,6 +195,39 @@ pub fn thread_rng() -> ThreadRng {
     ThreadRng { rng }
 }
 
+/// Set the reseed threshold of the thread-local generator
+///
+/// The generator behind [`thread_rng`] is reseeded after generating `bytes`
+/// bytes (by default, 64 kiB); see [`ReseedingRng::set_threshold`]. A value of
+/// `0` disables periodic reseeding. Reseeding after a fork is not affected.
+///
+/// This only affects the generator of the calling thread.
+///
+/// [`ReseedingRng::set_threshold`]: crate::rngs::adapter::ReseedingRng::set_threshold
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "std", feature = "std_rng"))))]
+pub fn set_thread_rng_reseed_threshold(bytes: u64) {
+    THREAD_RNG_KEY.with(|t| {
+        // SAFETY: no other reference to the generator is active during this
+        // call (see the rationale above)
+        let rng = unsafe { &mut *t.get() };
+        rng.set_threshold(bytes)
+    })
+}
+
+/// Get the reseed threshold of the thread-local generator
+///
+/// Returns the number of generated bytes after which the generator behind
+/// [`thread_rng`] is reseeded for the calling thread, or `0` if periodic
+/// reseeding is disabled. See [`set_thread_rng_reseed_threshold`].
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "std", feature = "std_rng"))))]
+pub fn thread_rng_reseed_threshold() -> u64 {
+    THREAD_RNG_KEY.with(|t| {
+        // SAFETY: as above
+        let rng = unsafe { &*t.get() };
+        rng.threshold()
+    })
+}
+
 impl Default for ThreadRng {
     fn default() -> ThreadRng {
         thread_rng()
@@ -122,6 +237,12 @@ impl Default for ThreadRng {
 impl RngCore for ThreadRng {
     #[inline(always)]
     fn next_u32(&mut self) -> u32 {
+        #[cfg(feature = "thread_rng_override")]
+        {
+            if let Some(result) = with_override(|rng| rng.next_u32()) {
+                return result;
+            }
+        }
         // SAFETY: We must make sure to stop using `rng` before anyone else
         // creates another mutable reference
         let rng = unsafe { &mut *self.rng.get() };
@@ -130,6 +251,12 @@ impl RngCore for ThreadRng {
 
     #[inline(always)]
     fn next_u64(&mut self) -> u64 {
+        #[cfg(feature = "thread_rng_override")]
+        {
+            if let Some(result) = with_override(|rng| rng.next_u64()) {
+                return result;
+            }
+        }
         // SAFETY: We must make sure to stop using `rng` before anyone else
         // creates another mutable reference
         let rng = unsafe { &mut *self.rng.get() };
@@ -137,6 +264,12 @@ impl RngCore for ThreadRng {
     }
 
     fn fill_bytes(&mut self, dest: &mut [u8]) {
+        #[cfg(feature = "thread_rng_override")]
+        {
+            if let Some(result) = with_override(|rng| rng.fill_bytes(dest)) {
+                return result;
+            }
+        }
         // SAFETY: We must make sure to stop using `rng` before anyone else
         // creates another mutable reference
         let rng = unsafe { &mut *self.rng.get() };
@@ -144,6 +277,12 @@ impl RngCore for ThreadRng {
     }
 
     fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        #[cfg(feature = "thread_rng_override")]
+        {
+            if let Some(result) = with_override(|rng| rng.try_fill_bytes(dest)) {
+                return result;
+            }
+        }
         // SAFETY: We must make sure to stop using `rng` before anyone else
         // creates another mutable reference
         let rng = unsafe { &mut *self.rng.get() };
@@ -164,6 +303,58 @@ mod test {
         assert_eq!(r.gen_range(0..1), 0);
     }
 
+    #[test]
+    fn test_reseed_threshold() {
+        use super::*;
+        use crate::Rng;
+
+        assert_eq!(thread_rng_reseed_threshold(), THREAD_RNG_RESEED_THRESHOLD);
+        set_thread_rng_reseed_threshold(1024);
+        assert_eq!(thread_rng_reseed_threshold(), 1024);
+        crate::thread_rng().gen::<u64>();
+
+        // Other threads are not affected
+        std::thread::spawn(|| {
+            assert_eq!(thread_rng_reseed_threshold(), THREAD_RNG_RESEED_THRESHOLD);
+        })
+        .join()
+        .unwrap();
+
+        set_thread_rng_reseed_threshold(0);
+        assert_eq!(thread_rng_reseed_threshold(), 0);
+        set_thread_rng_reseed_threshold(THREAD_RNG_RESEED_THRESHOLD);
+    }
+
+    #[test]
+    #[cfg(feature = "thread_rng_override")]
+    fn test_override_thread_rng() {
+        use super::*;
+        use crate::rngs::mock::StepRng;
+
+        let mut handle = crate::thread_rng();
+        {
+            let _guard = override_thread_rng(StepRng::new(1, 1));
+            assert_eq!(handle.next_u32(), 1);
+            assert_eq!(crate::thread_rng().next_u64(), 2);
+            {
+                let _inner = override_thread_rng(StepRng::new(10, 0));
+                assert_eq!(handle.next_u32(), 10);
+            }
+            assert_eq!(handle.next_u32(), 3);
+
+            // Other threads are not affected
+            std::thread::spawn(|| {
+                let mut rng = crate::thread_rng();
+                assert!((0..10).any(|_| rng.next_u32() != 4));
+            })
+            .join()
+            .unwrap();
+        }
+        let mut buf = [0u8; 8];
+        handle.fill_bytes(&mut buf);
+        assert!((0..10).any(|_| handle.next_u32() != 4));
+    }
+
     #[test]
     fn test_debug_output() {
         // We don't care about the exact output here, but it must not include
@@ -0,0 +1,111 @@
+// Copyright 2018-2023 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Helpers for [`SliceRandom::shuffle_ct`](super::SliceRandom::shuffle_ct)
+
+use crate::Rng;
+
+/// Types which can be swapped without branching on whether to swap
+///
+/// Used by [`SliceRandom::shuffle_ct`](super::SliceRandom::shuffle_ct).
+/// Implemented for primitive integers and arrays of such types.
+pub trait ConditionalSwap {
+    /// Swap `self` and `other` if `choice` is 1; leave both unchanged if
+    /// `choice` is 0.
+    ///
+    /// Implementations must not branch on `choice` nor access memory
+    /// depending on it. Other values of `choice` are not allowed.
+    fn conditional_swap(&mut self, other: &mut Self, choice: u8);
+}
+
+macro_rules! conditional_swap_int {
+    ($($ty:ty),*) => {$(
+        impl ConditionalSwap for $ty {
+            #[inline]
+            fn conditional_swap(&mut self, other: &mut Self, choice: u8) {
+                // All ones if `choice == 1`, zero if `choice == 0`
+                let mask = (0 as $ty).wrapping_sub(choice as $ty);
+                let t = (*self ^ *other) & mask;
+                *self ^= t;
+                *other ^= t;
+            }
+        }
+    )*}
+}
+conditional_swap_int!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl<T: ConditionalSwap, const N: usize> ConditionalSwap for [T; N] {
+    #[inline]
+    fn conditional_swap(&mut self, other: &mut Self, choice: u8) {
+        for (a, b) in self.iter_mut().zip(other.iter_mut()) {
+            a.conditional_swap(b, choice);
+        }
+    }
+}
+
+/// 1 if `a == b`, else 0, without branching
+///
+/// Requires `a, b < 2^63`, which holds for indices.
+#[inline]
+fn ct_eq(a: usize, b: usize) -> u8 {
+    let d = (a ^ b) as u64;
+    (d.wrapping_sub(1) >> 63) as u8
+}
+
+/// A uniform index in `0..ubound` without rejection sampling
+///
+/// Bias is at most `ubound / 2^64`.
+#[inline]
+fn ct_gen_index<R: Rng + ?Sized>(rng: &mut R, ubound: usize) -> usize {
+    ((u128::from(rng.next_u64()) * ubound as u128) >> 64) as usize
+}
+
+/// Fisher-Yates with constant-time swaps and index draws
+pub(super) fn shuffle_ct<T, R>(slice: &mut [T], rng: &mut R)
+where
+    T: ConditionalSwap,
+    R: Rng + ?Sized,
+{
+    for i in (1..slice.len()).rev() {
+        let j = ct_gen_index(rng, i + 1);
+        // Visit all candidates, such that the memory access pattern does not
+        // depend on `j`
+        let (head, tail) = slice.split_at_mut(i);
+        let last = &mut tail[0];
+        for (k, x) in head.iter_mut().enumerate() {
+            x.conditional_swap(last, ct_eq(k, j));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_conditional_swap() {
+        let (mut a, mut b) = (0x1234u16, 0xabcdu16);
+        a.conditional_swap(&mut b, 0);
+        assert_eq!((a, b), (0x1234, 0xabcd));
+        a.conditional_swap(&mut b, 1);
+        assert_eq!((a, b), (0xabcd, 0x1234));
+
+        let (mut a, mut b) = ([-1i8, 2, 3], [4i8, -5, 6]);
+        a.conditional_swap(&mut b, 1);
+        assert_eq!((a, b), ([4, -5, 6], [-1, 2, 3]));
+    }
+
+    #[test]
+    fn test_ct_eq() {
+        assert_eq!(ct_eq(0, 0), 1);
+        assert_eq!(ct_eq(7, 7), 1);
+        assert_eq!(ct_eq(0, 1), 0);
+        assert_eq!(ct_eq(1 << 20, 0), 0);
+        assert_eq!(ct_eq(usize::MAX >> 1, 0), 0);
+    }
+}
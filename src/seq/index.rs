@@ -56,6 +56,15 @@ impl IndexVec {
         }
     }
 
+    /// Sort the indices in ascending order
+    #[inline]
+    pub(crate) fn sort_unstable(&mut self) {
+        match *self {
+            IndexVec::U32(ref mut v) => v.sort_unstable(),
+            IndexVec::USize(ref mut v) => v.sort_unstable(),
+        }
+    }
+
     /// Return the value at the given `index`.
     ///
     /// (Note: we cannot implement [`std::ops::Index`] because of lifetime
@@ -258,6 +267,171 @@ where R: Rng + ?Sized {
     }
 }
 
+/// Randomly sample exactly `amount` distinct indices from `0..length`, lazily.
+///
+/// This is like [`sample`], but returns an iterator producing the indices one
+/// at a time, such that consumers may start processing before all indices
+/// have been chosen, or stop early. The indices are produced in random order:
+/// each (partial) sequence of indices is equally likely. The values differ
+/// from those of [`sample`] for the same RNG.
+///
+/// When `amount` is small compared to `length`, rejection sampling is used,
+/// remembering the indices produced so far: memory use is proportional to
+/// the number of indices produced, not to `length`. Otherwise, a partial
+/// Fisher-Yates shuffle of all indices is performed lazily; this allocates
+/// `O(length)` memory up-front. Each index takes `O(1)` expected time.
+///
+/// Panics if `amount > length`.
+///
+/// # Example
+///
+/// ```
+/// use rand::seq::index::sample_iter;
+///
+/// let mut rng = rand::thread_rng();
+/// for i in sample_iter(&mut rng, 1 << 30, 3) {
+///     println!("{}", i);
+/// }
+/// ```
+pub fn sample_iter<R>(rng: &mut R, length: usize, amount: usize) -> IndexSampleIter<'_, R>
+where R: Rng + ?Sized {
+    if amount > length {
+        panic!("`amount` of samples must be less than or equal to `length`");
+    }
+    // As for `sample`, the shuffle is faster unless `length` is much larger.
+    let state = if (length as f32) < 270.0 * (amount as f32) {
+        SampleIterState::Inplace((0..length).collect())
+    } else {
+        SampleIterState::Rejection(Default::default())
+    };
+    IndexSampleIter {
+        rng,
+        length,
+        pos: 0,
+        amount,
+        state,
+    }
+}
+
+/// Iterator returned by [`sample_iter`].
+#[derive(Debug)]
+pub struct IndexSampleIter<'a, R: ?Sized> {
+    rng: &'a mut R,
+    length: usize,
+    /// Number of indices produced
+    pos: usize,
+    amount: usize,
+    state: SampleIterState,
+}
+
+#[derive(Debug)]
+enum SampleIterState {
+    /// All indices; those produced are moved to the front
+    Inplace(Vec<usize>),
+    /// The indices produced
+    #[cfg(feature = "std")]
+    Rejection(HashSet<usize>),
+    #[cfg(not(feature = "std"))]
+    Rejection(BTreeSet<usize>),
+}
+
+impl<'a, R: Rng + ?Sized> Iterator for IndexSampleIter<'a, R> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        if self.pos == self.amount {
+            return None;
+        }
+        let index = match self.state {
+            SampleIterState::Inplace(ref mut indices) => {
+                let j = self.rng.gen_range(self.pos..self.length);
+                indices.swap(self.pos, j);
+                indices[self.pos]
+            }
+            SampleIterState::Rejection(ref mut seen) => loop {
+                let i = self.rng.gen_range(0..self.length);
+                if seen.insert(i) {
+                    break i;
+                }
+            },
+        };
+        self.pos += 1;
+        Some(index)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.amount - self.pos;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, R: Rng + ?Sized> ExactSizeIterator for IndexSampleIter<'a, R> {}
+
+/// Randomly select exactly `amount` distinct positions from `0..length`, and
+/// return them as a bitset.
+///
+/// The result holds `(length + 63) / 64` words; position `i` is selected if
+/// bit `i % 64` of word `i / 64` is set. Bits beyond `length` are not set.
+/// Each subset of `amount` positions is equally likely.
+///
+/// For dense selections this is more compact than the [`IndexVec`] returned
+/// by [`sample`]. If fewer than `length / 32` positions are selected (or not
+/// selected), these are chosen with [`sample`]; otherwise all positions are
+/// visited in order (selection sampling), taking `O(length)` time and no
+/// memory besides the result.
+///
+/// Panics if `amount > length`.
+///
+/// # Example
+///
+/// ```
+/// use rand::seq::index::sample_bitmask;
+///
+/// let mut rng = rand::thread_rng();
+/// let mask = sample_bitmask(&mut rng, 100, 40);
+/// assert_eq!(mask.len(), 2);
+/// assert_eq!(mask.iter().map(|w| w.count_ones()).sum::<u32>(), 40);
+/// ```
+pub fn sample_bitmask<R>(rng: &mut R, length: usize, amount: usize) -> Vec<u64>
+where R: Rng + ?Sized {
+    if amount > length {
+        panic!("`amount` of samples must be less than or equal to `length`");
+    }
+    let mut mask = alloc::vec![0u64; (length + 63) / 64];
+    let set = |mask: &mut [u64], i: usize| mask[i / 64] |= 1 << (i % 64);
+
+    // Select the smaller of the chosen and the other positions
+    let invert = amount > length - amount;
+    let k = if invert { length - amount } else { amount };
+    if k < length / 32 {
+        for i in sample(rng, length, k) {
+            set(&mut mask, i);
+        }
+    } else {
+        let mut needed = k;
+        for i in 0..length {
+            if needed == 0 {
+                break;
+            }
+            if rng.gen_range(0..length - i) < needed {
+                set(&mut mask, i);
+                needed -= 1;
+            }
+        }
+    }
+
+    if invert {
+        for word in mask.iter_mut() {
+            *word = !*word;
+        }
+        if length % 64 != 0 {
+            // Clear the bits beyond `length`
+            *mask.last_mut().unwrap() &= (1 << (length % 64)) - 1;
+        }
+    }
+    mask
+}
+
 /// Randomly sample exactly `amount` distinct indices from `0..length`, and
 /// return them in an arbitrary order (there is no guarantee of shuffling or
 /// ordering). The weights are to be provided by the input function `weights`,
@@ -348,7 +522,7 @@ where
     while index < length {
         let weight = weight(index.as_usize()).into();
         if !(weight >= 0.) {
-            return Err(WeightedError::InvalidWeight);
+            return Err(WeightedError::InvalidWeight { index: index.as_usize() });
         }
 
         let key = rng.gen::<f64>().powf(1.0 / weight);
@@ -372,6 +546,161 @@ where
     Ok(IndexVec::from(result))
 }
 
+/// Return a uniformly random permutation of `0..len` determined entirely by
+/// `seed`.
+///
+/// This is useful where the same permutation must be applied to several
+/// parallel sequences, possibly of different element types:
+///
+/// ```
+/// use rand::seq::index::permutation_seeded;
+///
+/// let names = ["a", "b", "c", "d"];
+/// let scores = [1.0, 2.0, 3.0, 4.0];
+/// let perm = permutation_seeded(42, names.len());
+/// let names: Vec<_> = perm.iter().map(|&i| names[i]).collect();
+/// let scores: Vec<_> = perm.iter().map(|&i| scores[i]).collect();
+/// # assert_eq!(names.len(), scores.len());
+/// ```
+///
+/// The permutation is generated by a Fisher–Yates shuffle driven by an
+/// internal, fixed-algorithm generator seeded from `seed` (independent of
+/// [`StdRng`] and [`SmallRng`]). Output for a given `(seed, len)` is portable
+/// and will not change in patch releases.
+///
+/// [`StdRng`]: crate::rngs::StdRng
+/// [`SmallRng`]: crate::rngs::SmallRng
+pub fn permutation_seeded(seed: u64, len: usize) -> Vec<usize> {
+    use super::SliceRandom;
+    let mut rng = crate::rngs::splitmix::SplitMix64::new(seed);
+    let mut perm: Vec<usize> = (0..len).collect();
+    perm.shuffle(&mut rng);
+    perm
+}
+
+/// The number of permutations tried by [`random_pattern_avoiding`]
+pub const PATTERN_AVOIDING_MAX_TRIES: usize = 10_000;
+
+/// Return a uniformly random permutation of `0..n` avoiding `pattern`, if one
+/// is found.
+///
+/// A permutation *contains* `pattern` if it has a subsequence (not
+/// necessarily contiguous) whose elements are in the same relative order as
+/// those of `pattern`; otherwise it *avoids* the pattern. For example,
+/// `[2, 0, 3, 1]` contains `[0, 1]` (e.g. via `2, 3`) but avoids `[0, 1, 2]`.
+/// Only the relative order of the values of `pattern` matters.
+///
+/// This uses rejection sampling: random permutations are generated until one
+/// avoids the pattern, giving up after [`PATTERN_AVOIDING_MAX_TRIES`] attempts.
+/// Since the fraction of permutations avoiding a pattern shrinks
+/// exponentially with `n` (e.g. `123`-avoiding permutations are counted by
+/// the Catalan numbers), this is only practical for small `n`; `None` is
+/// returned if no permutation was found. Checking a permutation takes up to
+/// `O(n^k)` time for a pattern of length `k`.
+///
+/// An empty pattern is contained in every permutation, thus `None` is
+/// returned; a pattern longer than `n` is avoided by every permutation.
+///
+/// # Panics
+///
+/// Panics if `pattern` contains duplicate values.
+///
+/// # Example
+///
+/// ```
+/// use rand::seq::index::random_pattern_avoiding;
+///
+/// let perm = random_pattern_avoiding(&mut rand::thread_rng(), 6, &[0, 1, 2]).unwrap();
+/// // No increasing subsequence of length 3
+/// # assert_eq!(perm.len(), 6);
+/// ```
+pub fn random_pattern_avoiding<R>(rng: &mut R, n: usize, pattern: &[usize]) -> Option<Vec<usize>>
+where R: Rng + ?Sized {
+    use super::SliceRandom;
+    for (i, x) in pattern.iter().enumerate() {
+        assert!(
+            !pattern[..i].contains(x),
+            "random_pattern_avoiding: duplicate value in pattern"
+        );
+    }
+    if pattern.is_empty() {
+        return None;
+    }
+
+    let mut perm: Vec<usize> = (0..n).collect();
+    let mut positions = Vec::with_capacity(pattern.len());
+    for _ in 0..PATTERN_AVOIDING_MAX_TRIES {
+        perm.shuffle(rng);
+        if !contains_pattern(&perm, pattern, &mut positions) {
+            return Some(perm);
+        }
+    }
+    None
+}
+
+/// Whether `perm` has a subsequence in the same relative order as `pattern`
+///
+/// `positions` is scratch space for the positions matched so far.
+fn contains_pattern(perm: &[usize], pattern: &[usize], positions: &mut Vec<usize>) -> bool {
+    let j = positions.len();
+    if j == pattern.len() {
+        return true;
+    }
+    let start = positions.last().map_or(0, |&p| p + 1);
+    // Leave room for the remaining elements of the pattern
+    let end = (perm.len() + j + 1).saturating_sub(pattern.len());
+    for p in start..end {
+        let consistent = positions
+            .iter()
+            .zip(pattern.iter())
+            .all(|(&q, &x)| (perm[q] < perm[p]) == (x < pattern[j]));
+        if consistent {
+            positions.push(p);
+            let found = contains_pattern(perm, pattern, positions);
+            positions.pop();
+            if found {
+                return true;
+            }
+        }
+    }
+    false
+}
+
+/// Randomly partition `0..length` into `parts` contiguous, non-empty
+/// segments.
+///
+/// Returns the `parts - 1` cut points in increasing order; they are distinct
+/// values in `1..length`. Segment `i` is `cuts[i - 1]..cuts[i]`, where the
+/// first segment starts at `0` and the last ends at `length`. Each of the
+/// possible partitions is equally likely.
+///
+/// This uses [`sample`], with the same complexity.
+///
+/// # Panics
+///
+/// Panics if `parts == 0` or `parts > length`.
+///
+/// # Example
+///
+/// ```
+/// use rand::seq::index::random_partition;
+///
+/// let data = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+/// let cuts = random_partition(&mut rand::thread_rng(), data.len(), 3);
+/// let chunks = [&data[..cuts[0]], &data[cuts[0]..cuts[1]], &data[cuts[1]..]];
+/// assert!(chunks.iter().all(|chunk| !chunk.is_empty()));
+/// ```
+pub fn random_partition<R>(rng: &mut R, length: usize, parts: usize) -> Vec<usize>
+where R: Rng + ?Sized {
+    assert!(
+        parts >= 1 && parts <= length,
+        "random_partition: `parts` must be in the range 1..=length"
+    );
+    let mut cuts = sample(rng, length - 1, parts - 1);
+    cuts.sort_unstable();
+    cuts.iter().map(|i| i + 1).collect()
+}
+
 /// Randomly sample exactly `amount` indices from `0..length`, using Floyd's
 /// combination algorithm.
 ///
@@ -572,6 +901,86 @@ mod test {
         assert_eq!(v1, v2);
     }
 
+    #[test]
+    fn test_sample_iter() {
+        let mut r = crate::test::rng(423);
+        for &(length, amount) in [(0, 0), (1, 1), (10, 0), (10, 10), (100, 50), (1 << 20, 600), (1 << 20, 5)].iter() {
+            let mut iter = sample_iter(&mut r, length, amount);
+            assert_eq!(iter.len(), amount);
+            let mut seen = vec![false; length];
+            let mut count = 0;
+            for i in &mut iter {
+                assert!(i < length);
+                assert!(!seen[i], "index {} produced twice", i);
+                seen[i] = true;
+                count += 1;
+            }
+            assert_eq!(count, amount);
+            assert_eq!(iter.next(), None);
+        }
+
+        // Memory use is proportional to the number of indices produced
+        let length = 1 << 30;
+        let mut iter = sample_iter(&mut r, length, 1 << 20);
+        let first: Vec<usize> = iter.by_ref().take(1000).collect();
+        assert!(first.iter().all(|&i| i < length));
+        match iter.state {
+            SampleIterState::Rejection(ref seen) => {
+                assert_eq!(seen.len(), 1000);
+                assert!(first.iter().all(|i| seen.contains(i)));
+            }
+            _ => panic!("expected rejection sampling"),
+        }
+        assert_eq!(iter.len(), (1 << 20) - 1000);
+
+        // Each index is equally likely to be produced first
+        let mut counts = [0; 10];
+        for _ in 0..10_000 {
+            counts[sample_iter(&mut r, 10, 3).next().unwrap()] += 1;
+        }
+        assert!(counts.iter().all(|&c| 900 < c && c < 1100), "{:?}", counts);
+    }
+
+    #[test]
+    fn test_sample_bitmask() {
+        let mut r = crate::test::rng(425);
+        let count = |mask: &[u64]| mask.iter().map(|w| w.count_ones() as usize).sum::<usize>();
+        for &(length, amount) in [(0, 0), (1, 0), (1, 1), (64, 64), (65, 0), (65, 65), (100, 3), (100, 97),
+            (130, 60), (1000, 5), (1000, 995), (1 << 20, 1000)].iter()
+        {
+            let mask = sample_bitmask(&mut r, length, amount);
+            assert_eq!(mask.len(), (length + 63) / 64);
+            assert_eq!(count(&mask), amount);
+            if length % 64 != 0 {
+                assert_eq!(mask[length / 64] >> (length % 64), 0);
+            }
+        }
+
+        // Positions are uniform, with (in order) selection sampling, sampling
+        // of indices, and both inverted
+        for &(length, amount) in [(10, 3), (130, 2), (10, 7), (130, 128)].iter() {
+            let mut counts = vec![0; length];
+            let trials = 200 * length / amount;
+            for _ in 0..trials {
+                let mask = sample_bitmask(&mut r, length, amount);
+                for (i, c) in counts.iter_mut().enumerate() {
+                    *c += (mask[i / 64] >> (i % 64)) as usize & 1;
+                }
+            }
+            // About 200 selections of each position are expected
+            let expected = (trials * amount) as f64 / length as f64;
+            for &c in &counts {
+                assert!((c as f64 - expected).abs() < 0.25 * expected, "{:?}", counts);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sample_iter_too_many() {
+        sample_iter(&mut crate::test::rng(424), 3, 4);
+    }
+
     #[cfg(feature = "std")]
     #[test]
     fn test_sample_weighted() {
@@ -593,6 +1002,97 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_permutation_seeded() {
+        for &len in &[0usize, 1, 2, 13, 100] {
+            let p1 = permutation_seeded(7, len);
+            let p2 = permutation_seeded(7, len);
+            assert_eq!(p1, p2);
+
+            let mut sorted = p1.clone();
+            sorted.sort_unstable();
+            assert_eq!(sorted, (0..len).collect::<Vec<_>>());
+        }
+
+        assert!(permutation_seeded(7, 100) != permutation_seeded(8, 100));
+    }
+
+    #[test]
+    fn test_contains_pattern() {
+        let check = |perm: &[usize], pattern: &[usize]| {
+            let mut positions = Vec::new();
+            let found = contains_pattern(perm, pattern, &mut positions);
+            assert!(positions.is_empty());
+            found
+        };
+        assert!(check(&[2, 0, 3, 1], &[0, 1]));
+        assert!(!check(&[2, 0, 3, 1], &[0, 1, 2]));
+        assert!(check(&[2, 0, 3, 1], &[1, 0, 2]));
+        assert!(check(&[2, 0, 3, 1], &[10, 5, 20]));
+        assert!(!check(&[3, 2, 1, 0], &[0, 1]));
+        assert!(!check(&[0, 1], &[0, 1, 2]));
+    }
+
+    #[test]
+    fn test_random_pattern_avoiding() {
+        let mut r = crate::test::rng(292);
+        for &(n, ref pattern) in &[(6, vec![0, 1, 2]), (7, vec![1, 0, 2]), (5, vec![2, 0, 3, 1])] {
+            for _ in 0..20 {
+                let perm = random_pattern_avoiding(&mut r, n, pattern).unwrap();
+                let mut sorted = perm.clone();
+                sorted.sort_unstable();
+                assert_eq!(sorted, (0..n).collect::<Vec<_>>());
+                assert!(!contains_pattern(&perm, pattern, &mut Vec::new()));
+            }
+        }
+
+        // There is exactly one 12-avoiding permutation
+        assert_eq!(random_pattern_avoiding(&mut r, 4, &[0, 1]), Some(vec![3, 2, 1, 0]));
+        // The 21-avoiding permutation of length 12 is too rare to find
+        assert_eq!(random_pattern_avoiding(&mut r, 12, &[1, 0]), None);
+
+        assert_eq!(random_pattern_avoiding(&mut r, 3, &[]), None);
+        assert_eq!(random_pattern_avoiding(&mut r, 2, &[0, 1, 2]).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_random_partition() {
+        let mut r = crate::test::rng(308);
+        for &(length, parts) in &[(1, 1), (5, 5), (10, 1), (10, 3), (100, 7), (1000, 999)] {
+            for _ in 0..20 {
+                let cuts = random_partition(&mut r, length, parts);
+                assert_eq!(cuts.len(), parts - 1);
+                // Segment bounds cover `0..length`, and each segment is non-empty
+                let bounds: Vec<usize> = core::iter::once(0)
+                    .chain(cuts.iter().cloned())
+                    .chain(core::iter::once(length))
+                    .collect();
+                assert!(bounds.windows(2).all(|w| w[0] < w[1]), "{:?}", cuts);
+            }
+        }
+
+        // All 6 partitions of 0..5 into 3 parts occur
+        let mut seen = Vec::new();
+        for _ in 0..200 {
+            let cuts = random_partition(&mut r, 5, 3);
+            if !seen.contains(&cuts) {
+                seen.push(cuts);
+            }
+        }
+        assert_eq!(seen.len(), 6);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_random_partition_too_many_parts() {
+        random_partition(&mut crate::test::rng(309), 3, 4);
+    }
+
+    #[test]
+    fn value_stability_permutation_seeded() {
+        assert_eq!(permutation_seeded(0, 8), vec![5, 3, 2, 0, 1, 7, 6, 4]);
+    }
+
     #[test]
     fn value_stability_sample() {
         let do_test = |length, amount, values: &[u32]| {
@@ -12,8 +12,11 @@
 //!
 //! *   [`SliceRandom`] slice sampling and mutation
 //! *   [`IteratorRandom`] iterator sampling
+//! *   [`VecDequeRandom`] sampling from a `VecDeque`
 //! *   [`index::sample`] low-level API to choose multiple indices from
-//!     `0..length`
+//!     `0..length`; [`index::sample_iter`] produces them lazily and
+//!     [`index::sample_bitmask`] as a bitset
+//! *   [`ConditionalSwap`] for element types of [`SliceRandom::shuffle_ct`]
 //!
 //! Also see:
 //!
@@ -25,6 +28,7 @@
 //! small performance boost in some cases).
 
 mod coin_flipper;
+mod constant_time;
 #[cfg(feature = "alloc")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
 pub mod index;
@@ -34,6 +38,8 @@ mod increasing_uniform;
 #[cfg(feature = "alloc")]
 use core::ops::Index;
 
+#[cfg(feature = "alloc")]
+use alloc::collections::VecDeque;
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
 
@@ -41,9 +47,11 @@ use alloc::vec::Vec;
 use crate::distributions::uniform::{SampleBorrow, SampleUniform};
 #[cfg(feature = "alloc")]
 use crate::distributions::WeightedError;
-use crate::Rng;
+use crate::distributions::Distribution;
+use crate::{CryptoRng, Rng};
 
 use self::coin_flipper::CoinFlipper;
+pub use self::constant_time::ConditionalSwap;
 use self::increasing_uniform::IncreasingUniform;
 
 /// Extension trait on slices, providing random mutation and sampling methods.
@@ -96,6 +104,56 @@ pub trait SliceRandom {
     where
         R: Rng + ?Sized;
 
+    /// Returns a clone of one random element of the slice, or a sample from
+    /// `fallback` if the slice is empty.
+    ///
+    /// For slices, complexity is `O(1)` plus the cost of cloning or sampling.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::distributions::Uniform;
+    /// use rand::seq::SliceRandom;
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let fallback = Uniform::new(100, 200).unwrap();
+    /// let x = [1, 2, 3].choose_or_sample(&mut rng, &fallback);
+    /// assert!((1..=3).contains(&x));
+    /// let y = [].choose_or_sample(&mut rng, &fallback);
+    /// assert!((100..200).contains(&y));
+    /// ```
+    fn choose_or_sample<R, D>(&self, rng: &mut R, fallback: &D) -> Self::Item
+    where
+        R: Rng + ?Sized,
+        Self::Item: Clone,
+        D: Distribution<Self::Item> + ?Sized;
+
+    /// Returns a reference to the element chosen by `index_fn`, or `None` if
+    /// the slice is empty.
+    ///
+    /// `index_fn` is called with the length of the slice (only if it is not
+    /// empty) and returns an index, which is reduced modulo the length. This
+    /// is a helper for deterministic tests of code written against
+    /// [`choose`], e.g. to always pick the first or last element; it is not
+    /// a random sampler.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::seq::SliceRandom;
+    ///
+    /// let choices = [1, 2, 3];
+    /// assert_eq!(choices.choose_by_index(|_| 0), Some(&1));
+    /// assert_eq!(choices.choose_by_index(|len| len - 1), Some(&3));
+    /// assert_eq!(choices.choose_by_index(|_| 4), Some(&2));
+    /// assert_eq!(choices[..0].choose_by_index(|_| 0), None);
+    /// ```
+    ///
+    /// [`choose`]: SliceRandom::choose
+    fn choose_by_index<F>(&self, index_fn: F) -> Option<&Self::Item>
+    where
+        F: FnOnce(usize) -> usize;
+
     /// Chooses `amount` elements from the slice at random, without repetition,
     /// and in random order. The returned iterator is appropriate both for
     /// collection into a `Vec` and filling an existing buffer (see example).
@@ -126,6 +184,62 @@ pub trait SliceRandom {
     where
         R: Rng + ?Sized;
 
+    /// Chooses `amount` elements from the slice at random, without repetition,
+    /// preserving their order in the slice.
+    ///
+    /// This is like [`choose_multiple`], except that the elements are yielded
+    /// in ascending order of their index, such that the result is a
+    /// subsequence of the slice. Every subset of `amount` elements is equally
+    /// likely. If `amount` exceeds the length of the slice, all elements are
+    /// yielded.
+    ///
+    /// Complexity is that of [`index::sample`] plus `O(amount * log(amount))`
+    /// for sorting the indices.
+    ///
+    /// # Example
+    /// ```
+    /// use rand::seq::SliceRandom;
+    ///
+    /// let items = [1, 2, 3, 4, 5, 6, 7, 8];
+    /// let sample: Vec<i32> = items.choose_multiple_stable(&mut rand::thread_rng(), 3).cloned().collect();
+    /// assert!(sample.windows(2).all(|w| w[0] < w[1]));
+    /// ```
+    ///
+    /// [`choose_multiple`]: SliceRandom::choose_multiple
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+    fn choose_multiple_stable<R>(&self, rng: &mut R, amount: usize) -> SliceChooseIter<Self, Self::Item>
+    where
+        R: Rng + ?Sized;
+
+    /// Chooses distinct elements from the slice at random, in random order,
+    /// filling `buf` without allocating.
+    ///
+    /// Writes `min(buf.len(), self.len())` elements to the start of `buf` and
+    /// returns that number; the rest of `buf` is left unchanged. The result
+    /// has the same distribution as [`choose_multiple`] with
+    /// `amount = buf.len()`, but not the same values for a given RNG.
+    ///
+    /// Elements are selected in a single pass over the slice and then
+    /// shuffled, so complexity is `O(self.len())`. For a sample much smaller
+    /// than the slice, [`choose_multiple`] is faster.
+    ///
+    /// # Example
+    /// ```
+    /// use rand::seq::SliceRandom;
+    ///
+    /// let items = [1, 2, 3, 4, 5, 6, 7, 8];
+    /// let mut buf = [0; 3];
+    /// let n = items.choose_multiple_into(&mut rand::thread_rng(), &mut buf);
+    /// assert_eq!(n, 3);
+    /// ```
+    ///
+    /// [`choose_multiple`]: SliceRandom::choose_multiple
+    fn choose_multiple_into<R>(&self, rng: &mut R, buf: &mut [Self::Item]) -> usize
+    where
+        R: Rng + ?Sized,
+        Self::Item: Clone;
+
     /// Similar to [`choose`], but where the likelihood of each outcome may be
     /// specified.
     ///
@@ -199,6 +313,98 @@ pub trait SliceRandom {
             + Clone
             + Default;
 
+    /// Similar to [`choose_weighted`], but without allocating.
+    ///
+    /// The specified function `weight` maps each item `x` to a relative
+    /// likelihood `weight(x)`. The probability of each item being selected is
+    /// therefore `weight(x) / s`, where `s` is the sum of all `weight(x)`.
+    ///
+    /// The slice is traversed twice: once to validate and sum the weights,
+    /// and once to find the chosen item. `weight` is thus called up to twice
+    /// per item and must return the same value each time. For slices of
+    /// length `n`, complexity is `O(n)` and only a single random value is
+    /// drawn.
+    ///
+    /// Returns [`WeightedError::NoItem`] if the slice is empty,
+    /// [`WeightedError::InvalidWeight`] if a weight is negative (or NaN),
+    /// [`WeightedError::AllWeightsZero`] if all weights are zero and
+    /// [`WeightedError::Overflow`] if the sum of floating-point weights is
+    /// infinite.
+    ///
+    /// The sum of integer weights must not overflow `X`: as with ordinary
+    /// integer arithmetic, this panics in debug builds, and otherwise wraps
+    /// and yields an incorrect distribution.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::prelude::*;
+    ///
+    /// let words = ["a", "bb", "ccc", "dddd"];
+    /// let mut rng = thread_rng();
+    /// // Longer words are more likely
+    /// let word = words.choose_weighted_by(&mut rng, |w| w.len()).unwrap();
+    /// println!("{}", word);
+    /// ```
+    /// [`choose_weighted`]: SliceRandom::choose_weighted
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+    fn choose_weighted_by<R, F, X>(
+        &self, rng: &mut R, weight: F,
+    ) -> Result<&Self::Item, WeightedError>
+    where
+        R: Rng + ?Sized,
+        F: Fn(&Self::Item) -> X,
+        X: SampleUniform
+            + for<'a> ::core::ops::AddAssign<&'a X>
+            + ::core::cmp::PartialOrd<X>
+            + Clone
+            + Default;
+
+    /// Draw `amount` independent weighted samples from the slice, with
+    /// replacement.
+    ///
+    /// The specified function `weight` maps each item `x` to a relative
+    /// likelihood `weight(x)`. Each sample is item `x` with probability
+    /// `weight(x) / s`, where `s` is the sum of all `weight(x)`. Items may be
+    /// returned more than once.
+    ///
+    /// The weights are evaluated and a [`distributions::WeightedIndex`] built
+    /// only once, so for slices of length `n`, complexity is
+    /// `O(n + amount * log n)`. This is cheaper than calling
+    /// [`choose_weighted`] `amount` times.
+    ///
+    /// Returns an error under the same conditions as [`choose_weighted`],
+    /// e.g. if the slice is empty or the total weight is zero, even if
+    /// `amount == 0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::prelude::*;
+    ///
+    /// let choices = [('a', 2), ('b', 1), ('c', 1)];
+    /// let mut rng = thread_rng();
+    /// let samples = choices.sample_weighted_with_replacement(&mut rng, 10, |item| item.1).unwrap();
+    /// assert_eq!(samples.len(), 10);
+    /// ```
+    /// [`choose_weighted`]: SliceRandom::choose_weighted
+    /// [`distributions::WeightedIndex`]: crate::distributions::WeightedIndex
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+    fn sample_weighted_with_replacement<R, F, B, X>(
+        &self, rng: &mut R, amount: usize, weight: F,
+    ) -> Result<Vec<&Self::Item>, WeightedError>
+    where
+        R: Rng + ?Sized,
+        F: Fn(&Self::Item) -> B,
+        B: SampleBorrow<X>,
+        X: SampleUniform
+            + for<'a> ::core::ops::AddAssign<&'a X>
+            + ::core::cmp::PartialOrd<X>
+            + Clone
+            + Default;
+
     /// Similar to [`choose_multiple`], but where the likelihood of each element's
     /// inclusion in the output may be specified. The elements are returned in an
     /// arbitrary, unspecified order.
@@ -242,6 +448,37 @@ pub trait SliceRandom {
         F: Fn(&Self::Item) -> X,
         X: Into<f64>;
 
+    /// Choose one element, favouring those near the end of the slice
+    ///
+    /// For a slice ordered oldest first (e.g. a log of events), the element at
+    /// index `i` is chosen with weight `exp(-(len - 1 - i) / half_life)`:
+    /// the most recent element has weight 1, and the weight halves every
+    /// `half_life * ln 2` elements further back. An infinite `half_life`
+    /// chooses uniformly.
+    ///
+    /// The weights are built once, so complexity is `O(n)` for slices of
+    /// length `n`. Returns `None` if the slice is empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `half_life` is not positive (or NaN).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::prelude::*;
+    ///
+    /// let events = ["boot", "login", "query", "logout"];
+    /// let mut rng = thread_rng();
+    /// println!("{:?}", events.choose_exp_recency(&mut rng, 2.0));
+    /// ```
+    //
+    // Note: this is feature-gated on std due to usage of f64::exp.
+    #[cfg(feature = "std")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
+    fn choose_exp_recency<R>(&self, rng: &mut R, half_life: f64) -> Option<&Self::Item>
+    where R: Rng + ?Sized;
+
     /// Shuffle a mutable slice in place.
     ///
     /// For slices of length `n`, complexity is `O(n)`.
@@ -263,6 +500,44 @@ pub trait SliceRandom {
     where
         R: Rng + ?Sized;
 
+    /// Shuffle a mutable slice in place, resisting timing side-channels.
+    ///
+    /// Like [`shuffle`], this picks a permutation uniformly at random (up to
+    /// a bias of at most `n / 2^64` per swap), but the sequence of operations
+    /// and memory accesses does not depend on the permutation:
+    ///
+    /// -   Each index is drawn from one `u64` by a widening multiplication,
+    ///     rather than by rejection sampling with a variable number of draws.
+    /// -   Each step of Fisher-Yates visits all candidates, swapping each
+    ///     with the current element under a mask (see [`ConditionalSwap`])
+    ///     instead of swapping only the chosen one.
+    ///
+    /// Complexity is thus `O(n²)` for slices of length `n`. The generator is
+    /// required to be a [`CryptoRng`], since otherwise the permutation may be
+    /// predictable anyway.
+    ///
+    /// The timing guarantees are best-effort and rest on assumptions outside
+    /// the control of this crate: that the compiler does not reintroduce
+    /// branches on the masks, that 128-bit multiplication and the generator
+    /// itself run in constant time on the target, and that the slice length
+    /// is public.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::seq::SliceRandom;
+    ///
+    /// let mut deck: Vec<u8> = (0..52).collect();
+    /// deck.shuffle_ct(&mut rand::thread_rng());
+    /// ```
+    ///
+    /// [`shuffle`]: SliceRandom::shuffle
+    /// [`CryptoRng`]: crate::CryptoRng
+    fn shuffle_ct<R>(&mut self, rng: &mut R)
+    where
+        Self::Item: ConditionalSwap,
+        R: Rng + CryptoRng + ?Sized;
+
     /// Shuffle a slice in place, but exit early.
     ///
     /// Returns two mutable slices from the source slice. The first contains
@@ -285,6 +560,65 @@ pub trait SliceRandom {
     ) -> (&mut [Self::Item], &mut [Self::Item])
     where
         R: Rng + ?Sized;
+
+    /// Shuffle a slice in place like [`partial_shuffle`], returning the
+    /// indices picked by the Fisher-Yates shuffle.
+    ///
+    /// The slice is permuted exactly as [`partial_shuffle`] would with the
+    /// same RNG, such that the last `amount` elements of the slice (or all
+    /// elements, if `amount` is larger than the slice) are randomly selected
+    /// and permuted. The returned indices record the swaps performed, in
+    /// order; passing them to [`apply_permutation`] repeats the shuffle on
+    /// another slice of the same length without using an RNG. This may be
+    /// used to keep several columns of data in sync.
+    ///
+    /// # Example
+    /// ```
+    /// use rand::seq::{apply_permutation, SliceRandom};
+    ///
+    /// let mut names = ["a", "b", "c", "d", "e"];
+    /// let mut ages = [10, 20, 30, 40, 50];
+    /// let picks = names.partial_shuffle_indices(&mut rand::thread_rng(), 3);
+    /// apply_permutation(&picks, &mut ages);
+    /// for (name, age) in names.iter().zip(ages.iter()) {
+    ///     let i = ["a", "b", "c", "d", "e"].iter().position(|n| n == name).unwrap();
+    ///     assert_eq!(*age, (i as i32 + 1) * 10);
+    /// }
+    /// ```
+    ///
+    /// [`partial_shuffle`]: SliceRandom::partial_shuffle
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+    fn partial_shuffle_indices<R>(&mut self, rng: &mut R, amount: usize) -> Vec<usize>
+    where
+        R: Rng + ?Sized;
+
+    /// Shuffle the elements for which `pred` returns `true` among their
+    /// positions, leaving all other elements in place.
+    ///
+    /// The matching elements are uniformly permuted, as by [`shuffle`] on the
+    /// subsequence of matching elements. `pred` is called exactly once for
+    /// each element, before any element is moved.
+    ///
+    /// Complexity is `O(n)` where `n` is the length of the slice; `O(m)`
+    /// memory is used where `m` is the number of matching elements.
+    ///
+    /// # Example
+    /// ```
+    /// use rand::seq::SliceRandom;
+    ///
+    /// let mut v = [1, 2, 3, 4, 5, 6];
+    /// v.shuffle_where(&mut rand::thread_rng(), |x| x % 2 == 0);
+    /// assert_eq!((v[0], v[2], v[4]), (1, 3, 5));
+    /// ```
+    ///
+    /// [`shuffle`]: SliceRandom::shuffle
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+    fn shuffle_where<R, F>(&mut self, rng: &mut R, pred: F)
+    where
+        R: Rng + ?Sized,
+        F: FnMut(&Self::Item) -> bool;
 }
 
 /// Extension trait on iterators, providing random sampling methods.
@@ -393,6 +727,12 @@ pub trait IteratorRandom: Iterator + Sized {
     /// calls are the same in the face of this optimization. If you want to
     /// force every element to be created regardless call `.inspect(|e| ())`.
     ///
+    /// Thus, given the same `rng` state, two iterators yielding the same
+    /// number of elements select the same position and leave `rng` in the
+    /// same state, even if their size hints differ (e.g. after `filter` or
+    /// `chain`). [`choose`] makes no such guarantee, since it draws fewer
+    /// random values when the size hint is exact.
+    ///
     /// [`choose`]: IteratorRandom::choose
     fn choose_stable<R>(mut self, rng: &mut R) -> Option<Self::Item>
     where
@@ -517,6 +857,133 @@ pub trait IteratorRandom: Iterator + Sized {
         }
         reservoir
     }
+
+    /// Collects `amount` values at random from the iterator, where the
+    /// likelihood of each element's inclusion may be specified. The elements
+    /// are returned in an arbitrary, unspecified order.
+    ///
+    /// This is the streaming equivalent of
+    /// [`SliceRandom::choose_multiple_weighted`]: the probability of an element
+    /// being chosen is proportional to `weight(x)`, sampling without
+    /// replacement. Weights must be positive and finite; otherwise
+    /// [`WeightedError::InvalidWeight`] is returned.
+    ///
+    /// If the iterator contains fewer than `amount` elements, all of them are
+    /// returned.
+    ///
+    /// This implementation uses the A-ExpJ weighted reservoir algorithm by
+    /// Efraimidis and Spirakis (<https://doi.org/10.1016/j.ipl.2005.11.003>).
+    /// It uses `O(amount)` space and `O(n)` time, where `n` is the length of
+    /// the iterator; random numbers are only required for about
+    /// `O(amount * log(n / amount))` elements.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::prelude::*;
+    ///
+    /// let records = vec![("a", 2.0), ("b", 1.0), ("c", 1.0), ("d", 0.5)];
+    /// let mut rng = thread_rng();
+    /// let chosen = records
+    ///     .into_iter()
+    ///     .choose_multiple_weighted(&mut rng, 2, |r| r.1)
+    ///     .unwrap();
+    /// assert_eq!(chosen.len(), 2);
+    /// ```
+    //
+    // Note: this is feature-gated on std due to usage of f64::ln.
+    #[cfg(feature = "std")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
+    fn choose_multiple_weighted<R, F, X>(
+        self, rng: &mut R, amount: usize, weight: F,
+    ) -> Result<Vec<Self::Item>, WeightedError>
+    where
+        R: Rng + ?Sized,
+        F: Fn(&Self::Item) -> X,
+        X: Into<f64>,
+    {
+        use std::collections::BinaryHeap;
+
+        // Keys are stored as `ln(u) / w` (the log of the key `u^(1/w)` used by
+        // Efraimidis and Spirakis) for precision. The heap is a min-heap, such
+        // that the top is the element with the smallest key.
+        struct Element<T> {
+            key: f64,
+            item: T,
+        }
+        impl<T> PartialEq for Element<T> {
+            fn eq(&self, other: &Self) -> bool {
+                self.key == other.key
+            }
+        }
+        impl<T> Eq for Element<T> {}
+        impl<T> PartialOrd for Element<T> {
+            fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl<T> Ord for Element<T> {
+            fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+                // Keys are never NaN since weights are checked; order reversed
+                other.key.partial_cmp(&self.key).unwrap()
+            }
+        }
+
+        let weight_of = |index: usize, item: &Self::Item| -> Result<f64, WeightedError> {
+            let w = weight(item).into();
+            if w > 0.0 && w.is_finite() {
+                Ok(w)
+            } else {
+                Err(WeightedError::InvalidWeight { index })
+            }
+        };
+        // A uniform value in (0, 1)
+        let open01 = |rng: &mut R| -> f64 { rng.sample(crate::distributions::Open01) };
+
+        if amount == 0 {
+            for (index, item) in self.enumerate() {
+                weight_of(index, &item)?;
+            }
+            return Ok(Vec::new());
+        }
+
+        let mut iter = self.enumerate();
+        let mut heap = BinaryHeap::with_capacity(amount);
+        for (index, item) in iter.by_ref() {
+            let w = weight_of(index, &item)?;
+            let key = open01(rng).ln() / w;
+            heap.push(Element { key, item });
+            if heap.len() == amount {
+                break;
+            }
+        }
+        if heap.len() < amount {
+            return Ok(heap.into_iter().map(|e| e.item).collect());
+        }
+
+        // `threshold` is the smallest key in the reservoir; `skip` is the
+        // total weight to skip before the next insertion.
+        let mut threshold = heap.peek().unwrap().key;
+        let mut skip = open01(rng).ln() / threshold;
+        for (index, item) in iter {
+            let w = weight_of(index, &item)?;
+            skip -= w;
+            if skip <= 0.0 {
+                // The new key is uniform in (threshold^w, 1) in the usual
+                // representation, thus exceeds the smallest key.
+                let t = (threshold * w).exp();
+                let u = t + (1.0 - t) * rng.gen::<f64>();
+                let key = u.ln() / w;
+                let mut top = heap.peek_mut().unwrap();
+                *top = Element { key, item };
+                drop(top);
+
+                threshold = heap.peek().unwrap().key;
+                skip = open01(rng).ln() / threshold;
+            }
+        }
+        Ok(heap.into_iter().map(|e| e.item).collect())
+    }
 }
 
 impl<T> SliceRandom for [T] {
@@ -545,6 +1012,29 @@ impl<T> SliceRandom for [T] {
         }
     }
 
+    fn choose_or_sample<R, D>(&self, rng: &mut R, fallback: &D) -> Self::Item
+    where
+        R: Rng + ?Sized,
+        Self::Item: Clone,
+        D: Distribution<Self::Item> + ?Sized,
+    {
+        match self.choose(rng) {
+            Some(x) => x.clone(),
+            None => fallback.sample(rng),
+        }
+    }
+
+    fn choose_by_index<F>(&self, index_fn: F) -> Option<&Self::Item>
+    where
+        F: FnOnce(usize) -> usize,
+    {
+        if self.is_empty() {
+            None
+        } else {
+            Some(&self[index_fn(self.len()) % self.len()])
+        }
+    }
+
     #[cfg(feature = "alloc")]
     fn choose_multiple<R>(&self, rng: &mut R, amount: usize) -> SliceChooseIter<Self, Self::Item>
     where
@@ -558,6 +1048,43 @@ impl<T> SliceRandom for [T] {
         }
     }
 
+    #[cfg(feature = "alloc")]
+    fn choose_multiple_stable<R>(&self, rng: &mut R, amount: usize) -> SliceChooseIter<Self, Self::Item>
+    where
+        R: Rng + ?Sized,
+    {
+        let amount = ::core::cmp::min(amount, self.len());
+        let mut indices = index::sample(rng, self.len(), amount);
+        indices.sort_unstable();
+        SliceChooseIter {
+            slice: self,
+            _phantom: Default::default(),
+            indices: indices.into_iter(),
+        }
+    }
+
+    fn choose_multiple_into<R>(&self, rng: &mut R, buf: &mut [Self::Item]) -> usize
+    where
+        R: Rng + ?Sized,
+        Self::Item: Clone,
+    {
+        let amount = ::core::cmp::min(buf.len(), self.len());
+        // Selection sampling: include each element with probability
+        // `needed / remaining`, yielding a uniform subset in slice order
+        let mut written = 0;
+        for (i, item) in self.iter().enumerate() {
+            if written == amount {
+                break;
+            }
+            if gen_index(rng, self.len() - i) < amount - written {
+                buf[written] = item.clone();
+                written += 1;
+            }
+        }
+        buf[..amount].shuffle(rng);
+        amount
+    }
+
     #[cfg(feature = "alloc")]
     fn choose_weighted<R, F, B, X>(
         &self, rng: &mut R, weight: F,
@@ -577,99 +1104,378 @@ impl<T> SliceRandom for [T] {
         Ok(&self[distr.sample(rng)])
     }
 
-    #[cfg(feature = "alloc")]
-    fn choose_weighted_mut<R, F, B, X>(
-        &mut self, rng: &mut R, weight: F,
-    ) -> Result<&mut Self::Item, WeightedError>
+    #[cfg(feature = "alloc")]
+    fn choose_weighted_mut<R, F, B, X>(
+        &mut self, rng: &mut R, weight: F,
+    ) -> Result<&mut Self::Item, WeightedError>
+    where
+        R: Rng + ?Sized,
+        F: Fn(&Self::Item) -> B,
+        B: SampleBorrow<X>,
+        X: SampleUniform
+            + for<'a> ::core::ops::AddAssign<&'a X>
+            + ::core::cmp::PartialOrd<X>
+            + Clone
+            + Default,
+    {
+        use crate::distributions::{Distribution, WeightedIndex};
+        let distr = WeightedIndex::new(self.iter().map(weight))?;
+        Ok(&mut self[distr.sample(rng)])
+    }
+
+    #[cfg(feature = "alloc")]
+    fn choose_weighted_by<R, F, X>(
+        &self, rng: &mut R, weight: F,
+    ) -> Result<&Self::Item, WeightedError>
+    where
+        R: Rng + ?Sized,
+        F: Fn(&Self::Item) -> X,
+        X: SampleUniform
+            + for<'a> ::core::ops::AddAssign<&'a X>
+            + ::core::cmp::PartialOrd<X>
+            + Clone
+            + Default,
+    {
+        use crate::distributions::uniform::UniformSampler;
+
+        if self.is_empty() {
+            return Err(WeightedError::NoItem);
+        }
+        let zero = X::default();
+        let mut total = zero.clone();
+        for (index, item) in self.iter().enumerate() {
+            let w = weight(item);
+            // Note that `!(w >= x)` is not equivalent to `w < x` for partially
+            // ordered types due to NaNs which are equal to nothing.
+            if !(w >= zero) {
+                return Err(WeightedError::InvalidWeight { index });
+            }
+            total += &w;
+        }
+        if total == zero {
+            return Err(WeightedError::AllWeightsZero);
+        }
+
+        let x = X::Sampler::new(zero.clone(), total)
+            .map_err(|_| WeightedError::Overflow)?
+            .sample(rng);
+        // The partial sums repeat the summation above, hence exceed `x` at the
+        // latest at the last item with non-zero weight. Should `weight` not be
+        // consistent, fall back to that item.
+        let mut partial = zero.clone();
+        let mut last = 0;
+        for (i, item) in self.iter().enumerate() {
+            let w = weight(item);
+            if w > zero {
+                partial += &w;
+                last = i;
+                if x < partial {
+                    return Ok(item);
+                }
+            }
+        }
+        Ok(&self[last])
+    }
+
+    #[cfg(feature = "alloc")]
+    fn sample_weighted_with_replacement<R, F, B, X>(
+        &self, rng: &mut R, amount: usize, weight: F,
+    ) -> Result<Vec<&Self::Item>, WeightedError>
+    where
+        R: Rng + ?Sized,
+        F: Fn(&Self::Item) -> B,
+        B: SampleBorrow<X>,
+        X: SampleUniform
+            + for<'a> ::core::ops::AddAssign<&'a X>
+            + ::core::cmp::PartialOrd<X>
+            + Clone
+            + Default,
+    {
+        use crate::distributions::WeightedIndex;
+        let distr = WeightedIndex::new(self.iter().map(weight))?;
+        Ok(distr.sample_iter(rng).take(amount).map(|i| &self[i]).collect())
+    }
+
+    #[cfg(feature = "std")]
+    fn choose_multiple_weighted<R, F, X>(
+        &self, rng: &mut R, amount: usize, weight: F,
+    ) -> Result<SliceChooseIter<Self, Self::Item>, WeightedError>
+    where
+        R: Rng + ?Sized,
+        F: Fn(&Self::Item) -> X,
+        X: Into<f64>,
+    {
+        let amount = ::core::cmp::min(amount, self.len());
+        Ok(SliceChooseIter {
+            slice: self,
+            _phantom: Default::default(),
+            indices: index::sample_weighted(
+                rng,
+                self.len(),
+                |idx| weight(&self[idx]).into(),
+                amount,
+            )?
+            .into_iter(),
+        })
+    }
+
+    #[cfg(feature = "std")]
+    fn choose_exp_recency<R>(&self, rng: &mut R, half_life: f64) -> Option<&Self::Item>
+    where R: Rng + ?Sized {
+        use crate::distributions::{Distribution, WeightedIndex};
+        assert!(half_life > 0.0, "choose_exp_recency: half_life must be positive");
+        let len = self.len();
+        if len == 0 {
+            return None;
+        }
+        let weights = (0..len).map(|i| (-((len - 1 - i) as f64) / half_life).exp());
+        // The last weight is 1, so the total is positive and finite
+        let distr = WeightedIndex::new(weights).unwrap();
+        Some(&self[distr.sample(rng)])
+    }
+
+    fn shuffle<R>(&mut self, rng: &mut R)
+    where
+        R: Rng + ?Sized,
+    {
+        if self.len() <= 1 {
+            // There is no need to shuffle an empty or single element slice
+            return;
+        }
+        self.partial_shuffle(rng, self.len());
+    }
+
+    fn shuffle_ct<R>(&mut self, rng: &mut R)
+    where
+        T: ConditionalSwap,
+        R: Rng + CryptoRng + ?Sized,
+    {
+        constant_time::shuffle_ct(self, rng)
+    }
+
+    fn partial_shuffle<R>(
+        &mut self, rng: &mut R, amount: usize,
+    ) -> (&mut [Self::Item], &mut [Self::Item])
+    where
+        R: Rng + ?Sized,
+    {
+        let m = partial_shuffle_with(self, rng, amount, |_| ());
+        let r = self.split_at_mut(m);
+        (r.1, r.0)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn partial_shuffle_indices<R>(&mut self, rng: &mut R, amount: usize) -> Vec<usize>
+    where
+        R: Rng + ?Sized,
+    {
+        let mut picks = Vec::with_capacity(::core::cmp::min(amount, self.len()));
+        partial_shuffle_with(self, rng, amount, |index| picks.push(index));
+        picks
+    }
+
+    #[cfg(feature = "alloc")]
+    fn shuffle_where<R, F>(&mut self, rng: &mut R, mut pred: F)
+    where
+        R: Rng + ?Sized,
+        F: FnMut(&Self::Item) -> bool,
+    {
+        let positions: Vec<usize> = (0..self.len()).filter(|&i| pred(&self[i])).collect();
+        // Fisher-Yates over the matching positions
+        for i in (1..positions.len()).rev() {
+            let j = gen_index(rng, i + 1);
+            self.swap(positions[i], positions[j]);
+        }
+    }
+}
+
+// Shuffle the last `amount` elements of `slice`, passing each index picked to
+// `pick`. Returns the number of elements not shuffled.
+fn partial_shuffle_with<T, R, F>(slice: &mut [T], rng: &mut R, amount: usize, mut pick: F) -> usize
+where
+    R: Rng + ?Sized,
+    F: FnMut(usize),
+{
+    let m = slice.len().saturating_sub(amount);
+
+    // The algorithm below is based on Durstenfeld's algorithm for the
+    // [Fisher–Yates shuffle](https://en.wikipedia.org/wiki/Fisher%E2%80%93Yates_shuffle#The_modern_algorithm)
+    // for an unbiased permutation.
+    // It ensures that the last `amount` elements of the slice
+    // are randomly selected from the whole slice.
+    
+    //`IncreasingUniform::next_index()` is faster than `gen_index`
+    //but only works for 32 bit integers
+    //So we must use the slow method if the slice is longer than that.
+    if slice.len() < (u32::MAX as usize) {
+        let mut chooser = IncreasingUniform::new(rng, m as u32);
+        for i in m..slice.len() {
+            let index = chooser.next_index();
+            pick(index);
+            slice.swap(i, index);
+        }
+    } else {            
+        for i in m..slice.len() {
+            let index = gen_index(rng, i + 1);
+            pick(index);
+            slice.swap(i, index);
+        }
+    }
+    m
+}
+
+/// Repeat a shuffle recorded by [`SliceRandom::partial_shuffle_indices`].
+///
+/// `indices` are the picks returned by `partial_shuffle_indices`; applying
+/// them to a slice of the same length as the original permutes it in the same
+/// way. (More generally, for a slice of length `n` and `k` indices, the last
+/// `k` elements are swapped in turn with the elements at the given indices.)
+///
+/// # Panics
+///
+/// Panics if there are more indices than elements in `slice` or if an index
+/// is out of range for its position in the shuffle, i.e. if `indices` does not
+/// come from a shuffle of a slice of the same length.
+pub fn apply_permutation<T>(indices: &[usize], slice: &mut [T]) {
+    assert!(
+        indices.len() <= slice.len(),
+        "apply_permutation: more indices than elements"
+    );
+    let m = slice.len() - indices.len();
+    for (i, &index) in (m..).zip(indices.iter()) {
+        assert!(index <= i, "apply_permutation: index out of range");
+        slice.swap(i, index);
+    }
+}
+
+impl<I> IteratorRandom for I where I: Iterator + Sized {}
+
+/// Extension trait on `VecDeque`, providing random sampling methods.
+///
+/// A `VecDeque` is not necessarily contiguous in memory and so cannot use
+/// [`SliceRandom`] directly; these methods sample by index instead, with the
+/// same results as the corresponding [`SliceRandom`] methods on a slice with
+/// the same elements.
+///
+/// ```
+/// use std::collections::VecDeque;
+/// use rand::seq::VecDequeRandom;
+///
+/// let mut queue: VecDeque<u32> = (0..10).collect();
+/// queue.rotate_left(3);
+/// println!("{:?}", queue.choose(&mut rand::thread_rng()));
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+pub trait VecDequeRandom {
+    /// The element type.
+    type Item;
+
+    /// Returns a reference to one random element, or `None` if the deque is
+    /// empty.
+    ///
+    /// See also [`SliceRandom::choose`].
+    fn choose<R>(&self, rng: &mut R) -> Option<&Self::Item>
+    where
+        R: Rng + ?Sized;
+
+    /// Returns a mutable reference to one random element, or `None` if the
+    /// deque is empty.
+    ///
+    /// See also [`SliceRandom::choose_mut`].
+    fn choose_mut<R>(&mut self, rng: &mut R) -> Option<&mut Self::Item>
     where
-        R: Rng + ?Sized,
-        F: Fn(&Self::Item) -> B,
-        B: SampleBorrow<X>,
-        X: SampleUniform
-            + for<'a> ::core::ops::AddAssign<&'a X>
-            + ::core::cmp::PartialOrd<X>
-            + Clone
-            + Default,
-    {
-        use crate::distributions::{Distribution, WeightedIndex};
-        let distr = WeightedIndex::new(self.iter().map(weight))?;
-        Ok(&mut self[distr.sample(rng)])
-    }
+        R: Rng + ?Sized;
 
-    #[cfg(feature = "std")]
-    fn choose_multiple_weighted<R, F, X>(
-        &self, rng: &mut R, amount: usize, weight: F,
-    ) -> Result<SliceChooseIter<Self, Self::Item>, WeightedError>
+    /// Chooses `amount` elements at random, without repetition, and in
+    /// random order. If `amount` exceeds the length of the deque, all
+    /// elements are returned.
+    ///
+    /// See also [`SliceRandom::choose_multiple`].
+    fn choose_multiple<R>(&self, rng: &mut R, amount: usize) -> SliceChooseIter<Self, Self::Item>
+    where
+        R: Rng + ?Sized;
+}
+
+#[cfg(feature = "alloc")]
+impl<T> VecDequeRandom for VecDeque<T> {
+    type Item = T;
+
+    fn choose<R>(&self, rng: &mut R) -> Option<&Self::Item>
     where
         R: Rng + ?Sized,
-        F: Fn(&Self::Item) -> X,
-        X: Into<f64>,
     {
-        let amount = ::core::cmp::min(amount, self.len());
-        Ok(SliceChooseIter {
-            slice: self,
-            _phantom: Default::default(),
-            indices: index::sample_weighted(
-                rng,
-                self.len(),
-                |idx| weight(&self[idx]).into(),
-                amount,
-            )?
-            .into_iter(),
-        })
+        if self.is_empty() {
+            None
+        } else {
+            self.get(gen_index(rng, self.len()))
+        }
     }
 
-    fn shuffle<R>(&mut self, rng: &mut R)
+    fn choose_mut<R>(&mut self, rng: &mut R) -> Option<&mut Self::Item>
     where
         R: Rng + ?Sized,
     {
-        if self.len() <= 1 {
-            // There is no need to shuffle an empty or single element slice
-            return;
+        if self.is_empty() {
+            None
+        } else {
+            let len = self.len();
+            self.get_mut(gen_index(rng, len))
         }
-        self.partial_shuffle(rng, self.len());
     }
 
-    fn partial_shuffle<R>(
-        &mut self, rng: &mut R, amount: usize,
-    ) -> (&mut [Self::Item], &mut [Self::Item])
+    fn choose_multiple<R>(&self, rng: &mut R, amount: usize) -> SliceChooseIter<Self, Self::Item>
     where
         R: Rng + ?Sized,
     {
-        let m = self.len().saturating_sub(amount);
-
-        // The algorithm below is based on Durstenfeld's algorithm for the
-        // [Fisher–Yates shuffle](https://en.wikipedia.org/wiki/Fisher%E2%80%93Yates_shuffle#The_modern_algorithm)
-        // for an unbiased permutation.
-        // It ensures that the last `amount` elements of the slice
-        // are randomly selected from the whole slice.
-        
-        //`IncreasingUniform::next_index()` is faster than `gen_index`
-        //but only works for 32 bit integers
-        //So we must use the slow method if the slice is longer than that.
-        if self.len() < (u32::MAX as usize) {
-            let mut chooser = IncreasingUniform::new(rng, m as u32);
-            for i in m..self.len() {
-                let index = chooser.next_index();
-                self.swap(i, index);
-            }
-        } else {            
-            for i in m..self.len() {
-                let index = gen_index(rng, i + 1);
-                self.swap(i, index);
-            }
+        let amount = ::core::cmp::min(amount, self.len());
+        SliceChooseIter {
+            slice: self,
+            _phantom: Default::default(),
+            indices: index::sample(rng, self.len(), amount).into_iter(),
         }
-        let r = self.split_at_mut(m);
-        (r.1, r.0)
     }
 }
 
-impl<I> IteratorRandom for I where I: Iterator + Sized {}
+/// Sort `items` by `key`, then shuffle them reproducibly using `seed`.
+///
+/// This yields a pseudo-random order which is reproducible (for the same
+/// `seed`) even if the order of the input is not, e.g. when collecting the
+/// entries of a `HashMap`. For this to hold, keys must be distinct: items with
+/// equal keys keep their input order before shuffling.
+///
+/// Like [`index::permutation_seeded`], the output does not depend on the
+/// platform and is not expected to change in patch releases.
+///
+/// # Example
+///
+/// ```
+/// use std::collections::HashMap;
+/// use rand::seq::shuffled_by_key;
+///
+/// let map: HashMap<&str, u32> = [("a", 1), ("b", 2), ("c", 3)].iter().cloned().collect();
+/// let entries: Vec<_> = map.into_iter().collect();
+/// let order = shuffled_by_key(entries, 42, |&(k, _)| k);
+/// assert_eq!(order.len(), 3);
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+pub fn shuffled_by_key<T, K, F>(mut items: Vec<T>, seed: u64, key: F) -> Vec<T>
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    items.sort_by_key(key);
+    let mut rng = crate::rngs::splitmix::SplitMix64::new(seed);
+    items.shuffle(&mut rng);
+    items
+}
 
 /// An iterator over multiple slice elements.
 ///
 /// This struct is created by
-/// [`SliceRandom::choose_multiple`](trait.SliceRandom.html#tymethod.choose_multiple).
+/// [`SliceRandom::choose_multiple`](trait.SliceRandom.html#tymethod.choose_multiple)
+/// and similar methods.
 #[cfg(feature = "alloc")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
 #[derive(Debug)]
@@ -1045,6 +1851,31 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_iterator_choose_stable_size_hints() {
+        // The same elements, with exact, partial and missing size hints
+        let items = [3, 1, 4, 1, 5, 9, 2, 6, 5, 3, 5];
+        for seed in 0..20 {
+            let mut r1 = crate::test::rng(seed);
+            let mut r2 = crate::test::rng(seed);
+            let mut r3 = crate::test::rng(seed);
+            let a = items.iter().choose_stable(&mut r1);
+            let b = (0..items.len())
+                .filter(|_| true)
+                .chain(None)
+                .map(|i| &items[i])
+                .choose_stable(&mut r2);
+            let c = UnhintedIterator { iter: items.iter() }.choose_stable(&mut r3);
+            assert!(a.is_some());
+            assert!(core::ptr::eq(a.unwrap(), b.unwrap()));
+            assert!(core::ptr::eq(a.unwrap(), c.unwrap()));
+            // The RNG is left in the same state
+            let x = r1.gen::<u64>();
+            assert_eq!(r2.gen::<u64>(), x);
+            assert_eq!(r3.gen::<u64>(), x);
+        }
+    }
+
     #[test]
     #[cfg_attr(miri, ignore)] // Miri is too slow
     fn test_shuffle() {
@@ -1095,6 +1926,141 @@ mod test {
         }
     }
 
+    #[test]
+    #[cfg(feature = "std_rng")]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn test_shuffle_ct() {
+        use crate::rngs::StdRng;
+        use crate::SeedableRng;
+        let mut r = StdRng::seed_from_u64(321);
+        let empty: &mut [u8] = &mut [];
+        empty.shuffle_ct(&mut r);
+        let mut one = [[1u32, 2]];
+        one.shuffle_ct(&mut r);
+        assert_eq!(one, [[1, 2]]);
+
+        let mut deck = [0u8; 52];
+        for (i, x) in deck.iter_mut().enumerate() {
+            *x = i as u8;
+        }
+        deck.shuffle_ct(&mut r);
+        let mut sorted = deck;
+        sorted.sort_unstable();
+        assert!(sorted.iter().copied().eq(0..52));
+        assert!(deck.iter().copied().ne(0..52));
+
+        // All 24 permutations of 4 elements are about equally likely
+        let mut counts = [0i32; 24];
+        for _ in 0..10000 {
+            let mut arr = [0usize, 1, 2, 3];
+            arr.shuffle_ct(&mut r);
+            // Lehmer code of the permutation
+            let mut code = 0;
+            for i in 0..4 {
+                let smaller = arr[i + 1..].iter().filter(|&&x| x < arr[i]).count();
+                code = code * (4 - i) + smaller;
+            }
+            counts[code] += 1;
+        }
+        for count in counts.iter() {
+            // As in test_shuffle: 99.9% chance samples lie within this range
+            assert!(352 <= *count && *count <= 483, "count: {}", count);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_choose_multiple_stable() {
+        let mut r = crate::test::rng(417);
+        let items = ['a', 'b', 'c', 'd', 'e'];
+
+        // All 10 subsets of size 2 are equally likely
+        let mut counts = [[0u32; 5]; 5];
+        for _ in 0..10000 {
+            let v: Vec<usize> = items
+                .choose_multiple_stable(&mut r, 2)
+                .map(|c| (*c as u8 - b'a') as usize)
+                .collect();
+            assert_eq!(v.len(), 2);
+            assert!(v[0] < v[1]);
+            counts[v[0]][v[1]] += 1;
+        }
+        for (i, row) in counts.iter().enumerate() {
+            for (j, &count) in row.iter().enumerate() {
+                if i < j {
+                    assert!(count > 900 && count < 1100);
+                } else {
+                    assert_eq!(count, 0);
+                }
+            }
+        }
+
+        let long: Vec<u32> = (0..1000).collect();
+        let v: Vec<u32> = long.choose_multiple_stable(&mut r, 100).cloned().collect();
+        assert_eq!(v.len(), 100);
+        assert!(v.windows(2).all(|w| w[0] < w[1]));
+
+        let all: Vec<char> = items.choose_multiple_stable(&mut r, 10).cloned().collect();
+        assert_eq!(all, items);
+        assert_eq!(items.choose_multiple_stable(&mut r, 0).count(), 0);
+    }
+
+    #[test]
+    fn test_choose_multiple_into() {
+        let mut r = crate::test::rng(418);
+        let items = [0usize, 1, 2, 3];
+
+        // All 12 ordered pairs are equally likely
+        let mut counts = [[0u32; 4]; 4];
+        let mut buf = [9usize; 2];
+        for _ in 0..12000 {
+            assert_eq!(items.choose_multiple_into(&mut r, &mut buf), 2);
+            assert_ne!(buf[0], buf[1]);
+            counts[buf[0]][buf[1]] += 1;
+        }
+        for (i, row) in counts.iter().enumerate() {
+            for (j, &count) in row.iter().enumerate() {
+                if i != j {
+                    assert!(count > 880 && count < 1120, "{:?}", counts);
+                }
+            }
+        }
+
+        // A short slice only partially fills the buffer
+        let mut buf = [9usize; 6];
+        assert_eq!(items.choose_multiple_into(&mut r, &mut buf), 4);
+        let mut chosen = [buf[0], buf[1], buf[2], buf[3]];
+        chosen.sort_unstable();
+        assert_eq!(chosen, items);
+        assert_eq!(buf[4..], [9, 9]);
+
+        let empty: [usize; 0] = [];
+        assert_eq!(empty.choose_multiple_into(&mut r, &mut buf), 0);
+        assert_eq!(items.choose_multiple_into(&mut r, &mut []), 0);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_shuffled_by_key() {
+        use std::collections::HashMap;
+
+        let map1: HashMap<u32, char> = (0..50).map(|i| (i, 'x')).collect();
+        let map2: HashMap<u32, char> = (0..50).rev().map(|i| (i, 'x')).collect();
+        let a = shuffled_by_key(map1.into_iter().collect(), 7, |&(k, _)| k);
+        let b = shuffled_by_key(map2.into_iter().collect(), 7, |&(k, _)| k);
+        assert_eq!(a, b);
+
+        let mut keys: Vec<u32> = a.iter().map(|&(k, _)| k).collect();
+        assert_ne!(keys, (0..50).collect::<Vec<_>>());
+        keys.sort_unstable();
+        assert_eq!(keys, (0..50).collect::<Vec<_>>());
+
+        let c = shuffled_by_key((0..50u32).rev().collect(), 8, |&k| k);
+        assert_ne!(a.iter().map(|&(k, _)| k).collect::<Vec<_>>(), c);
+
+        assert!(shuffled_by_key(Vec::<u32>::new(), 7, |&k| k).is_empty());
+    }
+
     #[test]
     fn test_partial_shuffle() {
         let mut r = crate::test::rng(118);
@@ -1111,6 +2077,149 @@ mod test {
         assert!(res.1[0] == 1 || res.1[1] == 2 || res.1[2] == 3);
     }
 
+    #[test]
+    fn test_choose_by_index() {
+        let v = ['a', 'b', 'c', 'd'];
+        assert_eq!(v.choose_by_index(|_| 0), Some(&'a'));
+        assert_eq!(v.choose_by_index(|len| len - 1), Some(&'d'));
+        assert_eq!(v.choose_by_index(|len| len / 2), Some(&'c'));
+        assert_eq!(v.choose_by_index(|_| 9), Some(&'b'));
+        assert_eq!(v.choose_by_index(|_| usize::MAX), Some(&'d'));
+
+        let empty: [char; 0] = [];
+        let mut called = false;
+        assert_eq!(empty.choose_by_index(|_| { called = true; 0 }), None);
+        assert!(!called);
+    }
+
+    #[test]
+    fn test_choose_or_sample() {
+        use crate::distributions::Uniform;
+
+        let mut r = crate::test::rng(296);
+        let fallback = Uniform::new(100u32, 110).unwrap();
+
+        let empty: [u32; 0] = [];
+        for _ in 0..100 {
+            let x = empty.choose_or_sample(&mut r, &fallback);
+            assert!((100..110).contains(&x));
+        }
+
+        let v = [1u32, 2, 3];
+        let mut seen = [false; 3];
+        for _ in 0..100 {
+            let x = v.choose_or_sample(&mut r, &fallback);
+            seen[x as usize - 1] = true;
+        }
+        assert_eq!(seen, [true; 3]);
+
+        // Consistent with `choose` for a non-empty slice
+        let a = v.choose_or_sample(&mut crate::test::rng(1), &fallback);
+        assert_eq!(Some(&a), v.choose(&mut crate::test::rng(1)));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_shuffle_where() {
+        let mut r = crate::test::rng(288);
+
+        let mut empty: [u32; 0] = [];
+        empty.shuffle_where(&mut r, |_| true);
+
+        let original: Vec<u32> = (0..12).collect();
+        let mut counts = [[0u32; 4]; 4];
+        for _ in 0..4000 {
+            let mut v = original.clone();
+            v.shuffle_where(&mut r, |x| x % 3 == 0);
+            for (i, (&x, &y)) in v.iter().zip(original.iter()).enumerate() {
+                if y % 3 == 0 {
+                    // Matching values stay among matching positions
+                    assert_eq!(x % 3, 0);
+                    counts[i / 3][x as usize / 3] += 1;
+                } else {
+                    assert_eq!(x, y);
+                }
+            }
+        }
+        // Each matching value is equally likely at each matching position
+        for row in counts.iter() {
+            assert!(row.iter().all(|&c| c > 900 && c < 1100));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_vec_deque() {
+        let mut r = crate::test::rng(287);
+
+        let mut empty: VecDeque<u32> = VecDeque::new();
+        assert_eq!(empty.choose(&mut r), None);
+        assert_eq!(empty.choose_mut(&mut r), None);
+        assert_eq!(empty.choose_multiple(&mut r, 3).count(), 0);
+
+        // A non-contiguous deque
+        let mut deque: VecDeque<usize> = VecDeque::with_capacity(8);
+        deque.extend(0..8);
+        deque.rotate_left(5);
+        assert!(!deque.as_slices().1.is_empty());
+
+        let mut counts = [0u32; 8];
+        for _ in 0..8000 {
+            counts[*deque.choose(&mut r).unwrap()] += 1;
+        }
+        assert!(counts.iter().all(|&c| c > 850 && c < 1150));
+
+        *deque.choose_mut(&mut r).unwrap() += 100;
+        assert_eq!(deque.iter().filter(|&&x| x >= 100).count(), 1);
+
+        let mut chosen: Vec<usize> = deque.choose_multiple(&mut r, 5).cloned().collect();
+        chosen.sort_unstable();
+        chosen.dedup();
+        assert_eq!(chosen.len(), 5);
+        assert_eq!(deque.choose_multiple(&mut r, 20).len(), 8);
+
+        // Same results as for a slice with the same elements
+        let slice: Vec<usize> = deque.iter().cloned().collect();
+        let a: Vec<usize> = deque.choose_multiple(&mut crate::test::rng(1), 4).cloned().collect();
+        let b: Vec<usize> = slice.choose_multiple(&mut crate::test::rng(1), 4).cloned().collect();
+        assert_eq!(a, b);
+        assert_eq!(deque.choose(&mut crate::test::rng(2)), slice.choose(&mut crate::test::rng(2)));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_partial_shuffle_indices() {
+        let original: Vec<u32> = (0..20).collect();
+        for &amount in &[0, 1, 7, 20, 30] {
+            let mut a = original.clone();
+            a.partial_shuffle(&mut crate::test::rng(286), amount);
+
+            let mut b = original.clone();
+            let picks = b.partial_shuffle_indices(&mut crate::test::rng(286), amount);
+            assert_eq!(a, b);
+            assert_eq!(picks.len(), amount.min(20));
+
+            // Replaying the picks gives the same permutation
+            let mut c = original.clone();
+            apply_permutation(&picks, &mut c);
+            assert_eq!(b, c);
+
+            // ... also on a parallel column of another type
+            let mut column: Vec<(u32, bool)> = original.iter().map(|&x| (x, x % 3 == 0)).collect();
+            apply_permutation(&picks, &mut column);
+            for (&x, &(y, flag)) in b.iter().zip(column.iter()) {
+                assert_eq!((x, x % 3 == 0), (y, flag));
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_apply_permutation_invalid() {
+        let mut v = [1, 2, 3];
+        apply_permutation(&[2, 0], &mut v);
+    }
+
     #[test]
     #[cfg(feature = "alloc")]
     fn test_sample_iter() {
@@ -1180,6 +2289,25 @@ mod test {
         }
         verify(chosen);
 
+        // sample_weighted_with_replacement
+        let mut items = [(0u32, 0usize); 14]; // (weight, index)
+        for (i, item) in items.iter_mut().enumerate() {
+            *item = (weights[i], i);
+        }
+        let mut chosen = [0i32; 14];
+        let samples = items
+            .sample_weighted_with_replacement(&mut r, N_REPS as usize, get_weight)
+            .unwrap();
+        assert_eq!(samples.len(), N_REPS as usize);
+        for item in samples {
+            chosen[item.1] += 1;
+        }
+        verify(chosen);
+        assert!(items
+            .sample_weighted_with_replacement(&mut r, 0, get_weight)
+            .unwrap()
+            .is_empty());
+
         // Check error cases
         let empty_slice = &mut [10][0..0];
         assert_eq!(
@@ -1194,13 +2322,67 @@ mod test {
             ['x'].choose_weighted_mut(&mut r, |_| 0),
             Err(WeightedError::AllWeightsZero)
         );
+        assert_eq!(
+            ['x', 'y'].sample_weighted_with_replacement(&mut r, 3, |_| 0),
+            Err(WeightedError::AllWeightsZero)
+        );
         assert_eq!(
             [0, -1].choose_weighted_mut(&mut r, |x| *x),
-            Err(WeightedError::InvalidWeight)
+            Err(WeightedError::InvalidWeight { index: 1 })
         );
         assert_eq!(
             [-1, 0].choose_weighted_mut(&mut r, |x| *x),
-            Err(WeightedError::InvalidWeight)
+            Err(WeightedError::InvalidWeight { index: 0 })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn test_choose_weighted_by() {
+        let mut r = crate::test::rng(430);
+        const N_REPS: u32 = 9000;
+
+        // Weight derived from the item; item 0 is never chosen
+        let items: [u32; 10] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+        let mut chosen = [0u32; 10];
+        for _ in 0..N_REPS {
+            chosen[*items.choose_weighted_by(&mut r, |&x| x).unwrap() as usize] += 1;
+        }
+        assert_eq!(chosen[0], 0);
+        for (i, &count) in chosen.iter().enumerate().skip(1) {
+            let exp = (i as u32 * N_REPS) as f32 / 45.0;
+            assert!((count as f32 - exp).abs() / exp <= 0.2, "{}: {}", i, count);
+        }
+
+        // Float weights
+        let items = [0.5f64, 0.0, 1.5];
+        let mut chosen = [0u32; 3];
+        for _ in 0..N_REPS {
+            let x = items.choose_weighted_by(&mut r, |&x| x).unwrap();
+            chosen[items.iter().position(|y| y == x).unwrap()] += 1;
+        }
+        assert_eq!(chosen[1], 0);
+        assert!((chosen[0] as f32 - N_REPS as f32 / 4.0).abs() < N_REPS as f32 * 0.03);
+
+        // Error cases
+        let empty: [u32; 0] = [];
+        assert_eq!(empty.choose_weighted_by(&mut r, |&x| x), Err(WeightedError::NoItem));
+        assert_eq!(
+            [0u32, 0].choose_weighted_by(&mut r, |&x| x),
+            Err(WeightedError::AllWeightsZero)
+        );
+        assert_eq!(
+            [1i32, -1].choose_weighted_by(&mut r, |&x| x),
+            Err(WeightedError::InvalidWeight { index: 1 })
+        );
+        assert_eq!(
+            [1.0, f64::NAN].choose_weighted_by(&mut r, |&x| x),
+            Err(WeightedError::InvalidWeight { index: 1 })
+        );
+        assert_eq!(
+            [1.0, f64::INFINITY].choose_weighted_by(&mut r, |&x| x),
+            Err(WeightedError::Overflow)
         );
     }
 
@@ -1316,6 +2498,40 @@ mod test {
         do_test(0..100, &[77, 95, 38, 23, 25, 8, 58, 40]);
     }
 
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_choose_exp_recency() {
+        let mut rng = crate::test::rng(306);
+        let log: Vec<usize> = (0..100).collect();
+        let mut counts = [0usize; 100];
+        for _ in 0..10000 {
+            counts[*log.choose_exp_recency(&mut rng, 1.0).unwrap()] += 1;
+        }
+        // The newest element has probability 1 - e^-1 ~ 0.632
+        assert!((6000..6600).contains(&counts[99]), "{}", counts[99]);
+        assert!(counts[98] > counts[97] && counts[97] > counts[96]);
+        assert!(counts[..90].iter().sum::<usize>() <= 5);
+
+        // An infinite half-life is uniform
+        let mut counts = [0usize; 4];
+        for _ in 0..4000 {
+            counts[*[0, 1, 2, 3].choose_exp_recency(&mut rng, f64::INFINITY).unwrap()] += 1;
+        }
+        assert!(counts.iter().all(|&c| (850..1150).contains(&c)), "{:?}", counts);
+
+        let empty: [u8; 0] = [];
+        assert_eq!(empty.choose_exp_recency(&mut rng, 1.0), None);
+        assert_eq!([7].choose_exp_recency(&mut rng, 1e-9), Some(&7));
+        assert_eq!([1, 2].choose_exp_recency(&mut rng, 1e-9), Some(&2));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    #[should_panic]
+    fn test_choose_exp_recency_bad_half_life() {
+        [1, 2, 3].choose_exp_recency(&mut crate::test::rng(307), 0.0);
+    }
+
     #[test]
     #[cfg(feature = "std")]
     fn test_multiple_weighted_edge_cases() {
@@ -1352,7 +2568,7 @@ mod test {
             choices
                 .choose_multiple_weighted(&mut rng, 2, |item| item.1)
                 .unwrap_err(),
-            WeightedError::InvalidWeight
+            WeightedError::InvalidWeight { index: 0 }
         );
 
         // Case 4: Empty list
@@ -1371,7 +2587,7 @@ mod test {
             choices
                 .choose_multiple_weighted(&mut rng, 2, |item| item.1)
                 .unwrap_err(),
-            WeightedError::InvalidWeight
+            WeightedError::InvalidWeight { index: 0 }
         );
 
         // Case 6: +infinity weights
@@ -1391,7 +2607,7 @@ mod test {
             choices
                 .choose_multiple_weighted(&mut rng, 2, |item| item.1)
                 .unwrap_err(),
-            WeightedError::InvalidWeight
+            WeightedError::InvalidWeight { index: 0 }
         );
 
         // Case 8: -0 weights
@@ -1446,4 +2662,85 @@ mod test {
             .map(|(a, b)| (a - b).abs());
         assert!(!diffs.any(|deviation| deviation > 100));
     }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_iterator_multiple_weighted() {
+        let mut r = crate::test::rng(415);
+
+        // Same distribution as `test_multiple_weighted_distributions`
+        let choices = [('a', 2), ('b', 1), ('c', 1)];
+        let mut results = [0i32; 3];
+        let expected_results = [4167, 4167, 1666];
+        for _ in 0..10000 {
+            let mut result = choices
+                .iter()
+                .choose_multiple_weighted(&mut r, 2, |item| item.1)
+                .unwrap();
+            assert_eq!(result.len(), 2);
+            result.sort_by_key(|item| item.0);
+            match (result[0].0, result[1].0) {
+                ('a', 'b') => results[0] += 1,
+                ('a', 'c') => results[1] += 1,
+                ('b', 'c') => results[2] += 1,
+                _ => panic!("unexpected result"),
+            }
+        }
+        let mut diffs = results
+            .iter()
+            .zip(&expected_results)
+            .map(|(a, b)| (a - b).abs());
+        assert!(!diffs.any(|deviation| deviation > 100));
+
+        // Single choice from a long stream: probability proportional to weight
+        let mut counts = [0u32; 10];
+        for _ in 0..11000 {
+            let chosen = (0..10usize)
+                .choose_multiple_weighted(&mut r, 1, |&i| (i + 1) as f64)
+                .unwrap();
+            counts[chosen[0]] += 1;
+        }
+        for (i, &c) in counts.iter().enumerate() {
+            let expected = 200.0 * (i + 1) as f64;
+            assert!((c as f64 - expected).abs() < 0.2 * expected, "{}: {}", i, c);
+        }
+
+        // Inclusion frequencies with a larger reservoir: heavy items dominate
+        let mut counts = [0u32; 100];
+        for _ in 0..1000 {
+            for i in (0..100usize)
+                .choose_multiple_weighted(&mut r, 10, |&i| if i < 5 { 1000.0 } else { 1.0 })
+                .unwrap()
+            {
+                counts[i] += 1;
+            }
+        }
+        assert!(counts[..5].iter().all(|&c| c > 990));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_iterator_multiple_weighted_edge_cases() {
+        let mut r = crate::test::rng(416);
+
+        // Fewer elements than requested
+        let mut all = (0..5).choose_multiple_weighted(&mut r, 10, |_| 1.0).unwrap();
+        all.sort_unstable();
+        assert_eq!(all, [0, 1, 2, 3, 4]);
+        assert!((0..5).choose_multiple_weighted(&mut r, 0, |_| 1.0).unwrap().is_empty());
+        assert!(core::iter::empty::<u32>()
+            .choose_multiple_weighted(&mut r, 3, |_| 1.0)
+            .unwrap()
+            .is_empty());
+
+        // Invalid weights, including in the skipped part of the stream
+        for &w in &[0.0, -1.0, f64::NAN, f64::INFINITY] {
+            for &pos in &[0, 50] {
+                let result = (0..100).choose_multiple_weighted(&mut r, 3, |&i| if i == pos { w } else { 1.0 });
+                assert_eq!(result.unwrap_err(), WeightedError::InvalidWeight { index: pos });
+            }
+            let result = (0..3).choose_multiple_weighted(&mut r, 0, |_| w);
+            assert_eq!(result.unwrap_err(), WeightedError::InvalidWeight { index: 0 });
+        }
+    }
 }
@@ -0,0 +1,148 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Utilities for reproducible randomized tests
+//!
+//! Randomized and property-based tests should usually use seeded generators,
+//! so that failures can be reproduced. This module helps to derive those
+//! seeds.
+
+use crate::rngs::splitmix::SplitMix64;
+#[cfg(all(feature = "std", feature = "small_rng"))]
+use crate::rngs::{OsRng, SmallRng};
+#[cfg(all(feature = "std", feature = "small_rng"))]
+use rand_core::SeedableRng;
+use rand_core::RngCore;
+
+/// The environment variable read by [`ci_rng`]
+pub const SEED_ENV_VAR: &str = "RAND_TEST_SEED";
+
+/// A reproducible series of distinct, well-separated seeds derived from `base`.
+///
+/// The series is the output of the SplitMix64 generator seeded with `base`.
+/// The first 2<sup>64</sup> seeds of a series are distinct, and series for
+/// similar `base` values (e.g. `0` and `1`) are unrelated. The output does
+/// not depend on the platform and is not expected to change in patch releases.
+///
+/// To replay a single iteration of a test, take the seed at its index with
+/// [`Iterator::nth`].
+///
+/// # Example
+///
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::test_support::seed_series;
+/// use rand::{Rng, SeedableRng};
+///
+/// for (i, seed) in seed_series(42).take(100).enumerate() {
+///     let mut rng = StdRng::seed_from_u64(seed);
+///     let x: u32 = rng.gen_range(0..1000);
+///     assert!(x < 1000, "failed at iteration {} (seed {})", i, seed);
+/// }
+/// ```
+pub fn seed_series(base: u64) -> impl Iterator<Item = u64> {
+    let mut rng = SplitMix64::new(base);
+    core::iter::repeat_with(move || rng.next_u64())
+}
+
+/// A generator for randomized tests, seeded from [`SEED_ENV_VAR`] if set,
+/// otherwise from entropy
+///
+/// If the environment variable `RAND_TEST_SEED` is set to a `u64` (in
+/// decimal), the generator is seeded with it; this makes a CI run
+/// deterministic. Otherwise, a random seed is used, so that local runs
+/// explore different inputs. Either way, the seed is printed to standard
+/// error (which the test harness shows for failing tests), such that a
+/// failure can be reproduced by setting the variable.
+///
+/// An empty value is treated as unset.
+///
+/// # Panics
+///
+/// Panics if the variable is set but not a valid `u64`.
+///
+/// # Example
+///
+/// ```
+/// use rand::Rng;
+///
+/// let mut rng = rand::test_support::ci_rng();
+/// let x: u32 = rng.gen_range(0..1000);
+/// assert!(x < 1000);
+/// ```
+#[cfg(all(feature = "std", feature = "small_rng"))]
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "std", feature = "small_rng"))))]
+pub fn ci_rng() -> SmallRng {
+    let var = std::env::var_os(SEED_ENV_VAR);
+    let seed = match var.as_ref().map(|v| v.to_str()) {
+        Some(Some(s)) if !s.trim().is_empty() => parse_seed(s),
+        Some(None) => panic!("{} is not valid unicode", SEED_ENV_VAR),
+        _ => OsRng.next_u64(),
+    };
+    std::eprintln!("ci_rng: using seed {} (set {}={} to reproduce)", seed, SEED_ENV_VAR, seed);
+    SmallRng::seed_from_u64(seed)
+}
+
+#[cfg(all(feature = "std", feature = "small_rng"))]
+fn parse_seed(s: &str) -> u64 {
+    s.trim()
+        .parse()
+        .unwrap_or_else(|_| panic!("{} must be a u64, got {:?}", SEED_ENV_VAR, s))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_seed_series() {
+        assert!(seed_series(289).take(50).eq(seed_series(289).take(50)));
+        assert!(!seed_series(289).take(50).eq(seed_series(290).take(50)));
+        let mut series = seed_series(289);
+        for _ in 0..10 {
+            series.next();
+        }
+        assert_eq!(seed_series(289).nth(10), series.next());
+
+        let mut seeds = [0u64; 1000];
+        for (slot, seed) in seeds.iter_mut().zip(seed_series(0)) {
+            *slot = seed;
+        }
+        seeds.sort_unstable();
+        assert!(seeds.windows(2).all(|w| w[0] != w[1]));
+    }
+
+    #[test]
+    #[cfg(all(feature = "std", feature = "small_rng"))]
+    fn test_ci_rng() {
+        use crate::Rng;
+
+        assert_eq!(parse_seed(" 42\n"), 42);
+        assert_eq!(parse_seed("18446744073709551615"), u64::MAX);
+
+        // This is the only test touching the variable
+        std::env::set_var(SEED_ENV_VAR, "303");
+        let a: [u64; 4] = ci_rng().gen();
+        let b: [u64; 4] = ci_rng().gen();
+        assert_eq!(a, b);
+        assert_eq!(a, SmallRng::seed_from_u64(303).gen::<[u64; 4]>());
+
+        std::env::set_var(SEED_ENV_VAR, "");
+        let c: [u64; 4] = ci_rng().gen();
+        let d: [u64; 4] = ci_rng().gen();
+        assert_ne!(c, d);
+        std::env::remove_var(SEED_ENV_VAR);
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(all(feature = "std", feature = "small_rng"))]
+    fn test_parse_seed_invalid() {
+        parse_seed("-1");
+    }
+}
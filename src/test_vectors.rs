@@ -0,0 +1,193 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Known output of the bundled generators, for reproducibility tests
+//!
+//! Code relying on a seeded generator producing the same stream in every
+//! version (e.g. for golden tests) may check this with
+//! [`assert_stream_matches`], either against the vectors in this module or
+//! against vectors recorded for its own seeds and generators (including
+//! those from other crates such as `rand_pcg`).
+//!
+//! A stream is a sequence of [`Draw`]s, since the output of
+//! [`RngCore::next_u32`], [`RngCore::next_u64`] and [`RngCore::fill_bytes`]
+//! may differ in how they consume the generator's state.
+//!
+//! Note that [`SmallRng`](crate::rngs::SmallRng) is not portable: its vectors
+//! are only provided for 64-bit targets.
+//!
+//! # Example
+//!
+//! ```
+//! use rand::rngs::StdRng;
+//! use rand::test_vectors::{assert_stream_matches, STD_RNG_SEED_U64_42};
+//! use rand::SeedableRng;
+//!
+//! assert_stream_matches(&mut StdRng::seed_from_u64(42), STD_RNG_SEED_U64_42);
+//! ```
+
+use alloc::vec;
+use rand_core::RngCore;
+
+/// A single expected output of a generator
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Draw<'a> {
+    /// The result of [`RngCore::next_u32`]
+    U32(u32),
+    /// The result of [`RngCore::next_u64`]
+    U64(u64),
+    /// The result of [`RngCore::fill_bytes`] with a buffer of the same length
+    Bytes(&'a [u8]),
+}
+
+/// Assert that `rng` produces the `expected` stream
+///
+/// Each [`Draw`] is compared to the output of the corresponding method.
+///
+/// # Panics
+///
+/// Panics on the first mismatch, reporting its position in the stream.
+#[track_caller]
+pub fn assert_stream_matches<R: RngCore + ?Sized>(rng: &mut R, expected: &[Draw<'_>]) {
+    for (i, draw) in expected.iter().enumerate() {
+        match *draw {
+            Draw::U32(x) => {
+                let got = rng.next_u32();
+                assert!(got == x, "stream mismatch at draw {}: expected U32({:#x}), got {:#x}", i, x, got);
+            }
+            Draw::U64(x) => {
+                let got = rng.next_u64();
+                assert!(got == x, "stream mismatch at draw {}: expected U64({:#x}), got {:#x}", i, x, got);
+            }
+            Draw::Bytes(bytes) => {
+                let mut got = vec![0u8; bytes.len()];
+                rng.fill_bytes(&mut got);
+                assert!(
+                    got == bytes,
+                    "stream mismatch at draw {}: expected Bytes({:?}), got {:?}",
+                    i,
+                    bytes,
+                    got
+                );
+            }
+        }
+    }
+}
+
+/// The stream of `StdRng::seed_from_u64(42)`
+#[cfg(feature = "std_rng")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "std_rng")))]
+pub const STD_RNG_SEED_U64_42: &[Draw<'static>] = &[
+    Draw::U32(0x222724a2),
+    Draw::U32(0x86cc7763),
+    Draw::U32(0x3fad517d),
+    Draw::U64(0xde5134d18af00a13),
+    Draw::U64(0xfd7630b2a2ef6071),
+    Draw::U64(0xf8119fea67e92d78),
+    Draw::Bytes(&[223, 176, 202, 8, 168, 129, 15, 158, 163, 156, 58, 106, 251, 120, 8, 89]),
+    Draw::Bytes(&[232, 216, 199, 188, 55]),
+    Draw::U64(0xa11c835ed9688d9b),
+];
+
+/// The stream of `StdRng::from_seed([0; 32])`
+#[cfg(feature = "std_rng")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "std_rng")))]
+pub const STD_RNG_ZERO_SEED: &[Draw<'static>] = &[
+    Draw::U32(0x6a9af49b),
+    Draw::U32(0x53f95507),
+    Draw::U32(0x12ce1f81),
+    Draw::U64(0xbbc32904d583265f),
+    Draw::U64(0xa589007e1474e049),
+    Draw::U64(0x79f864055f15ae2e),
+    Draw::Bytes(&[210, 122, 227, 192, 44, 232, 40, 52, 172, 250, 140, 121, 58, 98, 159, 44]),
+    Draw::Bytes(&[160, 222, 105, 25, 97]),
+    Draw::U64(0x4188d50bbe261341),
+];
+
+/// The stream of `SmallRng::seed_from_u64(42)` on 64-bit targets
+#[cfg(all(feature = "small_rng", target_pointer_width = "64"))]
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "small_rng", target_pointer_width = "64"))))]
+pub const SMALL_RNG_SEED_U64_42: &[Draw<'static>] = &[
+    Draw::U32(0xd0764d4f),
+    Draw::U32(0x519e4174),
+    Draw::U32(0xfbe07cfb),
+    Draw::U64(0xb37d9f600cd835b8),
+    Draw::U64(0xcb231c3874846a73),
+    Draw::U64(0x968d9f004e50de7d),
+    Draw::Bytes(&[86, 53, 26, 34, 255, 24, 23, 32, 70, 203, 216, 14, 7, 78, 233, 154]),
+    Draw::Bytes(&[199, 204, 149, 240, 218]),
+    Draw::U64(0xeeefd63219b4a0d4),
+];
+
+/// The stream of `SmallRng::from_seed` with the seed given by
+/// [`SMALL_RNG_REFERENCE_SEED`] on 64-bit targets, as produced by the
+/// reference implementation of xoshiro256++
+#[cfg(all(feature = "small_rng", target_pointer_width = "64"))]
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "small_rng", target_pointer_width = "64"))))]
+pub const SMALL_RNG_REFERENCE: &[Draw<'static>] = &[
+    Draw::U64(41943041),
+    Draw::U64(58720359),
+    Draw::U64(3588806011781223),
+    Draw::U64(3591011842654386),
+    Draw::U64(9228616714210784205),
+    Draw::U64(9973669472204895162),
+    Draw::U64(14011001112246962877),
+    Draw::U64(12406186145184390807),
+    Draw::U64(15849039046786891736),
+    Draw::U64(10450023813501588000),
+];
+
+/// The seed for [`SMALL_RNG_REFERENCE`]: the state words `[1, 2, 3, 4]`
+#[cfg(all(feature = "small_rng", target_pointer_width = "64"))]
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "small_rng", target_pointer_width = "64"))))]
+#[rustfmt::skip]
+pub const SMALL_RNG_REFERENCE_SEED: [u8; 32] = [
+    1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0,
+    3, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0,
+];
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::rngs::mock::StepRng;
+    use rand_core::SeedableRng;
+
+    #[test]
+    #[cfg(feature = "std_rng")]
+    fn test_std_rng_vectors() {
+        use crate::rngs::StdRng;
+        assert_stream_matches(&mut StdRng::seed_from_u64(42), STD_RNG_SEED_U64_42);
+        assert_stream_matches(&mut StdRng::from_seed([0; 32]), STD_RNG_ZERO_SEED);
+    }
+
+    #[test]
+    #[cfg(all(feature = "small_rng", target_pointer_width = "64"))]
+    fn test_small_rng_vectors() {
+        use crate::rngs::SmallRng;
+        assert_stream_matches(&mut SmallRng::seed_from_u64(42), SMALL_RNG_SEED_U64_42);
+        assert_stream_matches(&mut SmallRng::from_seed(SMALL_RNG_REFERENCE_SEED), SMALL_RNG_REFERENCE);
+    }
+
+    #[test]
+    fn test_assert_stream_matches() {
+        let x = 0x0102_0304_0506_0708;
+        let stream = [
+            Draw::U64(x),
+            Draw::U32(0x0506_0708),
+            Draw::Bytes(&[8, 7, 6, 5, 4, 3, 2, 1, 8, 7]),
+        ];
+        assert_stream_matches(&mut StepRng::new(x, 0), &stream);
+        assert_stream_matches(&mut StepRng::new(x, 0), &[]);
+    }
+
+    #[test]
+    #[should_panic(expected = "stream mismatch at draw 1")]
+    fn test_assert_stream_mismatch() {
+        assert_stream_matches(&mut StepRng::new(1, 1), &[Draw::U64(1), Draw::U64(3)]);
+    }
+}